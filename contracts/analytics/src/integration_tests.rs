@@ -320,7 +320,7 @@ mod integration_tests {
 
         // Test completion trends
         let trends =
-            client.get_completion_trends(&course_id, &base_time, &(base_time + 30 * 86400));
+            client.get_completion_trends(&course_id, &base_time, &(base_time + 30 * 86400), &None);
         assert!(!trends.is_empty());
 
         // Verify trend data shows progression
@@ -2,8 +2,9 @@ use crate::{
     errors::AnalyticsError,
     types::{
         Achievement, AggregatedMetrics, AnalyticsConfig, AnalyticsFilter, BatchSessionUpdate,
-        CourseAnalytics, InsightType, LeaderboardEntry, LeaderboardMetric, LearningSession,
-        MLInsight, ModuleAnalytics, ProgressAnalytics, ProgressReport, ReportPeriod,
+        CourseAnalytics, EventEmissionConfig, InsightType, LeaderboardEntry, LeaderboardMetric,
+        LearningSession, MLInsight, ModuleAnalytics, ProgressAnalytics, ProgressReport,
+        ReportPeriod, SessionSampleCounts,
     },
 };
 use soroban_sdk::{Address, BytesN, Env, Symbol, Vec};
@@ -42,6 +43,25 @@ pub trait AnalyticsTrait {
         course_id: Symbol,
     ) -> Result<ProgressAnalytics, AnalyticsError>;
 
+    /// Get recorded vs stored session counts for a sampled course
+    fn get_session_sample_counts(env: Env, course_id: Symbol) -> SessionSampleCounts;
+
+    /// Set the percent of students whose sessions are stored (admin only)
+    fn set_sample_rate_percent(
+        env: Env,
+        admin: Address,
+        percent: u32,
+    ) -> Result<(), AnalyticsError>;
+
+    /// Get the percent of students whose sessions are stored
+    fn get_sample_rate_percent(env: Env) -> u32;
+
+    /// Set the percent decline flagged as a drop-off point (admin only)
+    fn set_dropoff_threshold(env: Env, admin: Address, percent: u32) -> Result<(), AnalyticsError>;
+
+    /// Get the percent decline flagged as a drop-off point
+    fn get_dropoff_threshold(env: Env) -> u32;
+
     /// Calculate and get course-wide analytics
     fn get_course_analytics(env: Env, course_id: Symbol)
         -> Result<CourseAnalytics, AnalyticsError>;
@@ -96,6 +116,12 @@ pub trait AnalyticsTrait {
         metric: LeaderboardMetric,
     ) -> Vec<LeaderboardEntry>;
 
+    /// Show or hide a student on leaderboards
+    fn set_leaderboard_visibility(env: Env, user: Address, visible: bool);
+
+    /// Check whether a student appears on leaderboards
+    fn get_leaderboard_visibility(env: Env, user: Address) -> bool;
+
     /// Get student achievements
     fn get_student_achievements(env: Env, student: Address) -> Vec<Achievement>;
 
@@ -115,6 +141,16 @@ pub trait AnalyticsTrait {
     /// Get current configuration
     fn get_config(env: Env) -> Option<AnalyticsConfig>;
 
+    /// Switch event categories on or off (admin only)
+    fn set_event_emission(
+        env: Env,
+        admin: Address,
+        emission: EventEmissionConfig,
+    ) -> Result<(), AnalyticsError>;
+
+    /// Get which event categories are emitted
+    fn get_event_emission(env: Env) -> EventEmissionConfig;
+
     /// Recalculate all analytics for a course (admin only)
     fn recalculate_course_analytics(
         env: Env,
@@ -125,12 +161,41 @@ pub trait AnalyticsTrait {
     /// Cleanup old data (admin only)
     fn cleanup_old_data(env: Env, admin: Address, before_date: u64) -> Result<u32, AnalyticsError>;
 
+    /// Cleanup old data, examining at most `limit` students per call (admin only)
+    fn cleanup_old_data_batch(
+        env: Env,
+        admin: Address,
+        before_date: u64,
+        limit: u32,
+    ) -> Result<u32, AnalyticsError>;
+
+    /// Delete sessions older than the configured retention period (admin only)
+    fn enforce_retention(env: Env, admin: Address) -> Result<u32, AnalyticsError>;
+
+    /// Delete expired sessions, examining at most `limit` students per call (admin only)
+    fn enforce_retention_batch(env: Env, admin: Address, limit: u32)
+        -> Result<u32, AnalyticsError>;
+
+    /// Set how long sessions are kept; 0 keeps them forever (admin only)
+    fn set_retention_period(env: Env, admin: Address, seconds: u64) -> Result<(), AnalyticsError>;
+
+    /// Get the session retention period in seconds
+    fn get_retention_period(env: Env) -> u64;
+
+    /// Track courses recorded before course tracking existed (admin only)
+    fn backfill_tracked_courses(
+        env: Env,
+        admin: Address,
+        course_ids: Vec<Symbol>,
+    ) -> Result<u32, AnalyticsError>;
+
     /// Get course completion rates over time
     fn get_completion_trends(
         env: Env,
         course_id: Symbol,
         start_date: u64,
         end_date: u64,
+        smoothing_window: Option<u32>,
     ) -> Vec<AggregatedMetrics>;
 
     /// Get student performance comparison
@@ -18,19 +18,22 @@ use crate::reports::{generate_leaderboard_from_storage, ReportGenerator};
 use crate::storage::AnalyticsStorage;
 use crate::types::{
     Achievement, AchievementType, AggregatedMetrics, AnalyticsConfig, AnalyticsFilter,
-    CourseAnalytics, DataKey, DifficultyRating, InsightType, LeaderboardEntry, LeaderboardMetric,
-    LearningPathOptimization, LearningRecommendation, LearningSession, MLInsight, ModuleAnalytics,
-    PerformanceTrend, ProgressAnalytics, ProgressReport, ReportPeriod,
+    CourseAnalytics, DataKey, DifficultyRating, EventEmissionConfig, InsightType, LeaderboardEntry,
+    LeaderboardMetric, LearningPathOptimization, LearningRecommendation, LearningSession,
+    MLInsight, ModuleAnalytics, PerformanceTrend, ProgressAnalytics, ProgressReport, ReportPeriod,
+    SessionSampleCounts,
 };
 use shared::event_schema::{
-    AccessControlEventData, AnalyticsEventData, ContractInitializedEvent, SessionCompletedEvent,
-    SessionRecordedEvent,
+    AccessControlEventData, AnalyticsAchievementEarnedEvent, AnalyticsEventData,
+    ContractInitializedEvent, SessionCompletedEvent, SessionRecordedEvent,
 };
 use shared::monitoring::{ContractHealthReport, Monitor};
 use shared::timestamp_utils::{utc_day_index, validate_utc_timestamp};
 use shared::{emit_access_control_event, emit_analytics_event};
+use soroban_sdk::xdr::ToXdr;
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short, Address, BytesN, Env, String, Symbol, Vec,
+    contract, contractimpl, contracttype, symbol_short, Address, BytesN, Env, Map, String, Symbol,
+    Vec,
 };
 
 #[contracttype]
@@ -64,6 +67,9 @@ fn require_admin(env: &Env, caller: &Address) -> Result<(), AnalyticsError> {
     Ok(())
 }
 
+/// Students examined per call by the unbatched cleanup and retention entry points.
+const DEFAULT_PRUNE_BATCH: u32 = 50;
+
 fn require_initialized(env: &Env) -> Result<(), AnalyticsError> {
     if AnalyticsStorage::get_admin(env).is_none() {
         return Err(AnalyticsError::NotInitialized);
@@ -71,6 +77,89 @@ fn require_initialized(env: &Env) -> Result<(), AnalyticsError> {
     Ok(())
 }
 
+/// Event categories to emit; every category is on until configured otherwise.
+fn event_emission(env: &Env) -> EventEmissionConfig {
+    AnalyticsStorage::get_event_emission(env)
+}
+
+/// Whether sampling currently leaves some students' sessions unstored.
+fn sampling_enabled(env: &Env) -> bool {
+    let rate = AnalyticsStorage::get_sample_rate_percent(env);
+    rate > 0 && rate < 100
+}
+
+/// Whether a student falls inside the configured storage sample.
+///
+/// Sampling hashes the student address, so all of a student's sessions are
+/// kept or skipped together and per-student analytics stay complete.
+fn is_sampled_student(env: &Env, student: &Address) -> bool {
+    if !sampling_enabled(env) {
+        return true;
+    }
+    let rate = AnalyticsStorage::get_sample_rate_percent(env);
+    let digest = env.crypto().sha256(&student.clone().to_xdr(env)).to_array();
+    let bucket = u32::from_be_bytes([digest[0], digest[1], digest[2], digest[3]]) % 100;
+    bucket < rate
+}
+
+/// Scale a figure computed from sampled students up to the full population.
+fn scale_to_population(value: u64, sampled: u32, population: u32) -> u64 {
+    if sampled == 0 || population <= sampled {
+        return value;
+    }
+    value.saturating_mul(population as u64) / sampled as u64
+}
+
+/// Trailing moving average over the last `window` days; a window of 0 or 1
+/// returns the points as-is.
+///
+/// Windows are measured by date, so a day without metrics counts as a day of
+/// zero activity rather than being skipped. Windows are clipped to `first_day`,
+/// the start of the requested range. Points keep their own date.
+fn smooth_trend(
+    env: &Env,
+    points: &Vec<AggregatedMetrics>,
+    first_day: u64,
+    window: u32,
+) -> Vec<AggregatedMetrics> {
+    if window <= 1 {
+        return points.clone();
+    }
+
+    let day_secs = shared::timestamp_utils::SECS_PER_DAY;
+    let span = (window as u64 - 1) * day_secs;
+    let mut smoothed = Vec::new(env);
+    for i in 0..points.len() {
+        let mut point = points.get(i).unwrap();
+        let window_start = point.date.saturating_sub(span).max(first_day);
+        let (mut students, mut sessions, mut time, mut completions) = (0u64, 0u64, 0u64, 0u64);
+        let (mut score_total, mut scored_days) = (0u64, 0u64);
+        for j in (0..=i).rev() {
+            let day = points.get(j).unwrap();
+            if day.date < window_start {
+                break;
+            }
+            students += day.active_students as u64;
+            sessions += day.total_sessions as u64;
+            time += day.total_time;
+            completions += day.completions as u64;
+            if let Some(score) = day.average_score {
+                score_total += score as u64;
+                scored_days += 1;
+            }
+        }
+
+        let days = (point.date - window_start) / day_secs + 1;
+        point.active_students = (students / days) as u32;
+        point.total_sessions = (sessions / days) as u32;
+        point.total_time = time / days;
+        point.completions = (completions / days) as u32;
+        point.average_score = score_total.checked_div(scored_days).map(|score| score as u32);
+        smoothed.push_back(point);
+    }
+    smoothed
+}
+
 /// Compute or update progress analytics after a session is completed.
 fn update_progress_analytics(
     env: &Env,
@@ -237,6 +326,156 @@ fn update_progress_analytics(
     AnalyticsStorage::set_progress_analytics(env, &session.student, &session.course_id, &analytics);
 }
 
+/// Validates and stores a newly started session, emitting `SessionRecorded`.
+fn store_new_session(env: &Env, session: &LearningSession) -> Result<(), AnalyticsError> {
+    // Issue #414: validate that start_time is a plausible UTC epoch second so
+    // that achievement earned_date and streak calculations are timezone-safe.
+    validate_utc_timestamp(session.start_time).map_err(|_| AnalyticsError::InvalidTimestamp)?;
+
+    if AnalyticsStorage::has_session(env, &session.session_id) {
+        return Err(AnalyticsError::SessionAlreadyExists);
+    }
+
+    // Sampling re-hashes the student, so a replayed skipped session is skipped
+    // again and can never be stored twice; only the counters record it.
+    let sampled = is_sampled_student(env, &session.student);
+    AnalyticsStorage::record_session_sample(env, session, sampled);
+    if sampled {
+        AnalyticsStorage::set_session(env, session);
+        AnalyticsStorage::add_course_student(env, &session.course_id, &session.student);
+    }
+
+    if event_emission(env).session_recorded {
+        emit_analytics_event!(
+            env,
+            symbol_short!("analytics"),
+            session.student.clone(),
+            AnalyticsEventData::SessionRecorded(SessionRecordedEvent {
+                session_id: session.session_id.clone()
+            })
+        );
+    }
+
+    Ok(())
+}
+
+/// Deletes sessions whose last activity is before `cutoff`, examining at most
+/// `limit` students and resuming from where the previous call stopped.
+///
+/// Affected students have their progress analytics and stored leaderboard
+/// entries rebuilt from the sessions that remain, and the course's session
+/// counts are reduced. Returns the number of sessions removed.
+fn prune_sessions_before(env: &Env, cutoff: u64, limit: u32) -> u32 {
+    let mut courses = AnalyticsStorage::get_tracked_courses(env);
+    let (mut course_idx, mut student_idx) = AnalyticsStorage::get_prune_cursor(env);
+    let mut courses_changed = false;
+    let mut budget = limit;
+    let mut removed: u32 = 0;
+
+    while budget > 0 && course_idx < courses.len() {
+        let course_id = courses.get(course_idx).unwrap();
+        let mut students = AnalyticsStorage::get_course_students(env, &course_id);
+        let mut students_changed = false;
+        let mut course_removed: u32 = 0;
+
+        while budget > 0 && student_idx < students.len() {
+            budget -= 1;
+            let student = students.get(student_idx).unwrap();
+            let (pruned, kept) = prune_student_sessions(env, &student, &course_id, cutoff);
+            course_removed += pruned;
+            if kept == 0 {
+                students.remove(student_idx);
+                students_changed = true;
+            } else {
+                student_idx += 1;
+            }
+        }
+
+        if students_changed {
+            AnalyticsStorage::set_course_students(env, &course_id, &students);
+        }
+        if course_removed > 0 {
+            AnalyticsStorage::remove_session_samples(env, &course_id, course_removed);
+            removed += course_removed;
+        }
+        if student_idx < students.len() {
+            break; // out of budget part-way through the course
+        }
+
+        student_idx = 0;
+        if students.is_empty() {
+            courses.remove(course_idx);
+            courses_changed = true;
+        } else {
+            course_idx += 1;
+        }
+    }
+
+    if courses_changed {
+        AnalyticsStorage::set_tracked_courses(env, &courses);
+    }
+    if course_idx >= courses.len() {
+        // Pass complete; the next call starts over
+        course_idx = 0;
+        student_idx = 0;
+    }
+    AnalyticsStorage::set_prune_cursor(env, course_idx, student_idx);
+    removed
+}
+
+/// Deletes a student's sessions in a course last active before `cutoff`,
+/// returning how many were removed and how many remain.
+fn prune_student_sessions(
+    env: &Env,
+    student: &Address,
+    course_id: &Symbol,
+    cutoff: u64,
+) -> (u32, u32) {
+    let session_ids = AnalyticsStorage::get_student_sessions(env, student, course_id);
+    let mut kept: Vec<BytesN<32>> = Vec::new(env);
+    for session_id in session_ids.iter() {
+        if let Some(session) = AnalyticsStorage::get_session(env, &session_id) {
+            if session.start_time.max(session.end_time) < cutoff {
+                AnalyticsStorage::remove_session(env, &session_id);
+            } else {
+                kept.push_back(session_id);
+            }
+        }
+    }
+
+    let pruned = session_ids.len() - kept.len();
+    if pruned > 0 {
+        AnalyticsStorage::set_student_sessions(env, student, course_id, &kept);
+        rebuild_progress_analytics(env, student, course_id, &kept);
+        ReportGenerator::refresh_stored_leaderboards(env, course_id, student);
+    }
+    (pruned, kept.len())
+}
+
+/// Recomputes a student's progress analytics by replaying their remaining
+/// completed sessions in recorded order.
+fn rebuild_progress_analytics(
+    env: &Env,
+    student: &Address,
+    course_id: &Symbol,
+    session_ids: &Vec<BytesN<32>>,
+) {
+    AnalyticsStorage::remove_progress_analytics(env, student, course_id);
+    for session_id in session_ids.iter() {
+        if let Some(session) = AnalyticsStorage::get_session(env, &session_id) {
+            if session.end_time > 0 {
+                update_progress_analytics(
+                    env,
+                    &session,
+                    session.end_time,
+                    session.score,
+                    session.completion_percentage,
+                );
+            }
+        }
+    }
+}
+
 /// Check and award achievements based on the completed session.
 fn check_and_award_achievements(
     env: &Env,
@@ -245,6 +484,7 @@ fn check_and_award_achievements(
     analytics: &ProgressAnalytics,
 ) {
     let mut achievements = AnalyticsStorage::get_student_achievements(env, &session.student);
+    let previously_earned = achievements.len();
     let now = env.ledger().timestamp();
 
     // Excellence: score >= 95
@@ -313,6 +553,20 @@ fn check_and_award_achievements(
     }
 
     AnalyticsStorage::set_student_achievements(env, &session.student, &achievements);
+
+    if event_emission(env).achievement_earned {
+        for achievement in achievements.iter().skip(previously_earned as usize) {
+            emit_analytics_event!(
+                env,
+                symbol_short!("analytics"),
+                session.student.clone(),
+                AnalyticsEventData::AchievementEarned(AnalyticsAchievementEarnedEvent {
+                    achievement_id: achievement.achievement_id,
+                    earned_date: achievement.earned_date
+                })
+            );
+        }
+    }
 }
 
 #[contractimpl]
@@ -360,6 +614,14 @@ impl Analytics {
 
     /// Records the start of a new learning session.
     ///
+    /// When a sample rate is set with
+    /// [`set_sample_rate_percent`](Self::set_sample_rate_percent), only the sessions of a
+    /// deterministic sample of students (chosen by hashing the student address)
+    /// are stored; every session is still counted in
+    /// [`get_session_sample_counts`](Self::get_session_sample_counts). Skipped
+    /// sessions leave no per-session entry, so replaying one is counted again
+    /// rather than rejected.
+    ///
     /// # Arguments
     /// * `session` - Full [`LearningSession`] data for the new session.
     ///
@@ -374,30 +636,117 @@ impl Analytics {
     pub fn record_session(env: Env, session: LearningSession) -> Result<(), AnalyticsError> {
         require_initialized(&env)?;
         session.student.require_auth();
+        store_new_session(&env, &session)
+    }
 
-        // Issue #414: validate that start_time is a plausible UTC epoch second so
-        // that achievement earned_date and streak calculations are timezone-safe.
-        validate_utc_timestamp(session.start_time).map_err(|_| AnalyticsError::InvalidTimestamp)?;
+    /// Records a new learning session, deduplicating client retries by idempotency key.
+    ///
+    /// Keys are scoped per student. A repeated call with a key the student has already
+    /// used succeeds without storing anything and returns the session ID recorded the
+    /// first time, even if the retry carries a freshly generated `session_id`.
+    ///
+    /// # Arguments
+    /// * `session` - Full [`LearningSession`] data for the new session.
+    /// * `idempotency_key` - Client-supplied 32-byte key identifying this logical session.
+    ///
+    /// # Errors
+    /// Returns [`AnalyticsError::NotInitialized`] if the contract is not initialized.
+    /// Returns [`AnalyticsError::SessionAlreadyExists`] if a first-time key carries an existing session ID.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let session_id = client.record_session_idempotent(&session, &idempotency_key);
+    /// ```
+    pub fn record_session_idempotent(
+        env: Env,
+        session: LearningSession,
+        idempotency_key: BytesN<32>,
+    ) -> Result<BytesN<32>, AnalyticsError> {
+        require_initialized(&env)?;
+        session.student.require_auth();
 
-        if AnalyticsStorage::has_session(&env, &session.session_id) {
-            return Err(AnalyticsError::SessionAlreadyExists);
+        if let Some(existing) =
+            AnalyticsStorage::get_idempotent_session(&env, &session.student, &idempotency_key)
+        {
+            return Ok(existing);
         }
 
-        AnalyticsStorage::set_session(&env, &session);
-        AnalyticsStorage::add_course_student(&env, &session.course_id, &session.student);
-
-        emit_analytics_event!(
+        store_new_session(&env, &session)?;
+        AnalyticsStorage::set_idempotent_session(
             &env,
-            symbol_short!("analytics"),
-            session.student.clone(),
-            AnalyticsEventData::SessionRecorded(SessionRecordedEvent {
-                session_id: session.session_id.clone()
-            })
+            &session.student,
+            &idempotency_key,
+            &session.session_id,
         );
+        Ok(session.session_id)
+    }
 
+    /// Records a learning session on a student's behalf from a trusted contract.
+    ///
+    /// Contracts such as the assessment contract report sessions while the
+    /// student's own authorization covers only the outer call, so
+    /// [`record_session`](Self::record_session)'s `session.student.require_auth()`
+    /// would fail there. Instead the calling contract passes its own address as
+    /// `recorder`, which it authorizes implicitly, and the admin must have
+    /// trusted it with [`set_trusted_recorder`](Self::set_trusted_recorder).
+    ///
+    /// # Arguments
+    /// * `recorder` - Address of the calling contract; must authorize the call.
+    /// * `session` - Full [`LearningSession`] data for the new session.
+    ///
+    /// # Errors
+    /// Returns [`AnalyticsError::NotInitialized`] if the contract is not initialized.
+    /// Returns [`AnalyticsError::Unauthorized`] if `recorder` is not trusted.
+    /// Returns [`AnalyticsError::SessionAlreadyExists`] if a session with the same ID already exists.
+    ///
+    /// # Example
+    /// ```ignore
+    /// client.record_session_for(&assessment_contract, &session);
+    /// ```
+    pub fn record_session_for(
+        env: Env,
+        recorder: Address,
+        session: LearningSession,
+    ) -> Result<(), AnalyticsError> {
+        require_initialized(&env)?;
+        recorder.require_auth();
+        if !AnalyticsStorage::is_trusted_recorder(&env, &recorder) {
+            return Err(AnalyticsError::Unauthorized);
+        }
+        store_new_session(&env, &session)
+    }
+
+    /// Allows or disallows a contract to record sessions through
+    /// [`record_session_for`](Self::record_session_for). Requires admin authorization.
+    ///
+    /// # Errors
+    /// Returns [`AnalyticsError::Unauthorized`] if the caller is not the admin.
+    ///
+    /// # Example
+    /// ```ignore
+    /// client.set_trusted_recorder(&admin, &assessment_contract, &true);
+    /// ```
+    pub fn set_trusted_recorder(
+        env: Env,
+        admin: Address,
+        recorder: Address,
+        trusted: bool,
+    ) -> Result<(), AnalyticsError> {
+        require_admin(&env, &admin)?;
+        AnalyticsStorage::set_trusted_recorder(&env, &recorder, trusted);
         Ok(())
     }
 
+    /// Returns whether a contract may record sessions on students' behalf.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let trusted = client.is_trusted_recorder(&assessment_contract);
+    /// ```
+    pub fn is_trusted_recorder(env: Env, recorder: Address) -> bool {
+        AnalyticsStorage::is_trusted_recorder(&env, &recorder)
+    }
+
     /// Marks an existing learning session as completed with final metrics.
     ///
     /// # Arguments
@@ -406,6 +755,9 @@ impl Analytics {
     /// * `final_score` - Optional score achieved during the session.
     /// * `completion_percentage` - Percentage of module content completed (0–100).
     ///
+    /// While sampling is enabled, completing an unknown session succeeds without
+    /// changing anything, since sessions skipped by sampling are not stored.
+    ///
     /// # Errors
     /// Returns [`AnalyticsError::SessionNotFound`] if the session does not exist.
     ///
@@ -422,8 +774,12 @@ impl Analytics {
     ) -> Result<(), AnalyticsError> {
         require_initialized(&env)?;
 
-        let mut session = AnalyticsStorage::get_session(&env, &session_id)
-            .ok_or(AnalyticsError::SessionNotFound)?;
+        let mut session = match AnalyticsStorage::get_session(&env, &session_id) {
+            Some(session) => session,
+            // Sessions skipped by sampling were never stored; completing them is a no-op
+            None if sampling_enabled(&env) => return Ok(()),
+            None => return Err(AnalyticsError::SessionNotFound),
+        };
 
         session.student.require_auth();
 
@@ -463,14 +819,16 @@ impl Analytics {
 
         check_and_award_achievements(&env, &session, final_score, &updated_analytics);
 
-        emit_analytics_event!(
-            &env,
-            symbol_short!("analytics"),
-            session.student.clone(),
-            AnalyticsEventData::SessionCompleted(SessionCompletedEvent {
-                session_id: session_id.clone()
-            })
-        );
+        if event_emission(&env).session_completed {
+            emit_analytics_event!(
+                &env,
+                symbol_short!("analytics"),
+                session.student.clone(),
+                AnalyticsEventData::SessionCompleted(SessionCompletedEvent {
+                    session_id: session_id.clone()
+                })
+            );
+        }
 
         Ok(())
     }
@@ -521,8 +879,56 @@ impl Analytics {
             .ok_or(AnalyticsError::StudentNotFound)
     }
 
+    /// Returns how many sessions were recorded for a course and how many of
+    /// them were stored under the sample rate.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let counts = client.get_session_sample_counts(&course_id);
+    /// ```
+    pub fn get_session_sample_counts(env: Env, course_id: Symbol) -> SessionSampleCounts {
+        AnalyticsStorage::get_session_sample_counts(&env, &course_id)
+    }
+
+    /// Sets the percent of students whose sessions are stored; `0` or `100`
+    /// stores every session. Requires admin authorization.
+    ///
+    /// # Errors
+    /// Returns [`AnalyticsError::Unauthorized`] if the caller is not the admin.
+    /// Returns [`AnalyticsError::InvalidPercentage`] if `percent` is above 100.
+    ///
+    /// # Example
+    /// ```ignore
+    /// client.set_sample_rate_percent(&admin, &25);
+    /// ```
+    pub fn set_sample_rate_percent(
+        env: Env,
+        admin: Address,
+        percent: u32,
+    ) -> Result<(), AnalyticsError> {
+        require_admin(&env, &admin)?;
+        if percent > 100 {
+            return Err(AnalyticsError::InvalidPercentage);
+        }
+        AnalyticsStorage::set_sample_rate_percent(&env, percent);
+        Ok(())
+    }
+
+    /// Returns the percent of students whose sessions are stored (`0` stores all).
+    ///
+    /// # Example
+    /// ```ignore
+    /// let percent = client.get_sample_rate_percent();
+    /// ```
+    pub fn get_sample_rate_percent(env: Env) -> u32 {
+        AnalyticsStorage::get_sample_rate_percent(&env)
+    }
+
     /// Returns course-wide analytics aggregated from all enrolled students.
     ///
+    /// Under session sampling, student counts and time invested are scaled up
+    /// by recorded/stored sessions to estimate the full course population.
+    ///
     /// # Errors
     /// Returns [`AnalyticsError::CourseNotFound`] if no students are enrolled in the course.
     ///
@@ -595,17 +1001,25 @@ impl Analytics {
             0
         };
 
+        // Sampled students stand in for the whole course; rates are unaffected
+        let counts = AnalyticsStorage::get_session_sample_counts(&env, &course_id);
+        let population = total_students.saturating_add(counts.skipped_students);
         let analytics = CourseAnalytics {
             course_id: course_id.clone(),
-            total_students,
-            active_students,
+            total_students: population,
+            active_students: scale_to_population(active_students as u64, total_students, population)
+                as u32,
             completion_rate,
             average_completion_time,
             average_score,
             dropout_rate,
             most_difficult_module: None,
             easiest_module: None,
-            total_time_invested,
+            total_time_invested: scale_to_population(
+                total_time_invested,
+                total_students,
+                population,
+            ),
         };
 
         AnalyticsStorage::set_course_analytics(&env, &course_id, &analytics);
@@ -696,6 +1110,102 @@ impl Analytics {
         Ok(analytics)
     }
 
+    /// Sets the percent decline from the prior module at which
+    /// [`get_dropoff_points`](Self::get_dropoff_points) flags a module; the
+    /// default is 25. Requires admin authorization.
+    ///
+    /// # Errors
+    /// Returns [`AnalyticsError::Unauthorized`] if the caller is not the admin.
+    /// Returns [`AnalyticsError::InvalidPercentage`] if `percent` is above 100.
+    ///
+    /// # Example
+    /// ```ignore
+    /// client.set_dropoff_threshold(&admin, &40);
+    /// ```
+    pub fn set_dropoff_threshold(
+        env: Env,
+        admin: Address,
+        percent: u32,
+    ) -> Result<(), AnalyticsError> {
+        require_admin(&env, &admin)?;
+        if percent > 100 {
+            return Err(AnalyticsError::InvalidPercentage);
+        }
+        AnalyticsStorage::set_dropoff_threshold(&env, percent);
+        Ok(())
+    }
+
+    /// Returns the percent decline flagged as a drop-off point.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let percent = client.get_dropoff_threshold();
+    /// ```
+    pub fn get_dropoff_threshold(env: Env) -> u32 {
+        AnalyticsStorage::get_dropoff_threshold(&env)
+    }
+
+    /// Returns the modules where learners abandon a course, in course order.
+    ///
+    /// Modules are ordered by their earliest recorded session. A module's
+    /// completion rate is the share of the course's students who completed
+    /// it, and its drop-off rate is the percentage decline from the prior
+    /// module's rate; the first module is compared against every student who
+    /// started the course. Modules whose drop-off rate reaches
+    /// the [drop-off threshold](Self::set_dropoff_threshold) are returned with
+    /// that rate.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let dropoffs = client.get_dropoff_points(&course_id);
+    /// ```
+    pub fn get_dropoff_points(env: Env, course_id: Symbol) -> Vec<(Symbol, u32)> {
+        let students = AnalyticsStorage::get_course_students(&env, &course_id);
+        let mut first_seen: Map<Symbol, u64> = Map::new(&env);
+        let mut completions: Map<Symbol, u32> = Map::new(&env);
+
+        for student in students.iter() {
+            let mut completed: Map<Symbol, bool> = Map::new(&env);
+            for sid in AnalyticsStorage::get_student_sessions(&env, &student, &course_id).iter() {
+                let Some(session) = AnalyticsStorage::get_session(&env, &sid) else {
+                    continue;
+                };
+                let seen = first_seen.get(session.module_id.clone()).unwrap_or(u64::MAX);
+                first_seen.set(session.module_id.clone(), seen.min(session.start_time));
+                if session.completion_percentage == 100 {
+                    completed.set(session.module_id, true);
+                }
+            }
+            for module_id in completed.keys().iter() {
+                let count = completions.get(module_id.clone()).unwrap_or(0);
+                completions.set(module_id, count + 1);
+            }
+        }
+
+        // Insertion sort into course order by earliest session start
+        let mut ordered: Vec<Symbol> = Vec::new(&env);
+        for (module_id, seen) in first_seen.iter() {
+            let mut index = ordered.len();
+            while index > 0 && first_seen.get(ordered.get(index - 1).unwrap()).unwrap_or(0) > seen {
+                index -= 1;
+            }
+            ordered.insert(index, module_id);
+        }
+
+        let threshold = AnalyticsStorage::get_dropoff_threshold(&env);
+        let mut prior_rate: u32 = 100;
+        let mut dropoffs = Vec::new(&env);
+        for module_id in ordered.iter() {
+            let rate = completions.get(module_id.clone()).unwrap_or(0) * 100 / students.len();
+            let dropoff_rate = prior_rate.saturating_sub(rate) * 100 / prior_rate.max(1);
+            if dropoff_rate > 0 && dropoff_rate >= threshold {
+                dropoffs.push_back((module_id, dropoff_rate));
+            }
+            prior_rate = rate;
+        }
+        dropoffs
+    }
+
     // ─────────────────────────────────────────────────────────
     // Reports
     // ─────────────────────────────────────────────────────────
@@ -798,7 +1308,37 @@ impl Analytics {
         course_id: Symbol,
         metric: LeaderboardMetric,
     ) -> Vec<LeaderboardEntry> {
-        AnalyticsStorage::get_leaderboard(&env, &course_id, &metric)
+        let stored = AnalyticsStorage::get_leaderboard(&env, &course_id, &metric);
+        ReportGenerator::visible_leaderboard_entries(&env, &stored, 0)
+    }
+
+    /// Shows or hides a student on every leaderboard.
+    ///
+    /// Opted-out students are left out of generated and stored rankings, but
+    /// their sessions and progress analytics are still recorded and remain
+    /// available through the per-student queries.
+    ///
+    /// # Arguments
+    /// * `user` - Student changing their visibility; must authorize the call.
+    /// * `visible` - `false` to opt out of leaderboards, `true` to opt back in.
+    ///
+    /// # Example
+    /// ```ignore
+    /// client.set_leaderboard_visibility(&student, &false);
+    /// ```
+    pub fn set_leaderboard_visibility(env: Env, user: Address, visible: bool) {
+        user.require_auth();
+        AnalyticsStorage::set_leaderboard_hidden(&env, &user, !visible);
+    }
+
+    /// Returns whether a student currently appears on leaderboards.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let visible = client.get_leaderboard_visibility(&student);
+    /// ```
+    pub fn get_leaderboard_visibility(env: Env, user: Address) -> bool {
+        !AnalyticsStorage::is_leaderboard_hidden(&env, &user)
     }
 
     /// Returns all achievements earned by the given student.
@@ -862,29 +1402,36 @@ impl Analytics {
 
     /// Returns daily aggregated metrics for a course within the given date range.
     ///
+    /// With a `smoothing_window` above 1, each point is replaced by the trailing
+    /// moving average over its own day and the preceding `smoothing_window - 1`
+    /// days, where days without metrics count as zero activity; `None` or
+    /// `Some(1)` returns the raw daily points.
+    ///
     /// # Example
     /// ```ignore
-    /// let trends = client.get_completion_trends(&course_id, &start, &end);
+    /// let trends = client.get_completion_trends(&course_id, &start, &end, &Some(7));
     /// ```
     pub fn get_completion_trends(
         env: Env,
         course_id: Symbol,
         start_date: u64,
         end_date: u64,
+        smoothing_window: Option<u32>,
     ) -> Vec<AggregatedMetrics> {
         let mut result: Vec<AggregatedMetrics> = Vec::new(&env);
         if start_date >= end_date {
             return result;
         }
-        let mut current = utc_day_index(start_date) * shared::timestamp_utils::SECS_PER_DAY;
+        let first_day = utc_day_index(start_date) * shared::timestamp_utils::SECS_PER_DAY;
         let end_day = utc_day_index(end_date) * shared::timestamp_utils::SECS_PER_DAY;
+        let mut current = first_day;
         while current <= end_day {
             if let Some(metrics) = AnalyticsStorage::get_daily_metrics(&env, &course_id, current) {
                 result.push_back(metrics);
             }
             current += shared::timestamp_utils::SECS_PER_DAY;
         }
-        result
+        smooth_trend(&env, &result, first_day, smoothing_window.unwrap_or(1))
     }
 
     // ─────────────────────────────────────────────────────────
@@ -927,12 +1474,7 @@ impl Analytics {
     ) -> Vec<LeaderboardEntry> {
         let stored = AnalyticsStorage::get_leaderboard(&env, &course_id, &metric);
         if !stored.is_empty() {
-            let cap = if limit > 0 && limit < stored.len() { limit } else { stored.len() };
-            let mut result: Vec<LeaderboardEntry> = Vec::new(&env);
-            for i in 0..cap {
-                result.push_back(stored.get(i).unwrap());
-            }
-            return result;
+            return ReportGenerator::visible_leaderboard_entries(&env, &stored, limit);
         }
         generate_leaderboard_from_storage(&env, &course_id, &metric, limit)
     }
@@ -988,6 +1530,37 @@ impl Analytics {
         Ok(())
     }
 
+    /// Switches the session-recorded, session-completed and achievement-earned
+    /// events on or off individually. Disabled categories are still stored and
+    /// queryable. Requires admin authorization.
+    ///
+    /// # Errors
+    /// Returns [`AnalyticsError::Unauthorized`] if the caller is not the admin.
+    ///
+    /// # Example
+    /// ```ignore
+    /// client.set_event_emission(&admin, &emission);
+    /// ```
+    pub fn set_event_emission(
+        env: Env,
+        admin: Address,
+        emission: EventEmissionConfig,
+    ) -> Result<(), AnalyticsError> {
+        require_admin(&env, &admin)?;
+        AnalyticsStorage::set_event_emission(&env, &emission);
+        Ok(())
+    }
+
+    /// Returns which event categories are emitted (all on unless configured).
+    ///
+    /// # Example
+    /// ```ignore
+    /// let emission = client.get_event_emission();
+    /// ```
+    pub fn get_event_emission(env: Env) -> EventEmissionConfig {
+        AnalyticsStorage::get_event_emission(&env)
+    }
+
     /// Returns the current analytics configuration.
     ///
     /// # Example
@@ -1012,9 +1585,13 @@ impl Analytics {
         Ok(())
     }
 
-    /// Removes sessions created before `before_date`. Requires admin authorization.
+    /// Removes sessions whose last activity is before `before_date`. Requires admin authorization.
     ///
-    /// Returns the count of sessions removed.
+    /// Examines up to 50 students per call, resuming where the previous call
+    /// stopped; use [`cleanup_old_data_batch`](Self::cleanup_old_data_batch) to
+    /// choose the batch size. Progress analytics, stored leaderboards and
+    /// session counts of affected students are updated from their remaining
+    /// sessions. Returns the count of sessions removed.
     ///
     /// # Errors
     /// Returns [`AnalyticsError::Unauthorized`] if the caller is not the admin.
@@ -1028,11 +1605,139 @@ impl Analytics {
         admin: Address,
         before_date: u64,
     ) -> Result<u32, AnalyticsError> {
+        Self::cleanup_old_data_batch(env, admin, before_date, DEFAULT_PRUNE_BATCH)
+    }
+
+    /// Like [`cleanup_old_data`](Self::cleanup_old_data), but examines at most
+    /// `limit` students per call so large deployments can be pruned over
+    /// several transactions. Requires admin authorization.
+    ///
+    /// # Errors
+    /// Returns [`AnalyticsError::Unauthorized`] if the caller is not the admin.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let cleaned = client.cleanup_old_data_batch(&admin, &cutoff, &20);
+    /// ```
+    pub fn cleanup_old_data_batch(
+        env: Env,
+        admin: Address,
+        before_date: u64,
+        limit: u32,
+    ) -> Result<u32, AnalyticsError> {
+        require_admin(&env, &admin)?;
+        Ok(prune_sessions_before(&env, before_date, limit))
+    }
+
+    /// Applies the configured retention policy, deleting sessions older than
+    /// the retention period. Requires admin authorization.
+    ///
+    /// Intended to be called periodically by a scheduler. Does nothing when the
+    /// retention period is `0`. Examines up to 50 students per call like
+    /// [`cleanup_old_data`](Self::cleanup_old_data); see
+    /// [`enforce_retention_batch`](Self::enforce_retention_batch). Returns the
+    /// count of sessions removed.
+    ///
+    /// # Errors
+    /// Returns [`AnalyticsError::Unauthorized`] if the caller is not the admin.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let removed = client.enforce_retention(&admin);
+    /// ```
+    pub fn enforce_retention(env: Env, admin: Address) -> Result<u32, AnalyticsError> {
+        Self::enforce_retention_batch(env, admin, DEFAULT_PRUNE_BATCH)
+    }
+
+    /// Like [`enforce_retention`](Self::enforce_retention), but examines at most
+    /// `limit` students per call. Requires admin authorization.
+    ///
+    /// # Errors
+    /// Returns [`AnalyticsError::Unauthorized`] if the caller is not the admin.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let removed = client.enforce_retention_batch(&admin, &20);
+    /// ```
+    pub fn enforce_retention_batch(
+        env: Env,
+        admin: Address,
+        limit: u32,
+    ) -> Result<u32, AnalyticsError> {
+        require_admin(&env, &admin)?;
+        let retention_period = AnalyticsStorage::get_retention_period(&env);
+        if retention_period == 0 {
+            return Ok(0);
+        }
+        let cutoff = env.ledger().timestamp().saturating_sub(retention_period);
+        Ok(prune_sessions_before(&env, cutoff, limit))
+    }
+
+    /// Sets how long sessions are kept before
+    /// [`enforce_retention`](Self::enforce_retention) deletes them; `0` keeps
+    /// them forever. Requires admin authorization.
+    ///
+    /// # Errors
+    /// Returns [`AnalyticsError::Unauthorized`] if the caller is not the admin.
+    ///
+    /// # Example
+    /// ```ignore
+    /// client.set_retention_period(&admin, &(90 * 86_400));
+    /// ```
+    pub fn set_retention_period(
+        env: Env,
+        admin: Address,
+        seconds: u64,
+    ) -> Result<(), AnalyticsError> {
         require_admin(&env, &admin)?;
-        // In a production implementation this would iterate and prune old sessions.
-        // Here we return 0 to indicate no data was removed (gas-safe stub).
-        let _ = before_date;
-        Ok(0)
+        AnalyticsStorage::set_retention_period(&env, seconds);
+        Ok(())
+    }
+
+    /// Returns the session retention period in seconds (`0` keeps sessions forever).
+    ///
+    /// # Example
+    /// ```ignore
+    /// let seconds = client.get_retention_period();
+    /// ```
+    pub fn get_retention_period(env: Env) -> u64 {
+        AnalyticsStorage::get_retention_period(&env)
+    }
+
+    /// Registers courses recorded before course tracking existed so retention
+    /// covers them. Requires admin authorization.
+    ///
+    /// Courses without students, or already tracked, are skipped. Returns the
+    /// number of courses newly tracked.
+    ///
+    /// # Errors
+    /// Returns [`AnalyticsError::Unauthorized`] if the caller is not the admin.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let added = client.backfill_tracked_courses(&admin, &course_ids);
+    /// ```
+    pub fn backfill_tracked_courses(
+        env: Env,
+        admin: Address,
+        course_ids: Vec<Symbol>,
+    ) -> Result<u32, AnalyticsError> {
+        require_admin(&env, &admin)?;
+        let mut courses = AnalyticsStorage::get_tracked_courses(&env);
+        let mut added: u32 = 0;
+        for course_id in course_ids.iter() {
+            if courses.contains(&course_id)
+                || AnalyticsStorage::get_course_students(&env, &course_id).is_empty()
+            {
+                continue;
+            }
+            courses.push_back(course_id);
+            added += 1;
+        }
+        if added > 0 {
+            AnalyticsStorage::set_tracked_courses(&env, &courses);
+        }
+        Ok(added)
     }
 
     /// Returns the total number of students enrolled in the given course.
@@ -1473,7 +2178,7 @@ impl Analytics {
 mod tests {
     use super::*;
     use crate::errors::AnalyticsError;
-    use crate::types::{AnalyticsConfig, DifficultyThresholds};
+    use crate::types::{AnalyticsConfig, DifficultyThresholds, EventEmissionConfig};
     use soroban_sdk::{testutils::Address as _, Address, BytesN, Env};
 
     fn default_config(_env: &Env) -> AnalyticsConfig {
@@ -1543,6 +2248,196 @@ mod tests {
         assert_eq!(stored.score, Some(85));
     }
 
+    fn retry_session(env: &Env, student: &Address, id_byte: u8) -> crate::types::LearningSession {
+        crate::types::LearningSession {
+            session_id: BytesN::from_array(env, &[id_byte; 32]),
+            student: student.clone(),
+            course_id: soroban_sdk::Symbol::new(env, "RETRY"),
+            module_id: soroban_sdk::Symbol::new(env, "MOD1"),
+            start_time: 1_700_000_000,
+            end_time: 0,
+            completion_percentage: 0,
+            time_spent: 0,
+            interactions: 1,
+            score: None,
+            session_type: crate::types::SessionType::Study,
+        }
+    }
+
+    #[test]
+    fn test_record_session_idempotent_deduplicates_retries() {
+        let (env, client, _admin) = setup();
+        let student = Address::generate(&env);
+        let key = BytesN::from_array(&env, &[42u8; 32]);
+
+        let first = client.record_session_idempotent(&retry_session(&env, &student, 1), &key);
+        // Retry after a timeout arrives with a freshly generated session id
+        let retried = client.record_session_idempotent(&retry_session(&env, &student, 2), &key);
+
+        assert_eq!(first, BytesN::from_array(&env, &[1u8; 32]));
+        assert_eq!(retried, first);
+        assert!(client.get_session(&BytesN::from_array(&env, &[2u8; 32])).is_none());
+        let sessions =
+            client.get_student_sessions(&student, &soroban_sdk::Symbol::new(&env, "RETRY"));
+        assert_eq!(sessions.len(), 1);
+    }
+
+    #[test]
+    fn test_idempotency_keys_are_scoped_per_student() {
+        let (env, client, _admin) = setup();
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        let key = BytesN::from_array(&env, &[7u8; 32]);
+
+        client.record_session_idempotent(&retry_session(&env, &alice, 1), &key);
+        let bob_id = client.record_session_idempotent(&retry_session(&env, &bob, 2), &key);
+
+        assert_eq!(bob_id, BytesN::from_array(&env, &[2u8; 32]));
+        assert!(client.get_session(&bob_id).is_some());
+    }
+
+    fn seed_completed_session(
+        env: &Env,
+        client: &AnalyticsClient,
+        student: &Address,
+        id_byte: u8,
+        start_time: u64,
+        score: u32,
+    ) -> BytesN<32> {
+        let session_id = BytesN::from_array(env, &[id_byte; 32]);
+        client.record_session(&crate::types::LearningSession {
+            session_id: session_id.clone(),
+            student: student.clone(),
+            course_id: soroban_sdk::Symbol::new(env, "RETAIN"),
+            module_id: soroban_sdk::Symbol::new(env, "MOD1"),
+            start_time,
+            end_time: 0,
+            completion_percentage: 0,
+            time_spent: 0,
+            interactions: 1,
+            score: None,
+            session_type: crate::types::SessionType::Study,
+        });
+        client.complete_session(&session_id, &(start_time + 600), &Some(score), &100);
+        session_id
+    }
+
+    #[test]
+    fn test_enforce_retention_removes_only_expired_sessions() {
+        use soroban_sdk::testutils::Ledger;
+
+        let (env, client, admin) = setup();
+        client.set_retention_period(&admin, &86_400);
+
+        let now = 1_700_000_000 + 10 * 86_400;
+        env.ledger().set_timestamp(now);
+        let course = soroban_sdk::Symbol::new(&env, "RETAIN");
+        let active = Address::generate(&env);
+        let lapsed = Address::generate(&env);
+
+        let old = seed_completed_session(&env, &client, &active, 1, 1_700_000_000, 40);
+        let fresh = seed_completed_session(&env, &client, &active, 2, now - 3_600, 90);
+        let lapsed_old = seed_completed_session(&env, &client, &lapsed, 3, 1_700_000_000, 60);
+
+        assert_eq!(client.enforce_retention(&admin), 2);
+
+        assert!(client.get_session(&old).is_none());
+        assert!(client.get_session(&lapsed_old).is_none());
+        assert!(client.get_session(&fresh).is_some());
+        assert_eq!(client.get_student_sessions(&active, &course).len(), 1);
+
+        // Aggregates only reflect the remaining session
+        let progress = client.get_progress_analytics(&active, &course);
+        assert_eq!(progress.total_sessions, 1);
+        assert_eq!(progress.average_score, Some(90));
+        assert_eq!(progress.first_activity, now - 3_600);
+        assert!(client.try_get_progress_analytics(&lapsed, &course).is_err());
+        assert_eq!(client.get_course_students_count(&course), 1);
+
+        // Nothing left to expire
+        assert_eq!(client.enforce_retention(&admin), 0);
+    }
+
+    #[test]
+    fn test_retention_prunes_in_batches_and_updates_aggregates() {
+        use soroban_sdk::testutils::Ledger;
+
+        let (env, client, admin) = setup();
+        client.set_retention_period(&admin, &86_400);
+
+        let now = 1_700_000_000 + 10 * 86_400;
+        env.ledger().set_timestamp(now);
+        let course = soroban_sdk::Symbol::new(&env, "RETAIN");
+        let active = Address::generate(&env);
+        let lapsed = Address::generate(&env);
+        let idle = Address::generate(&env);
+
+        seed_completed_session(&env, &client, &active, 1, 1_700_000_000, 40);
+        seed_completed_session(&env, &client, &active, 2, now - 3_600, 90);
+        seed_completed_session(&env, &client, &lapsed, 3, 1_700_000_000, 80);
+        let idle_old = seed_completed_session(&env, &client, &idle, 4, 1_700_000_000, 70);
+        client.generate_leaderboard(&course, &LeaderboardMetric::TotalScore, &10);
+
+        // The first batch stops after two students; the next one resumes there
+        assert_eq!(client.enforce_retention_batch(&admin, &2), 2);
+        assert!(client.get_session(&idle_old).is_some());
+        assert_eq!(client.enforce_retention_batch(&admin, &2), 1);
+        assert!(client.get_session(&idle_old).is_none());
+
+        let board = client.get_leaderboard(&course, &LeaderboardMetric::TotalScore);
+        assert_eq!(board.len(), 1);
+        let top = board.get(0).unwrap();
+        assert_eq!((top.student, top.score, top.rank), (active, 90, 1));
+
+        let counts = client.get_session_sample_counts(&course);
+        assert_eq!((counts.recorded, counts.stored), (1, 1));
+    }
+
+    #[test]
+    fn test_backfilled_courses_covered_by_retention() {
+        use soroban_sdk::testutils::Ledger;
+
+        let (env, client, admin) = setup();
+        client.set_retention_period(&admin, &86_400);
+        env.ledger().set_timestamp(1_700_000_000 + 10 * 86_400);
+
+        let course = soroban_sdk::Symbol::new(&env, "RETAIN");
+        let student = Address::generate(&env);
+        let old = seed_completed_session(&env, &client, &student, 1, 1_700_000_000, 70);
+
+        // Courses recorded before tracking existed are invisible to retention
+        env.as_contract(&client.address, || {
+            AnalyticsStorage::set_tracked_courses(&env, &Vec::new(&env))
+        });
+        assert_eq!(client.enforce_retention(&admin), 0);
+
+        let unknown = soroban_sdk::Symbol::new(&env, "NOPE");
+        let course_ids = soroban_sdk::vec![&env, course.clone(), unknown];
+        assert_eq!(client.backfill_tracked_courses(&admin, &course_ids), 1);
+        assert_eq!(client.backfill_tracked_courses(&admin, &course_ids), 0);
+
+        assert_eq!(client.enforce_retention(&admin), 1);
+        assert!(client.get_session(&old).is_none());
+    }
+
+    #[test]
+    fn test_enforce_retention_disabled_and_admin_only() {
+        let (env, client, admin) = setup();
+        let student = Address::generate(&env);
+        let old = seed_completed_session(&env, &client, &student, 1, 1_700_000_000, 70);
+
+        // A retention period of 0 keeps everything
+        assert_eq!(client.enforce_retention(&admin), 0);
+        assert!(client.get_session(&old).is_some());
+
+        let attacker = Address::generate(&env);
+        let result = client.try_enforce_retention(&attacker);
+        assert_eq!(result, Err(Ok(AnalyticsError::Unauthorized)));
+        let result = client.try_set_retention_period(&attacker, &1);
+        assert_eq!(result, Err(Ok(AnalyticsError::Unauthorized)));
+        assert_eq!(client.get_retention_period(), 0);
+    }
+
     // ── Issue #372: pagination ────────────────────────────────
 
     #[test]
@@ -1715,4 +2610,462 @@ mod tests {
         let result = client.try_update_config(&attacker, &default_config(&env));
         assert_eq!(result, Err(Ok(AnalyticsError::Unauthorized)));
     }
+
+    #[test]
+    fn test_opted_out_student_excluded_from_leaderboards() {
+        use crate::types::LeaderboardMetric;
+        use soroban_sdk::testutils::Ledger;
+
+        let (env, client, _admin) = setup();
+        env.ledger().set_timestamp(1_700_000_000 + 86_400);
+        let course = soroban_sdk::Symbol::new(&env, "RETAIN");
+        let private = Address::generate(&env);
+        let public = Address::generate(&env);
+        seed_completed_session(&env, &client, &private, 1, 1_700_000_000, 95);
+        seed_completed_session(&env, &client, &public, 2, 1_700_000_000, 80);
+
+        // Cache a board that still ranks the student, then opt them out
+        client.generate_leaderboard(&course, &LeaderboardMetric::TotalScore, &10);
+        client.set_leaderboard_visibility(&private, &false);
+        assert!(!client.get_leaderboard_visibility(&private));
+
+        let stored = client.get_leaderboard(&course, &LeaderboardMetric::TotalScore);
+        let top = client.get_top_performers(&course, &LeaderboardMetric::TotalScore, &5);
+        let regenerated = client.generate_leaderboard(&course, &LeaderboardMetric::TotalScore, &10);
+        for board in [stored, top, regenerated] {
+            assert_eq!(board.len(), 1);
+            assert_eq!(board.get(0).unwrap().student, public);
+            assert_eq!(board.get(0).unwrap().rank, 1);
+        }
+
+        // Their own analytics are still tracked
+        let analytics = client.get_progress_analytics(&private, &course);
+        assert_eq!(analytics.average_score, Some(95));
+        assert_eq!(client.get_student_sessions(&private, &course).len(), 1);
+    }
+
+    #[test]
+    fn test_opting_back_in_restores_leaderboard_entry() {
+        use crate::types::LeaderboardMetric;
+        use soroban_sdk::testutils::Ledger;
+
+        let (env, client, _admin) = setup();
+        env.ledger().set_timestamp(1_700_000_000 + 86_400);
+        let course = soroban_sdk::Symbol::new(&env, "RETAIN");
+        let student = Address::generate(&env);
+
+        client.set_leaderboard_visibility(&student, &false);
+        seed_completed_session(&env, &client, &student, 1, 1_700_000_000, 90);
+        let board = client.generate_leaderboard(&course, &LeaderboardMetric::TotalScore, &10);
+        assert!(board.is_empty());
+
+        client.set_leaderboard_visibility(&student, &true);
+        let board = client.generate_leaderboard(&course, &LeaderboardMetric::TotalScore, &10);
+        assert_eq!(board.len(), 1);
+        assert_eq!(board.get(0).unwrap().student, student);
+    }
+
+    #[test]
+    fn test_sampling_stores_subset_of_sessions() {
+        use soroban_sdk::testutils::Ledger;
+
+        let (env, client, admin) = setup();
+        client.set_sample_rate_percent(&admin, &25);
+        env.ledger().set_timestamp(1_700_000_000 + 86_400);
+
+        let mut stored = 0u32;
+        for i in 0..200u32 {
+            let student = Address::generate(&env);
+            let mut session = retry_session(&env, &student, 0);
+            session.session_id = BytesN::from_array(&env, &[i as u8; 32]);
+            session.course_id = soroban_sdk::Symbol::new(&env, "SAMPLED");
+            client.record_session(&session);
+            client.complete_session(&session.session_id, &(1_700_000_000 + 1_800), &Some(80), &100);
+            if client.get_session(&session.session_id).is_some() {
+                stored += 1;
+            }
+        }
+
+        assert!((30..=70).contains(&stored), "stored {} of 200 sessions", stored);
+        let counts = client.get_session_sample_counts(&soroban_sdk::Symbol::new(&env, "SAMPLED"));
+        assert_eq!(counts.recorded, 200);
+        assert_eq!(counts.stored, stored);
+    }
+
+    #[test]
+    fn test_sample_rate_is_a_percentage_set_by_admin() {
+        let (env, client, admin) = setup();
+        assert_eq!(client.get_sample_rate_percent(), 0);
+
+        let result = client.try_set_sample_rate_percent(&admin, &101);
+        assert_eq!(result, Err(Ok(AnalyticsError::InvalidPercentage)));
+        let attacker = Address::generate(&env);
+        let result = client.try_set_sample_rate_percent(&attacker, &50);
+        assert_eq!(result, Err(Ok(AnalyticsError::Unauthorized)));
+
+        client.set_sample_rate_percent(&admin, &50);
+        assert_eq!(client.get_sample_rate_percent(), 50);
+    }
+
+    #[test]
+    fn test_sampled_course_analytics_scaled_to_population() {
+        use soroban_sdk::testutils::Ledger;
+
+        let (env, client, admin) = setup();
+        client.set_sample_rate_percent(&admin, &50);
+        env.ledger().set_timestamp(1_700_000_000 + 86_400);
+
+        let course = soroban_sdk::Symbol::new(&env, "SAMPLED");
+        for i in 0..40u32 {
+            let student = Address::generate(&env);
+            let mut session = retry_session(&env, &student, 0);
+            session.session_id = BytesN::from_array(&env, &[i as u8; 32]);
+            session.course_id = course.clone();
+            client.record_session(&session);
+            client.complete_session(&session.session_id, &(1_700_000_000 + 1_800), &Some(80), &100);
+        }
+
+        assert!(client.get_course_students_count(&course) < 40);
+        let analytics = client.get_course_analytics(&course);
+        assert_eq!(analytics.total_students, 40);
+        assert_eq!(analytics.total_time_invested, 40 * 1_800);
+        assert_eq!(analytics.completion_rate, 100);
+        assert_eq!(analytics.average_score, Some(80));
+    }
+
+    #[test]
+    fn test_sampling_keeps_or_skips_each_student_whole() {
+        use soroban_sdk::testutils::Ledger;
+
+        let (env, client, admin) = setup();
+        client.set_sample_rate_percent(&admin, &50);
+        env.ledger().set_timestamp(1_700_000_000 + 86_400);
+
+        let course = soroban_sdk::Symbol::new(&env, "SAMPLED");
+        let mut skipped: Option<crate::types::LearningSession> = None;
+        let mut stored = 0u32;
+        for s in 0..20u32 {
+            let student = Address::generate(&env);
+            let mut kept = 0u32;
+            for k in 0..3u32 {
+                let mut session = retry_session(&env, &student, (s * 3 + k) as u8);
+                session.course_id = course.clone();
+                client.record_session(&session);
+                if client.get_session(&session.session_id).is_some() {
+                    kept += 1;
+                } else {
+                    skipped = Some(session);
+                }
+            }
+            assert!(kept == 0 || kept == 3, "student split across the sample");
+            stored += kept;
+        }
+
+        // A replayed skipped session is skipped again: counted, but never stored
+        let replay = skipped.expect("no student was skipped");
+        client.record_session(&replay);
+        assert!(client.get_session(&replay.session_id).is_none());
+        // Completing it is a no-op rather than an error
+        client.complete_session(&replay.session_id, &(1_700_000_000 + 1_800), &None, &100);
+
+        let counts = client.get_session_sample_counts(&course);
+        assert_eq!(counts.recorded, 61);
+        assert_eq!(counts.stored, stored);
+        assert_eq!(counts.skipped_students, 20 - stored / 3);
+        assert_eq!(client.get_course_analytics(&course).total_students, 20);
+    }
+
+    /// Store a day of course metrics with the given completions and average score.
+    fn seed_daily_metrics(
+        env: &Env,
+        client: &AnalyticsClient,
+        course: &Symbol,
+        date: u64,
+        completions: u32,
+        average_score: Option<u32>,
+    ) {
+        let metrics = AggregatedMetrics {
+            course_id: course.clone(),
+            date,
+            active_students: completions * 2,
+            total_sessions: completions * 3,
+            total_time: completions as u64 * 600,
+            completions,
+            average_score,
+        };
+        env.as_contract(&client.address, || {
+            AnalyticsStorage::set_daily_metrics(env, course, date, &metrics)
+        });
+    }
+
+    #[test]
+    fn test_completion_trends_raw_when_window_is_one() {
+        let (env, client, _) = setup();
+        let course = Symbol::new(&env, "SPARSE");
+        let start = 19_700 * 86_400;
+        for (day, completions) in [10u32, 0, 8, 2, 6].iter().enumerate() {
+            let date = start + day as u64 * 86_400;
+            seed_daily_metrics(&env, &client, &course, date, *completions, None);
+        }
+
+        let end = start + 4 * 86_400;
+        let raw = client.get_completion_trends(&course, &start, &end, &None);
+        assert_eq!(raw.len(), 5);
+        assert_eq!(client.get_completion_trends(&course, &start, &end, &Some(1)), raw);
+        assert_eq!(raw.get(1).unwrap().completions, 0);
+        assert_eq!(raw.get(3).unwrap().completions, 2);
+    }
+
+    #[test]
+    fn test_completion_trends_smoothed_with_moving_average() {
+        let (env, client, _) = setup();
+        let course = Symbol::new(&env, "SPARSE");
+        let start = 19_700 * 86_400;
+        let completions = [10u32, 0, 8, 2, 6, 12, 1];
+        let scores = [Some(90u32), None, Some(60), Some(70), None, Some(80), Some(40)];
+        for day in 0..completions.len() {
+            let date = start + day as u64 * 86_400;
+            seed_daily_metrics(&env, &client, &course, date, completions[day], scores[day]);
+        }
+
+        let end = start + 6 * 86_400;
+        let window = 3usize;
+        let smoothed = client.get_completion_trends(&course, &start, &end, &Some(window as u32));
+        assert_eq!(smoothed.len(), completions.len() as u32);
+
+        for i in 0..completions.len() {
+            let first = (i + 1).saturating_sub(window);
+            let span = &completions[first..=i];
+            let expected = span.iter().sum::<u32>() / span.len() as u32;
+            let (score_total, scored_days) =
+                scores[first..=i].iter().flatten().fold((0, 0), |(t, n), s| (t + s, n + 1));
+            let expected_score = score_total.checked_div(scored_days);
+
+            let point = smoothed.get(i as u32).unwrap();
+            assert_eq!(point.date, start + i as u64 * 86_400);
+            assert_eq!(point.completions, expected);
+            assert_eq!(point.active_students, span.iter().sum::<u32>() * 2 / span.len() as u32);
+            assert_eq!(point.average_score, expected_score);
+        }
+
+        // The noisy zero day is lifted towards its neighbours
+        assert_eq!(smoothed.get(1).unwrap().completions, 5);
+        assert_eq!(smoothed.get(4).unwrap().completions, 5);
+    }
+
+    #[test]
+    fn test_completion_trends_smoothing_counts_missing_days_as_zero() {
+        let (env, client, _) = setup();
+        let course = Symbol::new(&env, "GAPPY");
+        let start = 19_700 * 86_400;
+        // Activity on days 0, 1 and 5 only
+        for (day, completions, score) in [(0u64, 9u32, Some(90u32)), (1, 3, None), (5, 12, Some(60))] {
+            seed_daily_metrics(&env, &client, &course, start + day * 86_400, completions, score);
+        }
+
+        let end = start + 6 * 86_400;
+        let smoothed = client.get_completion_trends(&course, &start, &end, &Some(3));
+        assert_eq!(smoothed.len(), 3);
+
+        // Days 0-1 are clipped to the range start
+        assert_eq!(smoothed.get(0).unwrap().completions, 9);
+        assert_eq!(smoothed.get(1).unwrap().completions, 6);
+        // Day 5 averages over days 3-5, two of them empty, not over days 0, 1 and 5
+        let day5 = smoothed.get(2).unwrap();
+        assert_eq!(day5.date, start + 5 * 86_400);
+        assert_eq!(day5.completions, 4);
+        assert_eq!(day5.active_students, 8);
+        assert_eq!(day5.average_score, Some(60));
+    }
+
+    /// Records and completes a session for `module` of the TAPER course.
+    fn seed_module_session(
+        env: &Env,
+        client: &AnalyticsClient,
+        student: &Address,
+        id_byte: u8,
+        module: &str,
+        offset: u64,
+        completion: u32,
+    ) {
+        let mut session = retry_session(env, student, id_byte);
+        session.course_id = soroban_sdk::Symbol::new(env, "TAPER");
+        session.module_id = soroban_sdk::Symbol::new(env, module);
+        session.start_time += offset;
+        client.record_session(&session);
+        client.complete_session(
+            &session.session_id,
+            &(session.start_time + 600),
+            &None,
+            &completion,
+        );
+    }
+
+    /// Four students finish M1 and M2, but only one of them finishes M3
+    fn seed_tapering_course(env: &Env, client: &AnalyticsClient) {
+        use soroban_sdk::testutils::Ledger;
+
+        env.ledger().set_timestamp(1_700_000_000 + 86_400);
+        for i in 0..4u8 {
+            let student = Address::generate(env);
+            seed_module_session(env, client, &student, i * 3 + 1, "M1", 0, 100);
+            seed_module_session(env, client, &student, i * 3 + 2, "M2", 1_000, 100);
+            if i < 2 {
+                let completion = if i == 0 { 100 } else { 40 };
+                seed_module_session(env, client, &student, i * 3 + 3, "M3", 2_000, completion);
+            }
+        }
+    }
+
+    #[test]
+    fn test_dropoff_points_flag_module_where_sessions_taper_off() {
+        let (env, client, _) = setup();
+        seed_tapering_course(&env, &client);
+
+        let dropoffs = client.get_dropoff_points(&soroban_sdk::Symbol::new(&env, "TAPER"));
+        assert_eq!(dropoffs.len(), 1);
+        assert_eq!(dropoffs.get(0).unwrap(), (soroban_sdk::Symbol::new(&env, "M3"), 75));
+    }
+
+    #[test]
+    fn test_dropoff_points_respect_configured_threshold() {
+        let (env, client, admin) = setup();
+        seed_tapering_course(&env, &client);
+
+        client.set_dropoff_threshold(&admin, &80);
+        assert_eq!(client.get_dropoff_threshold(), 80);
+        assert_eq!(client.try_set_dropoff_threshold(&admin, &101), Err(Ok(AnalyticsError::InvalidPercentage)));
+        assert!(client.get_dropoff_points(&soroban_sdk::Symbol::new(&env, "TAPER")).is_empty());
+    }
+
+    /// Stands in for a contract, like assessment, that reports sessions it did not start.
+    #[soroban_sdk::contract]
+    struct SessionRecorder;
+
+    #[soroban_sdk::contractimpl]
+    impl SessionRecorder {
+        pub fn report(
+            env: Env,
+            analytics: Address,
+            session: crate::types::LearningSession,
+        ) -> Result<(), AnalyticsError> {
+            AnalyticsClient::new(&env, &analytics)
+                .try_record_session_for(&env.current_contract_address(), &session)
+                .map_err(|_| AnalyticsError::Unauthorized)?
+                .map_err(|_| AnalyticsError::Unauthorized)
+        }
+    }
+
+    #[test]
+    fn test_trusted_recorder_records_without_student_auth() {
+        use soroban_sdk::testutils::{MockAuth, MockAuthInvoke};
+        use soroban_sdk::IntoVal;
+
+        // No mock_all_auths: only the admin's own calls are authorized
+        let env = Env::default();
+        let analytics_id = env.register(Analytics, ());
+        let client = AnalyticsClient::new(&env, &analytics_id);
+        let admin = Address::generate(&env);
+        let config = default_config(&env);
+        client
+            .mock_auths(&[MockAuth {
+                address: &admin,
+                invoke: &MockAuthInvoke {
+                    contract: &analytics_id,
+                    fn_name: "initialize",
+                    args: (admin.clone(), config.clone()).into_val(&env),
+                    sub_invokes: &[],
+                },
+            }])
+            .initialize(&admin, &config);
+        let recorder_id = env.register(SessionRecorder, ());
+        let recorder = SessionRecorderClient::new(&env, &recorder_id);
+        let student = Address::generate(&env);
+        let session = retry_session(&env, &student, 7);
+
+        assert_eq!(
+            recorder.try_report(&analytics_id, &session),
+            Err(Ok(AnalyticsError::Unauthorized))
+        );
+
+        client
+            .mock_auths(&[MockAuth {
+                address: &admin,
+                invoke: &MockAuthInvoke {
+                    contract: &analytics_id,
+                    fn_name: "set_trusted_recorder",
+                    args: (&admin, &recorder_id, true).into_val(&env),
+                    sub_invokes: &[],
+                },
+            }])
+            .set_trusted_recorder(&admin, &recorder_id, &true);
+        assert!(client.is_trusted_recorder(&recorder_id));
+
+        recorder.report(&analytics_id, &session);
+        assert_eq!(client.get_session(&session.session_id).unwrap().student, student);
+        // The student never authorized anything, so the direct entry point still refuses
+        assert!(client.try_record_session(&retry_session(&env, &student, 8)).is_err());
+    }
+
+    /// Event type topic of every standard event emitted by the last call.
+    fn emitted_event_types(env: &Env) -> Vec<Symbol> {
+        use soroban_sdk::{testutils::Events as _, TryFromVal};
+
+        let mut types = Vec::new(env);
+        for (_, topics, _) in env.events().all().iter() {
+            if let Some(Ok(event_type)) = topics.get(3).map(|t| Symbol::try_from_val(env, &t)) {
+                types.push_back(event_type);
+            }
+        }
+        types
+    }
+
+    #[test]
+    fn test_disabled_session_events_suppressed_but_session_stored() {
+        use soroban_sdk::testutils::Ledger;
+
+        let (env, client, admin) = setup();
+        let mut emission = EventEmissionConfig::all_enabled();
+        emission.session_recorded = false;
+        emission.session_completed = false;
+        client.set_event_emission(&admin, &emission);
+        env.ledger().set_timestamp(1_700_000_000 + 86_400);
+        let student = Address::generate(&env);
+        let session = retry_session(&env, &student, 1);
+
+        client.record_session(&session);
+        assert!(!emitted_event_types(&env).contains(Symbol::new(&env, "session_recorded")));
+        client.complete_session(&session.session_id, &(1_700_000_000 + 1_800), &Some(80), &100);
+        assert!(!emitted_event_types(&env).contains(Symbol::new(&env, "session_completed")));
+
+        let stored = client.get_session(&session.session_id).unwrap();
+        assert_eq!(stored.completion_percentage, 100);
+        assert_eq!(stored.score, Some(80));
+    }
+
+    #[test]
+    fn test_disabled_achievement_events_suppressed_but_achievements_stored() {
+        use soroban_sdk::testutils::Ledger;
+
+        let (env, client, admin) = setup();
+        env.ledger().set_timestamp(1_700_000_000 + 86_400);
+        let student = Address::generate(&env);
+        seed_completed_session(&env, &client, &student, 1, 1_700_000_000, 96);
+        let earned = emitted_event_types(&env);
+        assert!(earned.contains(Symbol::new(&env, "achievement_earned")));
+        assert!(earned.contains(Symbol::new(&env, "session_completed")));
+
+        let mut emission = client.get_event_emission();
+        emission.achievement_earned = false;
+        client.set_event_emission(&admin, &emission);
+        let other = Address::generate(&env);
+        seed_completed_session(&env, &client, &other, 2, 1_700_000_000, 96);
+        let suppressed = emitted_event_types(&env);
+        assert!(!suppressed.contains(Symbol::new(&env, "achievement_earned")));
+        assert!(suppressed.contains(Symbol::new(&env, "session_completed")));
+
+        let achievements = client.get_student_achievements(&other);
+        assert_eq!(achievements.len(), client.get_student_achievements(&student).len());
+        assert!(achievements.iter().any(|a| a.achievement_id == Symbol::new(&env, "EXCELLENCE")));
+    }
 }
@@ -4,7 +4,7 @@ use crate::{
     storage::AnalyticsStorage,
     types::{
         Achievement, AggregatedMetrics, AnalyticsFilter, LeaderboardEntry, LeaderboardMetric,
-        LearningSession, OptionalSessionType, ProgressReport, ReportPeriod,
+        LearningSession, OptionalSessionType, ProgressAnalytics, ProgressReport, ReportPeriod,
     },
 };
 use shared::timestamp_utils::{utc_day_start, SECS_PER_DAY};
@@ -219,34 +219,14 @@ impl ReportGenerator {
         // Calculate scores for each student based on metric type
         for i in 0..students.len() {
             let student = students.get(i).unwrap();
+            if AnalyticsStorage::is_leaderboard_hidden(env, &student) {
+                continue;
+            }
 
             if let Some(analytics) =
                 AnalyticsStorage::get_progress_analytics(env, &student, course_id)
             {
-                let score = match metric {
-                    LeaderboardMetric::CompletionSpeed => {
-                        if analytics.completion_percentage == 100 {
-                            // Lower time is better, so invert the score
-                            let completion_time =
-                                analytics.last_activity - analytics.first_activity;
-                            if completion_time > 0 {
-                                1000000 / completion_time as u32 // Arbitrary scaling
-                            } else {
-                                0
-                            }
-                        } else {
-                            0
-                        }
-                    }
-                    LeaderboardMetric::TotalScore => analytics.average_score.unwrap_or(0),
-                    LeaderboardMetric::ConsistencyScore => {
-                        // Calculate consistency based on streak and regular activity
-                        analytics.streak_days * 10 + (analytics.total_sessions / 10)
-                    }
-                    LeaderboardMetric::TimeSpent => {
-                        (analytics.total_time_spent / 3600) as u32 // Convert to hours
-                    }
-                };
+                let score = Self::leaderboard_score(&analytics, metric);
 
                 let entry = LeaderboardEntry {
                     student: student.clone(),
@@ -441,11 +421,101 @@ impl ReportGenerator {
         true
     }
 
+    /// Drop opted-out students from a stored leaderboard and re-rank the rest
+    ///
+    /// Stored boards may predate a student's opt-out, so reads filter them
+    /// again. A `limit` of 0 keeps every visible entry.
+    pub fn visible_leaderboard_entries(
+        env: &Env,
+        entries: &Vec<LeaderboardEntry>,
+        limit: u32,
+    ) -> Vec<LeaderboardEntry> {
+        let mut visible: Vec<LeaderboardEntry> = Vec::new(env);
+        for mut entry in entries.iter() {
+            if limit > 0 && visible.len() >= limit {
+                break;
+            }
+            if AnalyticsStorage::is_leaderboard_hidden(env, &entry.student) {
+                continue;
+            }
+            entry.rank = visible.len() + 1;
+            visible.push_back(entry);
+        }
+        visible
+    }
+
+    /// Score a student's progress analytics under a leaderboard metric
+    fn leaderboard_score(analytics: &ProgressAnalytics, metric: &LeaderboardMetric) -> u32 {
+        match metric {
+            LeaderboardMetric::CompletionSpeed => {
+                if analytics.completion_percentage == 100 {
+                    // Lower time is better, so invert the score
+                    let completion_time = analytics.last_activity - analytics.first_activity;
+                    if completion_time > 0 {
+                        1000000 / completion_time as u32 // Arbitrary scaling
+                    } else {
+                        0
+                    }
+                } else {
+                    0
+                }
+            }
+            LeaderboardMetric::TotalScore => analytics.average_score.unwrap_or(0),
+            LeaderboardMetric::ConsistencyScore => {
+                // Calculate consistency based on streak and regular activity
+                analytics.streak_days * 10 + (analytics.total_sessions / 10)
+            }
+            LeaderboardMetric::TimeSpent => {
+                (analytics.total_time_spent / 3600) as u32 // Convert to hours
+            }
+        }
+    }
+
+    /// Rescore a student's entries on a course's stored leaderboards, dropping
+    /// them when the student no longer has progress analytics in the course.
+    pub fn refresh_stored_leaderboards(env: &Env, course_id: &Symbol, student: &Address) {
+        let analytics = AnalyticsStorage::get_progress_analytics(env, student, course_id);
+        let metrics = [
+            LeaderboardMetric::CompletionSpeed,
+            LeaderboardMetric::TotalScore,
+            LeaderboardMetric::ConsistencyScore,
+            LeaderboardMetric::TimeSpent,
+        ];
+
+        for metric in metrics.iter() {
+            let stored = AnalyticsStorage::get_leaderboard(env, course_id, metric);
+            let mut entries: Vec<LeaderboardEntry> = Vec::new(env);
+            let mut changed = false;
+            for mut entry in stored.iter() {
+                if entry.student != *student {
+                    entries.push_back(entry);
+                    continue;
+                }
+                changed = true;
+                if let Some(analytics) = &analytics {
+                    entry.score = Self::leaderboard_score(analytics, metric);
+                    entries.push_back(entry);
+                }
+            }
+            if !changed {
+                continue;
+            }
+
+            Self::sort_leaderboard_entries(env, &mut entries);
+            for i in 0..entries.len() {
+                let mut entry = entries.get(i).unwrap();
+                entry.rank = i + 1;
+                entries.set(i, entry);
+            }
+            AnalyticsStorage::set_leaderboard(env, course_id, metric, &entries);
+        }
+    }
+
     /// Sort leaderboard entries by score (descending)
     fn sort_leaderboard_entries(_env: &Env, entries: &mut Vec<LeaderboardEntry>) {
         // Simple bubble sort for small datasets
         for _i in 0..entries.len() {
-            for j in 0..entries.len() - 1 {
+            for j in 0..entries.len().saturating_sub(1) {
                 let entry_j = entries.get(j).unwrap();
                 let entry_j_plus_1 = entries.get(j + 1).unwrap();
 
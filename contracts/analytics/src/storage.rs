@@ -1,8 +1,8 @@
 #![allow(dead_code)]
 use crate::types::{
-    Achievement, AggregatedMetrics, AnalyticsConfig, CourseAnalytics, DataKey, InsightType,
-    LeaderboardEntry, LearningSession, MLInsight, ModuleAnalytics, ProgressAnalytics,
-    ProgressReport,
+    Achievement, AggregatedMetrics, AnalyticsConfig, CourseAnalytics, DataKey, EventEmissionConfig,
+    InsightType, LeaderboardEntry, LearningSession, MLInsight, ModuleAnalytics, ProgressAnalytics,
+    ProgressReport, SessionSampleCounts,
 };
 use soroban_sdk::{Address, BytesN, Env, Symbol, Vec};
 
@@ -25,6 +25,27 @@ impl AnalyticsStorage {
         env.storage().persistent().get(&key)
     }
 
+    /// Get the session ID recorded under a student's idempotency key
+    pub fn get_idempotent_session(
+        env: &Env,
+        student: &Address,
+        idempotency_key: &BytesN<32>,
+    ) -> Option<BytesN<32>> {
+        let key = DataKey::SessionIdempotencyKey(student.clone(), idempotency_key.clone());
+        env.storage().persistent().get(&key)
+    }
+
+    /// Remember which session a student's idempotency key produced
+    pub fn set_idempotent_session(
+        env: &Env,
+        student: &Address,
+        idempotency_key: &BytesN<32>,
+        session_id: &BytesN<32>,
+    ) {
+        let key = DataKey::SessionIdempotencyKey(student.clone(), idempotency_key.clone());
+        env.storage().persistent().set(&key, session_id);
+    }
+
     /// Add session to student's session list
     pub fn add_student_session(
         env: &Env,
@@ -103,10 +124,104 @@ impl AnalyticsStorage {
             }
         }
 
+        if students.is_empty() {
+            Self::add_tracked_course(env, course_id);
+        }
         students.push_back(student.clone());
         env.storage().persistent().set(&key, &students);
     }
 
+    /// Replace the list of students in a course
+    pub fn set_course_students(env: &Env, course_id: &Symbol, students: &Vec<Address>) {
+        let key = DataKey::CourseStudents(course_id.clone());
+        env.storage().persistent().set(&key, students);
+    }
+
+    /// Replace a student's session list for a course
+    pub fn set_student_sessions(
+        env: &Env,
+        student: &Address,
+        course_id: &Symbol,
+        sessions: &Vec<BytesN<32>>,
+    ) {
+        let key = DataKey::StudentSessions(student.clone(), course_id.clone());
+        env.storage().persistent().set(&key, sessions);
+    }
+
+    /// Remove progress analytics for a student in a course
+    pub fn remove_progress_analytics(env: &Env, student: &Address, course_id: &Symbol) {
+        let key = DataKey::ProgressAnalytics(student.clone(), course_id.clone());
+        env.storage().persistent().remove(&key);
+    }
+
+    /// Get all courses that have recorded sessions
+    pub fn get_tracked_courses(env: &Env) -> Vec<Symbol> {
+        env.storage().persistent().get(&DataKey::TrackedCourses).unwrap_or(Vec::new(env))
+    }
+
+    /// Replace the list of courses that have recorded sessions
+    pub fn set_tracked_courses(env: &Env, courses: &Vec<Symbol>) {
+        env.storage().persistent().set(&DataKey::TrackedCourses, courses);
+    }
+
+    fn add_tracked_course(env: &Env, course_id: &Symbol) {
+        let mut courses = Self::get_tracked_courses(env);
+        if !courses.contains(course_id) {
+            courses.push_back(course_id.clone());
+            Self::set_tracked_courses(env, &courses);
+        }
+    }
+
+    /// Get recorded and stored session counts for a course
+    pub fn get_session_sample_counts(env: &Env, course_id: &Symbol) -> SessionSampleCounts {
+        let key = DataKey::CourseSessionCounts(course_id.clone());
+        env.storage().persistent().get(&key).unwrap_or(SessionSampleCounts {
+            recorded: 0,
+            stored: 0,
+            skipped_students: 0,
+        })
+    }
+
+    /// Count a recorded session, and whether sampling kept it.
+    ///
+    /// Skipped sessions are only counted, never stored individually; the first
+    /// skipped session of a student counts them as a skipped student.
+    pub fn record_session_sample(env: &Env, session: &LearningSession, stored: bool) {
+        let mut counts = Self::get_session_sample_counts(env, &session.course_id);
+        counts.recorded += 1;
+        if stored {
+            counts.stored += 1;
+        } else {
+            let student_key =
+                DataKey::SkippedStudent(session.course_id.clone(), session.student.clone());
+            if !env.storage().persistent().has(&student_key) {
+                env.storage().persistent().set(&student_key, &true);
+                counts.skipped_students += 1;
+            }
+        }
+        let key = DataKey::CourseSessionCounts(session.course_id.clone());
+        env.storage().persistent().set(&key, &counts);
+    }
+
+    /// Remove pruned sessions from a course's recorded and stored counts
+    pub fn remove_session_samples(env: &Env, course_id: &Symbol, removed: u32) {
+        let mut counts = Self::get_session_sample_counts(env, course_id);
+        counts.recorded = counts.recorded.saturating_sub(removed);
+        counts.stored = counts.stored.saturating_sub(removed);
+        let key = DataKey::CourseSessionCounts(course_id.clone());
+        env.storage().persistent().set(&key, &counts);
+    }
+
+    /// Get the (course index, student index) where session pruning resumes
+    pub fn get_prune_cursor(env: &Env) -> (u32, u32) {
+        env.storage().persistent().get(&DataKey::PruneCursor).unwrap_or((0, 0))
+    }
+
+    /// Store the position where session pruning resumes
+    pub fn set_prune_cursor(env: &Env, course_idx: u32, student_idx: u32) {
+        env.storage().persistent().set(&DataKey::PruneCursor, &(course_idx, student_idx));
+    }
+
     /// Get all students in a course
     pub fn get_course_students(env: &Env, course_id: &Symbol) -> Vec<Address> {
         let key = DataKey::CourseStudents(course_id.clone());
@@ -218,6 +333,38 @@ impl AnalyticsStorage {
         env.storage().persistent().get(&key).unwrap_or(Vec::new(env))
     }
 
+    /// Set whether a student is left out of leaderboards
+    pub fn set_leaderboard_hidden(env: &Env, student: &Address, hidden: bool) {
+        let key = DataKey::LeaderboardHidden(student.clone());
+        if hidden {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+    }
+
+    /// Check if a student opted out of leaderboards
+    pub fn is_leaderboard_hidden(env: &Env, student: &Address) -> bool {
+        let key = DataKey::LeaderboardHidden(student.clone());
+        env.storage().persistent().get(&key).unwrap_or(false)
+    }
+
+    /// Allow or disallow a contract to record sessions on students' behalf
+    pub fn set_trusted_recorder(env: &Env, recorder: &Address, trusted: bool) {
+        let key = DataKey::TrustedRecorder(recorder.clone());
+        if trusted {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+    }
+
+    /// Check if a contract may record sessions on students' behalf
+    pub fn is_trusted_recorder(env: &Env, recorder: &Address) -> bool {
+        let key = DataKey::TrustedRecorder(recorder.clone());
+        env.storage().persistent().get(&key).unwrap_or(false)
+    }
+
     /// Store an ML insight
     pub fn set_ml_insight(env: &Env, insight: &MLInsight) {
         let key = DataKey::MLInsight(
@@ -251,6 +398,49 @@ impl AnalyticsStorage {
         env.storage().instance().get(&key)
     }
 
+    /// Store how long sessions are kept before retention deletes them
+    pub fn set_retention_period(env: &Env, seconds: u64) {
+        env.storage().instance().set(&DataKey::RetentionPeriod, &seconds);
+    }
+
+    /// Get the retention period in seconds; 0 (the default) keeps sessions forever
+    pub fn get_retention_period(env: &Env) -> u64 {
+        env.storage().instance().get(&DataKey::RetentionPeriod).unwrap_or(0)
+    }
+
+    /// Store the percent of students whose sessions are kept
+    pub fn set_sample_rate_percent(env: &Env, percent: u32) {
+        env.storage().instance().set(&DataKey::SampleRatePercent, &percent);
+    }
+
+    /// Get the sample rate; 0 (the default) stores every session
+    pub fn get_sample_rate_percent(env: &Env) -> u32 {
+        env.storage().instance().get(&DataKey::SampleRatePercent).unwrap_or(0)
+    }
+
+    /// Store the percent decline flagged as a drop-off point
+    pub fn set_dropoff_threshold(env: &Env, percent: u32) {
+        env.storage().instance().set(&DataKey::DropoffThreshold, &percent);
+    }
+
+    /// Get the drop-off threshold; defaults to 25, a quarter of learners lost
+    pub fn get_dropoff_threshold(env: &Env) -> u32 {
+        env.storage().instance().get(&DataKey::DropoffThreshold).unwrap_or(25)
+    }
+
+    /// Store which event categories are emitted
+    pub fn set_event_emission(env: &Env, emission: &EventEmissionConfig) {
+        env.storage().instance().set(&DataKey::EventEmission, emission);
+    }
+
+    /// Get which event categories are emitted; every category is on by default
+    pub fn get_event_emission(env: &Env) -> EventEmissionConfig {
+        env.storage()
+            .instance()
+            .get(&DataKey::EventEmission)
+            .unwrap_or_else(EventEmissionConfig::all_enabled)
+    }
+
     /// Store admin address
     pub fn set_admin(env: &Env, admin: &Address) {
         let key = DataKey::Admin;
@@ -277,7 +467,6 @@ impl AnalyticsStorage {
     }
 
     /// Remove old sessions (for cleanup)
-    #[allow(dead_code)]
     pub fn remove_session(env: &Env, session_id: &BytesN<32>) {
         let key = DataKey::Session(session_id.clone());
         env.storage().persistent().remove(&key);
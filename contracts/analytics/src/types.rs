@@ -185,15 +185,22 @@ pub enum LeaderboardMetric {
 #[contracttype]
 pub enum DataKey {
     // Learning sessions
-    Session(BytesN<32>),              // session_id
-    StudentSessions(Address, Symbol), // (student, course_id) -> Vec<BytesN<32>>
+    Session(BytesN<32>),                        // session_id
+    StudentSessions(Address, Symbol),           // (student, course_id) -> Vec<BytesN<32>>
+    SessionIdempotencyKey(Address, BytesN<32>), // (student, idempotency_key) -> session_id
 
     // Progress analytics
     ProgressAnalytics(Address, Symbol), // (student, course_id)
 
     // Course analytics
-    CourseAnalytics(Symbol), // course_id
-    CourseStudents(Symbol),  // course_id -> Vec<Address>
+    CourseAnalytics(Symbol),     // course_id
+    CourseStudents(Symbol),      // course_id -> Vec<Address>
+    TrackedCourses,              // Vec<Symbol> of courses with recorded sessions
+    CourseSessionCounts(Symbol), // course_id -> SessionSampleCounts
+    PruneCursor,                 // (course index, student index) where pruning resumes
+
+    // Marker for students left out of the storage sample
+    SkippedStudent(Symbol, Address), // (course_id, student)
 
     // Module analytics
     ModuleAnalytics(Symbol, Symbol), // (course_id, module_id)
@@ -209,15 +216,38 @@ pub enum DataKey {
 
     // Leaderboards
     Leaderboard(Symbol, LeaderboardMetric), // (course_id, metric) -> Vec<LeaderboardEntry>
+    LeaderboardHidden(Address),             // student -> true when opted out of rankings
 
     // Configuration
     Admin,
     AnalyticsConfig,
+    TrustedRecorder(Address), // contract allowed to record sessions on students' behalf
+    RetentionPeriod,          // seconds to keep sessions; 0 keeps them forever
+    SampleRatePercent,        // percent of students whose sessions are stored; 0 or 100 stores all
+    EventEmission,            // EventEmissionConfig; every category on when unset
+    DropoffThreshold,         // percent decline from the prior module flagged as drop-off
 
     // ML Insights
     MLInsight(Address, Symbol, InsightType), // (student, course_id, type)
 }
 
+/// Event categories emitted by analytics mutations; disabling one only skips
+/// the event, the underlying data is still stored
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct EventEmissionConfig {
+    pub session_recorded: bool,
+    pub session_completed: bool,
+    pub achievement_earned: bool,
+}
+
+impl EventEmissionConfig {
+    /// Every category enabled, the behavior before toggles existed
+    pub fn all_enabled() -> Self {
+        Self { session_recorded: true, session_completed: true, achievement_earned: true }
+    }
+}
+
 /// Configuration for analytics calculations
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[contracttype]
@@ -230,6 +260,15 @@ pub struct AnalyticsConfig {
     pub oracle_address: Option<Address>, // External ML oracle address
 }
 
+/// Sessions recorded for a course versus those kept by sampling
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct SessionSampleCounts {
+    pub recorded: u32,         // Every session passed to record_session
+    pub stored: u32,           // Sessions kept in storage under the sample rate
+    pub skipped_students: u32, // Distinct students whose sessions sampling skipped
+}
+
 /// Thresholds for difficulty calculation
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[contracttype]
@@ -37,6 +37,12 @@ pub enum AssessmentError {
     SubmissionNotFound = 25,
     /// The submission has already been finalized and cannot be modified.
     SubmissionAlreadyFinalized = 26,
+    /// The assessment requires answers to be committed and revealed instead of submitted directly.
+    CommitmentRequired = 27,
+    /// No answer commitment was found for the submission.
+    CommitmentNotFound = 28,
+    /// The revealed answers do not hash to the stored commitment.
+    CommitmentMismatch = 29,
 
     // Adaptive / accessibility
     /// The assessment does not have adaptive testing mode enabled.
@@ -47,7 +53,29 @@ pub enum AssessmentError {
     // Integrity / security
     /// The caller is not the admin or a registered security monitor contract.
     SecurityIntegrationMissing = 40,
+    /// The attached proctoring evidence is older than the configured validity window.
+    ProctoringEvidenceExpired = 42,
 
     // Rate limiting
     RateLimitExceeded = 50,
+    /// The attempt cooldown since the student's last submission has not yet elapsed.
+    AttemptCooldownActive = 51,
+
+    // Manual grading
+    /// The rubric is empty, has a zero-point criterion, or does not total the question's max score.
+    InvalidRubric = 60,
+    /// No rubric has been configured for the question.
+    RubricNotFound = 61,
+    /// The per-criterion scores do not match the rubric or do not sum to the awarded score.
+    RubricScoreMismatch = 62,
+    /// The submission is not awaiting manual review.
+    ManualReviewNotRequired = 63,
+    /// The answer to this question has already been manually graded.
+    QuestionAlreadyGraded = 64,
+
+    // Question bank
+    /// The question belongs to a single assessment and cannot be attached elsewhere.
+    NotBankQuestion = 70,
+    /// The assessment already includes this question.
+    QuestionAlreadyAttached = 71,
 }
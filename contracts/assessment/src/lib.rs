@@ -17,7 +17,8 @@ mod test;
 use shared::monitoring::{ContractHealthReport, Monitor};
 use soroban_sdk::xdr::ToXdr;
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short, Address, BytesN, Env, Map, String, Symbol, Vec,
+    contract, contractimpl, contracttype, symbol_short, vec, Address, Bytes, BytesN, Env, IntoVal,
+    Map, String, Symbol, Vec,
 };
 
 #[contracttype]
@@ -32,6 +33,9 @@ pub struct AssessmentExport {
     pub submitted_at: u64,
 }
 
+/// `Question::assessment_id` of questions in the shared bank; assessment ids start at 1.
+const BANK_ASSESSMENT_ID: u64 = 0;
+
 const RL_OP_START_SUBMISSION: u64 = 1;
 const RL_OP_SUBMIT_ANSWERS: u64 = 2;
 
@@ -99,6 +103,39 @@ fn get_questions_for_assessment(env: &Env, assessment_id: u64) -> Vec<Question>
     result
 }
 
+/// Deterministically shuffles question ids (Fisher-Yates) using bytes drawn from `seed`.
+fn shuffle_question_ids(env: &Env, ids: &Vec<u64>, seed: BytesN<32>) -> Vec<u64> {
+    let mut order = ids.clone();
+    let mut entropy = seed.to_array();
+    let mut cursor = 0usize;
+
+    let mut i = order.len();
+    while i > 1 {
+        if cursor + 4 > entropy.len() {
+            entropy = env.crypto().sha256(&Bytes::from_array(env, &entropy)).to_array();
+            cursor = 0;
+        }
+        let r = u32::from_be_bytes([
+            entropy[cursor],
+            entropy[cursor + 1],
+            entropy[cursor + 2],
+            entropy[cursor + 3],
+        ]);
+        cursor += 4;
+
+        let j = r % i;
+        let last = i - 1;
+        if j != last {
+            let a = order.get(j).unwrap();
+            let b = order.get(last).unwrap();
+            order.set(j, b);
+            order.set(last, a);
+        }
+        i -= 1;
+    }
+    order
+}
+
 fn within_schedule(env: &Env, assessment_id: u64) -> bool {
     let schedule: Option<ScheduleConfig> =
         env.storage().persistent().get(&DataKey::Schedule(assessment_id));
@@ -141,6 +178,42 @@ fn append_student_submission(
     env.storage().persistent().set(&key, &ids);
 }
 
+fn get_effective_attempt_cooldown(env: &Env, assessment_id: u64, student: &Address) -> u64 {
+    let cooldown: u64 =
+        env.storage().persistent().get(&DataKey::AttemptCooldown(assessment_id)).unwrap_or(0);
+    if let Some(ac) = get_accommodation(env, student) {
+        let reduction =
+            cooldown.saturating_mul(ac.cooldown_reduction_percent.min(100) as u64) / 100;
+        cooldown - reduction
+    } else {
+        cooldown
+    }
+}
+
+/// Rejects a new attempt until the cooldown since the student's last
+/// submitted attempt at the assessment has elapsed.
+fn require_attempt_cooldown_elapsed(
+    env: &Env,
+    student: &Address,
+    assessment_id: u64,
+) -> Result<(), AssessmentError> {
+    let cooldown = get_effective_attempt_cooldown(env, assessment_id, student);
+    if cooldown == 0 {
+        return Ok(());
+    }
+    let last_submitted_at: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::LastSubmittedAt(student.clone(), assessment_id))
+        .unwrap_or(0);
+    if last_submitted_at > 0
+        && env.ledger().timestamp() < last_submitted_at.saturating_add(cooldown)
+    {
+        return Err(AssessmentError::AttemptCooldownActive);
+    }
+    Ok(())
+}
+
 fn get_submission(env: &Env, submission_id: &BytesN<32>) -> Result<Submission, AssessmentError> {
     env.storage()
         .persistent()
@@ -193,6 +266,342 @@ fn get_integration(env: &Env) -> IntegrationConfig {
     })
 }
 
+/// Derives the analytics session ID for a graded attempt.
+///
+/// Submission IDs are not unique across attempts or assessments, so the
+/// assessment ID and attempt number are folded in.
+fn analytics_session_id(env: &Env, submission: &Submission) -> BytesN<32> {
+    let mut material = Bytes::from_array(env, &submission.submission_id.to_array());
+    material.append(&Bytes::from_array(env, &submission.assessment_id.to_be_bytes()));
+    material.append(&Bytes::from_array(env, &submission.attempt.to_be_bytes()));
+    env.crypto().sha256(&material).into()
+}
+
+/// Records an auto-graded submission in the configured analytics contract as a
+/// completed assessment session.
+///
+/// Best-effort: a missing integration or a failing analytics call never blocks
+/// grading. Each attempt is exported at most once.
+fn export_result_to_analytics(env: &Env, meta: &AssessmentMetadata, submission: &Submission) {
+    let analytics = match get_integration(env).analytics_contract {
+        Some(addr) => addr,
+        None => return,
+    };
+    let session_id = analytics_session_id(env, submission);
+    let key = DataKey::AnalyticsExported(session_id.clone());
+    if env.storage().persistent().has(&key) {
+        return;
+    }
+
+    let score_percent =
+        submission.score.saturating_mul(100).checked_div(submission.max_score).unwrap_or(0);
+    let session = AnalyticsSession {
+        session_id,
+        student: submission.student.clone(),
+        course_id: meta.course_id.clone(),
+        module_id: meta.module_id.clone(),
+        start_time: submission.started_at,
+        end_time: submission.submitted_at,
+        completion_percentage: 100,
+        time_spent: submission.submitted_at.saturating_sub(submission.started_at),
+        interactions: submission.answers.len(),
+        score: Some(score_percent),
+        session_type: AnalyticsSessionType::Assessment,
+    };
+
+    // The student's authorization does not reach nested calls, so analytics must
+    // trust this contract as a recorder (`set_trusted_recorder`) for the export to land.
+    let result = env.try_invoke_contract::<(), soroban_sdk::Error>(
+        &analytics,
+        &Symbol::new(env, "record_session_for"),
+        vec![env, env.current_contract_address().into_val(env), session.into_val(env)],
+    );
+    if let Ok(Ok(())) = result {
+        env.storage().persistent().set(&key, &true);
+    }
+}
+
+/// Marks the assessment's module complete in the configured progress contract
+/// after a passing attempt.
+///
+/// Best-effort: a missing integration or a failing progress call never blocks
+/// grading. Failed attempts leave module progress untouched.
+fn sync_module_progress(env: &Env, meta: &AssessmentMetadata, submission: &Submission) {
+    if !submission.passed {
+        return;
+    }
+    let progress = match get_integration(env).progress_contract {
+        Some(addr) => addr,
+        None => return,
+    };
+
+    let _ = env.try_invoke_contract::<(), soroban_sdk::Error>(
+        &progress,
+        &Symbol::new(env, "update_progress"),
+        vec![
+            env,
+            submission.student.into_val(env),
+            meta.course_id.into_val(env),
+            meta.module_id.into_val(env),
+            100u32.into_val(env),
+        ],
+    );
+}
+
+fn get_proctoring_evidence_window(env: &Env) -> u64 {
+    env.storage().instance().get(&DataKey::ProctoringEvidenceWindow).unwrap_or(0)
+}
+
+/// Storage key of the time proctoring evidence was attached to a submission's attempt.
+fn proctoring_evidence_at_key(submission: &Submission) -> DataKey {
+    DataKey::ProctoringEvidenceAt(
+        submission.submission_id.clone(),
+        submission.assessment_id,
+        submission.attempt,
+    )
+}
+
+/// Rejects grading a proctored assessment whose attached evidence is older than
+/// the configured validity window. Submissions without evidence, or with evidence
+/// attached before attachment times were recorded, are not gated here.
+fn require_valid_proctoring_evidence(
+    env: &Env,
+    meta: &AssessmentMetadata,
+    submission: &Submission,
+) -> Result<(), AssessmentError> {
+    if !meta.config.proctoring_required || !submission.integrity.has_proctoring_evidence {
+        return Ok(());
+    }
+    let attached_at: u64 =
+        match env.storage().persistent().get(&proctoring_evidence_at_key(submission)) {
+            Some(at) => at,
+            None => return Ok(()),
+        };
+    let window = get_proctoring_evidence_window(env);
+    let age = env.ledger().timestamp().saturating_sub(attached_at);
+    if window > 0 && age > window {
+        return Err(AssessmentError::ProctoringEvidenceExpired);
+    }
+    Ok(())
+}
+
+fn is_commit_reveal_required(env: &Env, assessment_id: u64) -> bool {
+    env.storage().persistent().get(&DataKey::CommitRevealRequired(assessment_id)).unwrap_or(false)
+}
+
+fn enforce_answer_rate_limit(env: &Env, student: &Address) -> Result<(), AssessmentError> {
+    let rl = get_rate_limits(env);
+    enforce_rate_limit(
+        env,
+        &DataKey::RateLimit(student.clone(), RL_OP_SUBMIT_ANSWERS),
+        &RateLimitConfig { max_calls: rl.max_answers_per_day, window_seconds: rl.window_seconds },
+    )
+    .map_err(|_| AssessmentError::RateLimitExceeded)
+}
+
+/// Loads a submission that `student` owns and may still answer.
+fn get_open_submission(
+    env: &Env,
+    student: &Address,
+    submission_id: &BytesN<32>,
+) -> Result<(Submission, AssessmentMetadata), AssessmentError> {
+    let submission = get_submission(env, submission_id)?;
+    if submission.student != *student {
+        return Err(AssessmentError::Unauthorized);
+    }
+    if let SubmissionStatus::Finalized = submission.status {
+        return Err(AssessmentError::SubmissionAlreadyFinalized);
+    }
+
+    let meta = get_assessment(env, submission.assessment_id)?;
+    let effective_limit = get_effective_time_limit(env, &meta.config, student);
+    if effective_limit > 0 && env.ledger().timestamp() > submission.started_at + effective_limit {
+        return Err(AssessmentError::AssessmentClosed);
+    }
+    Ok((submission, meta))
+}
+
+fn grade_and_store_submission(
+    env: &Env,
+    mut submission: Submission,
+    meta: &AssessmentMetadata,
+    answers: Vec<SubmittedAnswer>,
+) -> Submission {
+    submission.answers = answers;
+    submission.submitted_at = env.ledger().timestamp();
+    env.storage().persistent().set(
+        &DataKey::LastSubmittedAt(submission.student.clone(), submission.assessment_id),
+        &submission.submitted_at,
+    );
+
+    let questions = get_questions_for_assessment(env, submission.assessment_id);
+    let result = GradingEngine::grade_submission(env, &questions, &submission);
+    submission.score = result.score;
+    submission.max_score = result.max_score;
+    submission.passed = submission.score >= meta.config.pass_score;
+    submission.status = GradingEngine::derive_status(result.requires_manual_review);
+
+    AssessmentEvents::emit_submission_received(
+        env,
+        &submission.submission_id,
+        submission.assessment_id,
+    );
+    AssessmentEvents::emit_submission_graded(
+        env,
+        &submission.submission_id,
+        submission.score,
+        submission.max_score,
+        submission.passed,
+    );
+
+    put_submission(env, &submission);
+    if let SubmissionStatus::AutoGraded = submission.status {
+        export_result_to_analytics(env, meta, &submission);
+        sync_module_progress(env, meta, &submission);
+    }
+    submission
+}
+
+fn get_question(env: &Env, question_id: u64) -> Result<Question, AssessmentError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Question(question_id))
+        .ok_or(AssessmentError::QuestionNotFound)
+}
+
+/// Requires `actor` to be the admin or the instructor who created the assessment.
+fn require_instructor_or_admin(
+    env: &Env,
+    actor: &Address,
+    meta: &AssessmentMetadata,
+) -> Result<(), AssessmentError> {
+    actor.require_auth();
+    if meta.instructor == *actor || get_admin(env) == *actor {
+        return Ok(());
+    }
+    Err(AssessmentError::Unauthorized)
+}
+
+/// Requires `actor` to be the admin or the question's owner: the instructor of
+/// its assessment, or the author of a bank question.
+fn require_question_owner(
+    env: &Env,
+    actor: &Address,
+    question: &Question,
+) -> Result<(), AssessmentError> {
+    if question.assessment_id != BANK_ASSESSMENT_ID {
+        let meta = get_assessment(env, question.assessment_id)?;
+        return require_instructor_or_admin(env, actor, &meta);
+    }
+    actor.require_auth();
+    let author: Option<Address> =
+        env.storage().persistent().get(&DataKey::BankQuestionAuthor(question.question_id));
+    if author.as_ref() == Some(actor) || get_admin(env) == *actor {
+        return Ok(());
+    }
+    Err(AssessmentError::Unauthorized)
+}
+
+fn assessment_has_question(env: &Env, assessment_id: u64, question_id: u64) -> bool {
+    let ids: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::AssessmentQuestions(assessment_id))
+        .unwrap_or(Vec::new(env));
+    ids.contains(question_id)
+}
+
+/// Loads a manually graded question and the student's answer to it within a
+/// submission that is awaiting manual review.
+fn get_manual_review_item(
+    env: &Env,
+    submission_id: &BytesN<32>,
+    question_id: u64,
+) -> Result<(Submission, Question, SubmittedAnswerValue), AssessmentError> {
+    let submission = get_submission(env, submission_id)?;
+    if submission.status != SubmissionStatus::RequiresManualReview {
+        return Err(AssessmentError::ManualReviewNotRequired);
+    }
+    if !assessment_has_question(env, submission.assessment_id, question_id) {
+        return Err(AssessmentError::QuestionNotFound);
+    }
+    let question = get_question(env, question_id)?;
+    if question.answer_key != AnswerKey::Manual {
+        return Err(AssessmentError::InvalidQuestionType);
+    }
+    let answer = submission
+        .answers
+        .iter()
+        .find(|a| a.question_id == question_id)
+        .map(|a| a.value)
+        .ok_or(AssessmentError::InvalidAnswer)?;
+    Ok((submission, question, answer))
+}
+
+/// Checks that `criterion_scores` covers the question's rubric, stays within
+/// each criterion's points, and sums to `score`.
+fn validate_criterion_scores(
+    env: &Env,
+    question_id: u64,
+    score: u32,
+    criterion_scores: &Vec<u32>,
+) -> Result<(), AssessmentError> {
+    let rubric: Vec<RubricCriterion> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::QuestionRubric(question_id))
+        .ok_or(AssessmentError::RubricNotFound)?;
+    if criterion_scores.len() != rubric.len() {
+        return Err(AssessmentError::RubricScoreMismatch);
+    }
+
+    let mut total: u32 = 0;
+    for (criterion, points) in rubric.iter().zip(criterion_scores.iter()) {
+        if points > criterion.max_points {
+            return Err(AssessmentError::RubricScoreMismatch);
+        }
+        total = total.saturating_add(points);
+    }
+    if total != score {
+        return Err(AssessmentError::RubricScoreMismatch);
+    }
+    Ok(())
+}
+
+/// Storage key of a manual grade, scoped to the submission's assessment and attempt since
+/// submission ids are reused across a student's attempts.
+fn manual_grade_key(submission: &Submission, question_id: u64) -> DataKey {
+    DataKey::ManualGrade(
+        submission.submission_id.clone(),
+        submission.assessment_id,
+        submission.attempt,
+        question_id,
+    )
+}
+
+/// Storage key of the question order presented for a submission's attempt.
+fn question_order_key(submission: &Submission) -> DataKey {
+    DataKey::QuestionOrder(
+        submission.submission_id.clone(),
+        submission.assessment_id,
+        submission.attempt,
+    )
+}
+
+/// Returns true once every answered manual question in the submission has a recorded grade.
+fn all_manual_answers_graded(env: &Env, submission: &Submission) -> bool {
+    for answer in submission.answers.iter() {
+        let is_manual = get_question(env, answer.question_id)
+            .map(|q| q.answer_key == AnswerKey::Manual)
+            .unwrap_or(false);
+        let key = manual_grade_key(submission, answer.question_id);
+        if is_manual && !env.storage().persistent().has(&key) {
+            return false;
+        }
+    }
+    true
+}
+
 #[allow(clippy::too_many_arguments)]
 #[contractimpl]
 impl Assessment {
@@ -515,6 +924,95 @@ impl Assessment {
         )
     }
 
+    /// Adds a question to the shared question bank so it can be reused across assessments.
+    ///
+    /// The question is stored once and linked into assessments with
+    /// [`attach_bank_question`](Self::attach_bank_question).
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment.
+    /// * `instructor` - The instructor authoring the question.
+    /// * `question` - The question content, type, scoring and answer key.
+    ///
+    /// # Errors
+    /// Returns [`AssessmentError::InvalidQuestionType`] if `max_score` or `difficulty` is zero.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let question_id = client.add_bank_question(&instructor, &question);
+    /// ```
+    pub fn add_bank_question(
+        env: Env,
+        instructor: Address,
+        question: BankQuestionParams,
+    ) -> Result<u64, AssessmentError> {
+        instructor.require_auth();
+        if question.max_score == 0 || question.difficulty == 0 {
+            return Err(AssessmentError::InvalidQuestionType);
+        }
+
+        let qid = get_next_question_id(&env);
+        let q = Question {
+            question_id: qid,
+            assessment_id: BANK_ASSESSMENT_ID,
+            question_type: question.question_type,
+            max_score: question.max_score,
+            difficulty: question.difficulty,
+            content_hash: question.content_hash,
+            options: question.options,
+            answer_key: question.answer_key,
+        };
+        env.storage().persistent().set(&DataKey::Question(qid), &q);
+        env.storage().persistent().set(&DataKey::BankQuestionAuthor(qid), &instructor);
+        Ok(qid)
+    }
+
+    /// Links a bank question into an assessment without copying it.
+    ///
+    /// Submissions to the assessment are graded against the shared question like any other.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment.
+    /// * `instructor` - The assessment's instructor or the admin.
+    /// * `assessment_id` - The assessment to add the question to.
+    /// * `question_id` - The bank question to attach.
+    ///
+    /// # Errors
+    /// Returns [`AssessmentError::AssessmentNotFound`] if no assessment exists with the given ID.
+    /// Returns [`AssessmentError::Unauthorized`] if the caller is neither the instructor nor the admin.
+    /// Returns [`AssessmentError::QuestionNotFound`] if the question does not exist.
+    /// Returns [`AssessmentError::NotBankQuestion`] if the question belongs to a single assessment.
+    /// Returns [`AssessmentError::QuestionAlreadyAttached`] if the assessment already includes it.
+    ///
+    /// # Example
+    /// ```ignore
+    /// client.attach_bank_question(&instructor, &assessment_id, &question_id);
+    /// ```
+    pub fn attach_bank_question(
+        env: Env,
+        instructor: Address,
+        assessment_id: u64,
+        question_id: u64,
+    ) -> Result<(), AssessmentError> {
+        let meta = get_assessment(&env, assessment_id)?;
+        require_instructor_or_admin(&env, &instructor, &meta)?;
+        let question = get_question(&env, question_id)?;
+        if question.assessment_id != BANK_ASSESSMENT_ID {
+            return Err(AssessmentError::NotBankQuestion);
+        }
+
+        let key = DataKey::AssessmentQuestions(assessment_id);
+        let mut ids: Vec<u64> = env.storage().persistent().get(&key).unwrap_or(Vec::new(&env));
+        if ids.contains(question_id) {
+            return Err(AssessmentError::QuestionAlreadyAttached);
+        }
+        ids.push_back(question_id);
+        env.storage().persistent().set(&key, &ids);
+
+        AssessmentEvents::emit_question_added(&env, assessment_id, question_id);
+        Ok(())
+    }
+
     /// Returns the metadata for the given assessment, or `None` if it does not exist.
     ///
     /// # Arguments
@@ -579,6 +1077,127 @@ impl Assessment {
         Ok(())
     }
 
+    /// Sets how long attached proctoring evidence stays valid for grading proctored assessments.
+    ///
+    /// Evidence older than `window_seconds` when answers are submitted or revealed is rejected, so
+    /// a stale recording cannot be reused for a later attempt. `0` disables expiry.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment.
+    /// * `admin` - The admin address authorizing the change.
+    /// * `window_seconds` - Validity window for proctoring evidence, in seconds.
+    ///
+    /// # Errors
+    /// Returns [`AssessmentError::Unauthorized`] if the caller is not the admin.
+    ///
+    /// # Example
+    /// ```ignore
+    /// client.set_proctoring_evidence_window(&admin, &3600u64);
+    /// ```
+    pub fn set_proctoring_evidence_window(
+        env: Env,
+        admin: Address,
+        window_seconds: u64,
+    ) -> Result<(), AssessmentError> {
+        require_admin(&env, &admin)?;
+        env.storage().instance().set(&DataKey::ProctoringEvidenceWindow, &window_seconds);
+        Ok(())
+    }
+
+    /// Returns the proctoring evidence validity window in seconds (`0` means evidence never expires).
+    ///
+    /// # Example
+    /// ```ignore
+    /// let window = client.get_proctoring_evidence_window();
+    /// ```
+    pub fn get_proctoring_evidence_window(env: Env) -> u64 {
+        get_proctoring_evidence_window(&env)
+    }
+
+    /// Requires students to commit to and then reveal their answers for a high-stakes assessment.
+    ///
+    /// While enabled, [`submit_answers`](Self::submit_answers) is rejected for the assessment and
+    /// answers must go through [`commit_answers`](Self::commit_answers) and
+    /// [`reveal_answers`](Self::reveal_answers).
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment.
+    /// * `admin` - The admin address authorizing the change.
+    /// * `assessment_id` - The ID of the assessment to configure.
+    /// * `required` - Whether commit-reveal submission is mandatory.
+    ///
+    /// # Errors
+    /// Returns [`AssessmentError::Unauthorized`] if the caller is not the admin.
+    /// Returns [`AssessmentError::AssessmentNotFound`] if no assessment exists with the given ID.
+    ///
+    /// # Example
+    /// ```ignore
+    /// client.set_commit_reveal_required(&admin, &assessment_id, &true);
+    /// ```
+    pub fn set_commit_reveal_required(
+        env: Env,
+        admin: Address,
+        assessment_id: u64,
+        required: bool,
+    ) -> Result<(), AssessmentError> {
+        require_admin(&env, &admin)?;
+        let _ = get_assessment(&env, assessment_id)?;
+        let key = DataKey::CommitRevealRequired(assessment_id);
+        if required {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+        Ok(())
+    }
+
+    /// Sets the minimum time a student must wait after submitting before starting another attempt.
+    ///
+    /// Gives students time to re-study between attempts instead of retrying back-to-back. A
+    /// student's [`AccommodationConfig::cooldown_reduction_percent`] shortens the wait. `0`
+    /// disables the cooldown.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment.
+    /// * `admin` - The admin address authorizing the change.
+    /// * `assessment_id` - The ID of the assessment to configure.
+    /// * `cooldown_seconds` - Wait between a submission and the next attempt, in seconds.
+    ///
+    /// # Errors
+    /// Returns [`AssessmentError::Unauthorized`] if the caller is not the admin.
+    /// Returns [`AssessmentError::AssessmentNotFound`] if no assessment exists with the given ID.
+    ///
+    /// # Example
+    /// ```ignore
+    /// client.set_attempt_cooldown(&admin, &assessment_id, &86_400u64);
+    /// ```
+    pub fn set_attempt_cooldown(
+        env: Env,
+        admin: Address,
+        assessment_id: u64,
+        cooldown_seconds: u64,
+    ) -> Result<(), AssessmentError> {
+        require_admin(&env, &admin)?;
+        let _ = get_assessment(&env, assessment_id)?;
+        let key = DataKey::AttemptCooldown(assessment_id);
+        if cooldown_seconds > 0 {
+            env.storage().persistent().set(&key, &cooldown_seconds);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+        Ok(())
+    }
+
+    /// Returns the attempt cooldown in seconds configured for an assessment (`0` means none).
+    ///
+    /// # Example
+    /// ```ignore
+    /// let cooldown = client.get_attempt_cooldown(&assessment_id);
+    /// ```
+    pub fn get_attempt_cooldown(env: Env, assessment_id: u64) -> u64 {
+        env.storage().persistent().get(&DataKey::AttemptCooldown(assessment_id)).unwrap_or(0)
+    }
+
     /// Sets accessibility accommodations for a student, such as extra time or additional attempts.
     ///
     /// Requires admin authorization. Overwrites any previously stored accommodation for the student.
@@ -722,7 +1341,9 @@ impl Assessment {
 
     /// Opens a new in-progress submission for the student, returning a unique submission ID.
     ///
-    /// The student must authorize the call. Checks that the assessment is published, within schedule, and that the student has remaining attempts (including any accommodation bonuses).
+    /// The student must authorize the call. Checks that the assessment is published, within schedule, and that the student has remaining attempts (including any accommodation bonuses) and that any attempt cooldown has elapsed.
+    ///
+    /// When `shuffle_questions` is enabled, a deterministic per-attempt question ordering (seeded from the student, submission ID, assessment ID and attempt number) is stored and returned by `get_question_order`; answers are still keyed by original question ID.
     ///
     /// # Arguments
     /// * `env` - The Soroban environment.
@@ -734,6 +1355,7 @@ impl Assessment {
     /// Returns [`AssessmentError::AssessmentNotPublished`] if the assessment is not yet published.
     /// Returns [`AssessmentError::AssessmentClosed`] if the current time is outside the scheduled window.
     /// Returns [`AssessmentError::MaxAttemptsReached`] if the student has used all allowed attempts.
+    /// Returns [`AssessmentError::AttemptCooldownActive`] if the attempt cooldown since the student's last submission has not elapsed.
     ///
     /// # Example
     /// ```ignore
@@ -775,15 +1397,35 @@ impl Assessment {
         if attempts >= max_attempts {
             return Err(AssessmentError::MaxAttemptsReached);
         }
+        require_attempt_cooldown_elapsed(&env, &student, assessment_id)?;
 
         let addr_bytes = student.clone().to_xdr(&env);
         let sid_hash = env.crypto().sha256(&addr_bytes);
         let sid: BytesN<32> = sid_hash.into();
+
+        let question_ids: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::AssessmentQuestions(assessment_id))
+            .unwrap_or(Vec::new(&env));
+        let attempt = attempts + 1;
+        let question_order = if meta.config.shuffle_questions {
+            // Submission ids repeat across attempts, so the assessment and attempt vary the seed
+            let mut seed_material = student.clone().to_xdr(&env);
+            seed_material.append(&Bytes::from_array(&env, &sid.to_array()));
+            seed_material.append(&Bytes::from_array(&env, &assessment_id.to_be_bytes()));
+            seed_material.append(&Bytes::from_array(&env, &attempt.to_be_bytes()));
+            let seed: BytesN<32> = env.crypto().sha256(&seed_material).into();
+            shuffle_question_ids(&env, &question_ids, seed)
+        } else {
+            question_ids
+        };
+
         let submission = Submission {
             submission_id: sid.clone(),
             assessment_id,
             student: student.clone(),
-            attempt: attempts + 1,
+            attempt,
             started_at: env.ledger().timestamp(),
             submitted_at: 0,
             score: 0,
@@ -800,6 +1442,7 @@ impl Assessment {
             },
         };
         put_submission(&env, &submission);
+        env.storage().persistent().set(&question_order_key(&submission), &question_order);
         append_student_submission(&env, &student, assessment_id, &sid);
         Ok(sid)
     }
@@ -807,6 +1450,10 @@ impl Assessment {
     /// Submits answers for an in-progress submission, triggers auto-grading, and returns the completed submission.
     ///
     /// The student must authorize the call. The submission is finalized after this call; time-limit violations cause an error.
+    /// Once auto-graded, the result is recorded in the configured analytics contract as a completed
+    /// assessment session (best-effort, at most once per attempt; analytics must trust this contract
+    /// as a recorder). A passing result also marks the
+    /// assessment's module complete in the configured progress contract (best-effort).
     ///
     /// # Arguments
     /// * `env` - The Soroban environment.
@@ -819,6 +1466,8 @@ impl Assessment {
     /// Returns [`AssessmentError::Unauthorized`] if the caller does not own the submission.
     /// Returns [`AssessmentError::SubmissionAlreadyFinalized`] if the submission has already been graded.
     /// Returns [`AssessmentError::AssessmentClosed`] if the student's time limit has been exceeded.
+    /// Returns [`AssessmentError::ProctoringEvidenceExpired`] if the assessment requires proctoring
+    /// and the attached evidence is older than the validity window.
     ///
     /// # Example
     /// ```ignore
@@ -831,56 +1480,102 @@ impl Assessment {
         answers: Vec<SubmittedAnswer>,
     ) -> Result<Submission, AssessmentError> {
         student.require_auth();
-        let rl = get_rate_limits(&env);
-        enforce_rate_limit(
-            &env,
-            &DataKey::RateLimit(student.clone(), RL_OP_SUBMIT_ANSWERS),
-            &RateLimitConfig {
-                max_calls: rl.max_answers_per_day,
-                window_seconds: rl.window_seconds,
-            },
-        )
-        .map_err(|_| AssessmentError::RateLimitExceeded)?;
-        let mut submission = get_submission(&env, &submission_id)?;
-        if submission.student != student {
-            return Err(AssessmentError::Unauthorized);
-        }
-        if let SubmissionStatus::Finalized = submission.status {
-            return Err(AssessmentError::SubmissionAlreadyFinalized);
-        }
-
-        let meta = get_assessment(&env, submission.assessment_id)?;
-        let effective_limit = get_effective_time_limit(&env, &meta.config, &student);
-        let now = env.ledger().timestamp();
-        if effective_limit > 0 && now > submission.started_at + effective_limit {
-            return Err(AssessmentError::AssessmentClosed);
+        enforce_answer_rate_limit(&env, &student)?;
+        let (submission, meta) = get_open_submission(&env, &student, &submission_id)?;
+        if is_commit_reveal_required(&env, submission.assessment_id) {
+            return Err(AssessmentError::CommitmentRequired);
         }
+        require_valid_proctoring_evidence(&env, &meta, &submission)?;
+        Ok(grade_and_store_submission(&env, submission, &meta, answers))
+    }
 
-        submission.answers = answers;
-        submission.submitted_at = now;
-
-        let questions = get_questions_for_assessment(&env, submission.assessment_id);
-        let result = GradingEngine::grade_submission(&env, &questions, &submission);
-        submission.score = result.score;
-        submission.max_score = result.max_score;
-        submission.passed = submission.score >= meta.config.pass_score;
-        submission.status = GradingEngine::derive_status(result.requires_manual_review);
+    /// Commits to a set of answers without revealing them on-chain.
+    ///
+    /// The commitment is the SHA-256 hash of the XDR-encoded `(answers, salt)` pair that will later
+    /// be passed to [`reveal_answers`](Self::reveal_answers). The salt is a random value kept
+    /// off-chain until the reveal, so answers with few possible values cannot be brute-forced
+    /// from the public commitment. Committing again before revealing replaces the previous
+    /// commitment.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment.
+    /// * `student` - The student's address committing their answers.
+    /// * `submission_id` - The ID of the in-progress submission.
+    /// * `commitment_hash` - SHA-256 of the XDR-encoded `(Vec<SubmittedAnswer>, BytesN<32>)` pair.
+    ///
+    /// # Errors
+    /// Returns [`AssessmentError::SubmissionNotFound`] if the submission does not exist.
+    /// Returns [`AssessmentError::Unauthorized`] if the caller does not own the submission.
+    /// Returns [`AssessmentError::SubmissionAlreadyFinalized`] if the submission has already been graded.
+    /// Returns [`AssessmentError::AssessmentClosed`] if the student's time limit has been exceeded.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let commitment = env.crypto().sha256(&(answers.clone(), salt.clone()).to_xdr(&env));
+    /// client.commit_answers(&student, &submission_id, &commitment.into());
+    /// ```
+    pub fn commit_answers(
+        env: Env,
+        student: Address,
+        submission_id: BytesN<32>,
+        commitment_hash: BytesN<32>,
+    ) -> Result<(), AssessmentError> {
+        student.require_auth();
+        enforce_answer_rate_limit(&env, &student)?;
+        let _ = get_open_submission(&env, &student, &submission_id)?;
+        env.storage().persistent().set(&DataKey::AnswerCommitment(submission_id), &commitment_hash);
+        Ok(())
+    }
 
-        AssessmentEvents::emit_submission_received(
-            &env,
-            &submission.submission_id,
-            submission.assessment_id,
-        );
-        AssessmentEvents::emit_submission_graded(
-            &env,
-            &submission.submission_id,
-            submission.score,
-            submission.max_score,
-            submission.passed,
-        );
+    /// Reveals previously committed answers, verifies them against the commitment, and grades them.
+    ///
+    /// The commitment is consumed on success. Grading, events, and the analytics export follow
+    /// the same path as [`submit_answers`](Self::submit_answers).
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment.
+    /// * `student` - The student's address revealing their answers.
+    /// * `submission_id` - The ID of the in-progress submission.
+    /// * `answers` - The answers whose hash was committed.
+    /// * `salt` - The secret salt that was hashed together with the answers.
+    ///
+    /// # Errors
+    /// Returns [`AssessmentError::SubmissionNotFound`] if the submission does not exist.
+    /// Returns [`AssessmentError::Unauthorized`] if the caller does not own the submission.
+    /// Returns [`AssessmentError::SubmissionAlreadyFinalized`] if the submission has already been graded.
+    /// Returns [`AssessmentError::AssessmentClosed`] if the student's time limit has been exceeded.
+    /// Returns [`AssessmentError::CommitmentNotFound`] if no commitment was made for the submission.
+    /// Returns [`AssessmentError::CommitmentMismatch`] if the answers do not match the commitment.
+    /// Returns [`AssessmentError::ProctoringEvidenceExpired`] under the same conditions as
+    /// `submit_answers`.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let result = client.reveal_answers(&student, &submission_id, &answers, &salt);
+    /// ```
+    pub fn reveal_answers(
+        env: Env,
+        student: Address,
+        submission_id: BytesN<32>,
+        answers: Vec<SubmittedAnswer>,
+        salt: BytesN<32>,
+    ) -> Result<Submission, AssessmentError> {
+        student.require_auth();
+        enforce_answer_rate_limit(&env, &student)?;
+        let (submission, meta) = get_open_submission(&env, &student, &submission_id)?;
+        require_valid_proctoring_evidence(&env, &meta, &submission)?;
+
+        let key = DataKey::AnswerCommitment(submission_id);
+        let commitment: BytesN<32> =
+            env.storage().persistent().get(&key).ok_or(AssessmentError::CommitmentNotFound)?;
+        let revealed: BytesN<32> =
+            env.crypto().sha256(&(answers.clone(), salt).to_xdr(&env)).into();
+        if revealed != commitment {
+            return Err(AssessmentError::CommitmentMismatch);
+        }
+        env.storage().persistent().remove(&key);
 
-        put_submission(&env, &submission);
-        Ok(submission)
+        Ok(grade_and_store_submission(&env, submission, &meta, answers))
     }
 
     /// Returns the full submission record for the given submission ID, or `None` if it does not exist.
@@ -897,6 +1592,210 @@ impl Assessment {
         env.storage().persistent().get(&DataKey::Submission(submission_id))
     }
 
+    /// Returns the question ids in the order presented for the submission's current attempt.
+    ///
+    /// Attempts started before question orders were recorded fall back to the assessment's creation order.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment.
+    /// * `submission_id` - The unique identifier of the submission.
+    ///
+    /// # Errors
+    /// Returns [`AssessmentError::SubmissionNotFound`] if the submission does not exist.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let order = client.get_question_order(&submission_id);
+    /// ```
+    pub fn get_question_order(
+        env: Env,
+        submission_id: BytesN<32>,
+    ) -> Result<Vec<u64>, AssessmentError> {
+        let submission = get_submission(&env, &submission_id)?;
+        Ok(env.storage().persistent().get(&question_order_key(&submission)).unwrap_or_else(|| {
+            env.storage()
+                .persistent()
+                .get(&DataKey::AssessmentQuestions(submission.assessment_id))
+                .unwrap_or(Vec::new(&env))
+        }))
+    }
+
+    /// Sets the scoring rubric for a manually graded question, replacing any previous rubric.
+    ///
+    /// The criteria's points must total the question's `max_score`.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment.
+    /// * `instructor` - The assessment's instructor, the bank question's author, or the admin.
+    /// * `question_id` - The manually graded question the rubric applies to.
+    /// * `rubric` - The scoring criteria, in the order graders report points for them.
+    ///
+    /// # Errors
+    /// Returns [`AssessmentError::QuestionNotFound`] if the question does not exist.
+    /// Returns [`AssessmentError::Unauthorized`] if the caller does not own the question and is not
+    /// the admin.
+    /// Returns [`AssessmentError::InvalidQuestionType`] if the question is auto-graded.
+    /// Returns [`AssessmentError::InvalidRubric`] if the rubric is empty, has a zero-point
+    /// criterion, or does not total the question's max score.
+    ///
+    /// # Example
+    /// ```ignore
+    /// client.set_question_rubric(&instructor, &question_id, &rubric);
+    /// ```
+    pub fn set_question_rubric(
+        env: Env,
+        instructor: Address,
+        question_id: u64,
+        rubric: Vec<RubricCriterion>,
+    ) -> Result<(), AssessmentError> {
+        let question = get_question(&env, question_id)?;
+        require_question_owner(&env, &instructor, &question)?;
+        if question.answer_key != AnswerKey::Manual {
+            return Err(AssessmentError::InvalidQuestionType);
+        }
+
+        let mut total: u32 = 0;
+        for criterion in rubric.iter() {
+            if criterion.max_points == 0 {
+                return Err(AssessmentError::InvalidRubric);
+            }
+            total = total.saturating_add(criterion.max_points);
+        }
+        if rubric.is_empty() || total != question.max_score {
+            return Err(AssessmentError::InvalidRubric);
+        }
+
+        env.storage().persistent().set(&DataKey::QuestionRubric(question_id), &rubric);
+        Ok(())
+    }
+
+    /// Returns the scoring rubric for a question, or `None` if none has been set.
+    pub fn get_question_rubric(env: Env, question_id: u64) -> Option<Vec<RubricCriterion>> {
+        env.storage().persistent().get(&DataKey::QuestionRubric(question_id))
+    }
+
+    /// Returns the student's answer to a manually graded question together with the question's
+    /// rubric, for a submission awaiting manual review.
+    ///
+    /// # Errors
+    /// Returns [`AssessmentError::SubmissionNotFound`] if the submission does not exist.
+    /// Returns [`AssessmentError::ManualReviewNotRequired`] if the submission is not awaiting review.
+    /// Returns [`AssessmentError::QuestionNotFound`] if the question is not part of the assessment.
+    /// Returns [`AssessmentError::InvalidQuestionType`] if the question is auto-graded.
+    /// Returns [`AssessmentError::InvalidAnswer`] if the student did not answer the question.
+    pub fn get_manual_grading_task(
+        env: Env,
+        submission_id: BytesN<32>,
+        question_id: u64,
+    ) -> Result<ManualGradingTask, AssessmentError> {
+        let (_, question, answer) = get_manual_review_item(&env, &submission_id, question_id)?;
+        let rubric = env
+            .storage()
+            .persistent()
+            .get(&DataKey::QuestionRubric(question_id))
+            .unwrap_or(Vec::new(&env));
+        Ok(ManualGradingTask {
+            submission_id,
+            question_id,
+            max_score: question.max_score,
+            answer,
+            rubric,
+        })
+    }
+
+    /// Records the score for a manually graded answer and adds it to the submission's total.
+    ///
+    /// When `criterion_scores` is provided, it must list the points awarded for each criterion of
+    /// the question's rubric, in order, summing to `score`. Once every manually graded answer in
+    /// the submission has a score, the submission is finalized, exported to analytics, and a
+    /// passing result marks the module complete, as for auto-graded attempts.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment.
+    /// * `grader` - The assessment's instructor or the admin.
+    /// * `submission_id` - The submission awaiting manual review.
+    /// * `question_id` - The manually graded question being scored.
+    /// * `score` - Points awarded, at most the question's `max_score`.
+    /// * `criterion_scores` - Optional per-criterion breakdown of `score` against the rubric.
+    ///
+    /// # Errors
+    /// Returns the errors of [`get_manual_grading_task`](Self::get_manual_grading_task).
+    /// Returns [`AssessmentError::Unauthorized`] if the caller is neither the instructor nor the admin.
+    /// Returns [`AssessmentError::QuestionAlreadyGraded`] if the answer already has a score.
+    /// Returns [`AssessmentError::InvalidAnswer`] if `score` exceeds the question's max score.
+    /// Returns [`AssessmentError::RubricNotFound`] if criterion scores are given without a rubric.
+    /// Returns [`AssessmentError::RubricScoreMismatch`] if the criterion scores do not fit the
+    /// rubric or do not sum to `score`.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let grade = client.grade_manual_question(&instructor, &submission_id, &qid, &7, &Some(points));
+    /// ```
+    pub fn grade_manual_question(
+        env: Env,
+        grader: Address,
+        submission_id: BytesN<32>,
+        question_id: u64,
+        score: u32,
+        criterion_scores: Option<Vec<u32>>,
+    ) -> Result<ManualGrade, AssessmentError> {
+        let (mut submission, question, _) =
+            get_manual_review_item(&env, &submission_id, question_id)?;
+        let meta = get_assessment(&env, submission.assessment_id)?;
+        require_instructor_or_admin(&env, &grader, &meta)?;
+
+        let key = manual_grade_key(&submission, question_id);
+        if env.storage().persistent().has(&key) {
+            return Err(AssessmentError::QuestionAlreadyGraded);
+        }
+        if score > question.max_score {
+            return Err(AssessmentError::InvalidAnswer);
+        }
+        let criterion_scores = criterion_scores.unwrap_or(Vec::new(&env));
+        if !criterion_scores.is_empty() {
+            validate_criterion_scores(&env, question_id, score, &criterion_scores)?;
+        }
+
+        let grade = ManualGrade {
+            question_id,
+            score,
+            criterion_scores,
+            grader,
+            graded_at: env.ledger().timestamp(),
+        };
+        env.storage().persistent().set(&key, &grade);
+
+        submission.score = submission.score.saturating_add(score);
+        submission.passed = submission.score >= meta.config.pass_score;
+        if all_manual_answers_graded(&env, &submission) {
+            submission.status = SubmissionStatus::Finalized;
+            AssessmentEvents::emit_submission_graded(
+                &env,
+                &submission.submission_id,
+                submission.score,
+                submission.max_score,
+                submission.passed,
+            );
+        }
+        put_submission(&env, &submission);
+        if let SubmissionStatus::Finalized = submission.status {
+            export_result_to_analytics(&env, &meta, &submission);
+            sync_module_progress(&env, &meta, &submission);
+        }
+        Ok(grade)
+    }
+
+    /// Returns the recorded manual grade for a question in the submission's current attempt, or
+    /// `None` if ungraded.
+    pub fn get_manual_grade(
+        env: Env,
+        submission_id: BytesN<32>,
+        question_id: u64,
+    ) -> Option<ManualGrade> {
+        let submission = get_submission(&env, &submission_id).ok()?;
+        env.storage().persistent().get(&manual_grade_key(&submission, question_id))
+    }
+
     /// Attaches integrity metadata to a submission, such as plagiarism scores and proctoring evidence.
     ///
     /// Only the registered security monitor contract or the admin may call this function. Emits integrity events if a plagiarism flag is set.
@@ -909,7 +1808,8 @@ impl Assessment {
     /// * `plagiarism_flag` - `true` if the submission is flagged for plagiarism.
     /// * `integrity_flags` - A list of symbolic integrity violation codes.
     /// * `has_proctoring_evidence` - Whether proctoring evidence is available for this submission.
-    /// * `proctoring_evidence_hash` - Hash of the proctoring evidence artifact.
+    /// * `proctoring_evidence_hash` - Hash of the proctoring evidence artifact. Attaching evidence
+    ///   records the current ledger time, from which its validity window is measured.
     ///
     /// # Errors
     /// Returns [`AssessmentError::SecurityIntegrationMissing`] if the caller is neither the admin nor the registered security monitor.
@@ -949,6 +1849,12 @@ impl Assessment {
         submission.integrity.integrity_flags = integrity_flags.clone();
         submission.integrity.has_proctoring_evidence = has_proctoring_evidence;
         submission.integrity.proctoring_evidence_hash = proctoring_evidence_hash;
+        let evidence_at_key = proctoring_evidence_at_key(&submission);
+        if has_proctoring_evidence {
+            env.storage().persistent().set(&evidence_at_key, &env.ledger().timestamp());
+        } else {
+            env.storage().persistent().remove(&evidence_at_key);
+        }
 
         if plagiarism_flag {
             AssessmentEvents::emit_plagiarism_flagged(&env, &submission_id, plagiarism_score, true);
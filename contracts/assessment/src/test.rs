@@ -1,6 +1,7 @@
 use super::*;
-use soroban_sdk::testutils::Address as _;
-use soroban_sdk::{Address, Bytes, Env, Symbol, Vec};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{contract, contractimpl, symbol_short, Address, Bytes, Env, Symbol, Vec};
 
 fn setup() -> (Env, AssessmentClient<'static>, Address) {
     let env = Env::default();
@@ -28,6 +29,7 @@ fn test_create_and_publish_assessment() {
         allow_review: true,
         is_adaptive: false,
         proctoring_required: false,
+        shuffle_questions: false,
     };
 
     let id = client.create_assessment(&instructor, &course_id, &module_id, &config);
@@ -55,6 +57,7 @@ fn test_single_choice_grading() {
         allow_review: false,
         is_adaptive: false,
         proctoring_required: false,
+        shuffle_questions: false,
     };
 
     let id = client.create_assessment(&instructor, &course_id, &module_id, &config);
@@ -104,6 +107,7 @@ fn test_adaptive_state_updates() {
         allow_review: false,
         is_adaptive: true,
         proctoring_required: false,
+        shuffle_questions: false,
     };
 
     let id = client.create_assessment(&instructor, &course_id, &module_id, &config);
@@ -131,3 +135,740 @@ fn test_adaptive_state_updates() {
     let q = maybe_q.unwrap();
     client.update_adaptive_state(&student, &id, &q.question_id, &true);
 }
+
+fn create_shuffled_assessment(env: &Env, client: &AssessmentClient, admin: &Address) -> u64 {
+    create_shuffled_assessment_with_attempts(env, client, admin, 1)
+}
+
+fn create_shuffled_assessment_with_attempts(
+    env: &Env,
+    client: &AssessmentClient,
+    admin: &Address,
+    max_attempts: u32,
+) -> u64 {
+    let config = AssessmentConfig {
+        time_limit_seconds: 0,
+        max_attempts,
+        pass_score: 1,
+        allow_review: false,
+        is_adaptive: false,
+        proctoring_required: false,
+        shuffle_questions: true,
+    };
+
+    let id =
+        client.create_assessment(admin, &Symbol::new(env, "C3"), &Symbol::new(env, "M3"), &config);
+    client.publish_assessment(admin, &id);
+
+    let options: Vec<QuestionOption> = Vec::new(env);
+    for i in 0u32..8u32 {
+        let content_hash: BytesN<32> = env.crypto().sha256(&Bytes::new(env)).into();
+        client.add_question(
+            admin,
+            &id,
+            &QuestionType::SingleChoice,
+            &1u32,
+            &1u32,
+            &content_hash,
+            &options,
+            &AnswerKey::SingleChoice(i),
+        );
+    }
+    id
+}
+
+#[test]
+fn test_shuffled_question_order_differs_per_student() {
+    let (env, client, admin) = setup();
+    let id = create_shuffled_assessment(&env, &client, &admin);
+
+    let student_a = Address::generate(&env);
+    let student_b = Address::generate(&env);
+    let sid_a = client.start_submission(&student_a, &id);
+    let sid_b = client.start_submission(&student_b, &id);
+
+    let order_a = client.get_question_order(&sid_a);
+    let order_b = client.get_question_order(&sid_b);
+
+    assert_eq!(order_a.len(), 8);
+    assert_eq!(order_b.len(), 8);
+    assert_ne!(order_a, order_b);
+
+    // Each ordering is a permutation of the original question ids
+    for qid in 1u64..=8u64 {
+        assert!(order_a.contains(qid));
+        assert!(order_b.contains(qid));
+    }
+}
+
+#[test]
+fn test_shuffled_question_order_differs_per_attempt() {
+    let (env, client, admin) = setup();
+    let id = create_shuffled_assessment_with_attempts(&env, &client, &admin, 2);
+
+    let student = Address::generate(&env);
+    let first_sid = client.start_submission(&student, &id);
+    let first_order = client.get_question_order(&first_sid);
+    let second_sid = client.start_submission(&student, &id);
+    let second_order = client.get_question_order(&second_sid);
+
+    // Submission ids repeat across attempts; the order must not
+    assert_eq!(first_sid, second_sid);
+    assert_eq!(second_order.len(), 8);
+    assert_ne!(first_order, second_order);
+}
+
+#[test]
+fn test_shuffled_submission_grades_by_original_question_id() {
+    let (env, client, admin) = setup();
+    let id = create_shuffled_assessment(&env, &client, &admin);
+
+    let student = Address::generate(&env);
+    let submission_id = client.start_submission(&student, &id);
+    let order = client.get_question_order(&submission_id);
+
+    // Answer in presented order; question ids 1..=8 expect options 0..=7
+    let mut answers: Vec<SubmittedAnswer> = Vec::new(&env);
+    for qid in order.iter() {
+        let correct = (qid - 1) as u32;
+        let value = if qid % 2 == 0 { correct } else { correct + 100 };
+        answers.push_back(SubmittedAnswer {
+            question_id: qid,
+            value: SubmittedAnswerValue::SingleChoice(value),
+        });
+    }
+
+    let submission = client.submit_answers(&student, &submission_id, &answers);
+    assert_eq!(submission.max_score, 8);
+    assert_eq!(submission.score, 4);
+}
+
+#[test]
+fn test_unshuffled_question_order_matches_creation_order() {
+    let (env, client, admin) = setup();
+    let config = AssessmentConfig {
+        time_limit_seconds: 0,
+        max_attempts: 1,
+        pass_score: 1,
+        allow_review: false,
+        is_adaptive: false,
+        proctoring_required: false,
+        shuffle_questions: false,
+    };
+    let id = client.create_assessment(
+        &admin,
+        &Symbol::new(&env, "C4"),
+        &Symbol::new(&env, "M4"),
+        &config,
+    );
+    client.publish_assessment(&admin, &id);
+
+    let options: Vec<QuestionOption> = Vec::new(&env);
+    for _ in 0..3 {
+        let content_hash: BytesN<32> = env.crypto().sha256(&Bytes::new(&env)).into();
+        client.add_question(
+            &admin,
+            &id,
+            &QuestionType::SingleChoice,
+            &1u32,
+            &1u32,
+            &content_hash,
+            &options,
+            &AnswerKey::SingleChoice(1),
+        );
+    }
+
+    let student = Address::generate(&env);
+    let submission_id = client.start_submission(&student, &id);
+    let order = client.get_question_order(&submission_id);
+    assert_eq!(order, soroban_sdk::vec![&env, 1u64, 2u64, 3u64]);
+}
+
+#[contract]
+struct MockAnalytics;
+
+#[contractimpl]
+impl MockAnalytics {
+    pub fn record_session_for(env: Env, recorder: Address, session: AnalyticsSession) {
+        recorder.require_auth();
+        let key = symbol_short!("recorded");
+        let count: u32 = env.storage().instance().get(&key).unwrap_or(0);
+        env.storage().instance().set(&key, &(count + 1));
+        env.storage().instance().set(&symbol_short!("last"), &session);
+    }
+
+    pub fn recorded(env: Env) -> u32 {
+        env.storage().instance().get(&symbol_short!("recorded")).unwrap_or(0)
+    }
+
+    pub fn last(env: Env) -> Option<AnalyticsSession> {
+        env.storage().instance().get(&symbol_short!("last"))
+    }
+}
+
+fn create_graded_assessment(env: &Env, client: &AssessmentClient, admin: &Address) -> (u64, u64) {
+    let config = AssessmentConfig {
+        time_limit_seconds: 0,
+        max_attempts: 2,
+        pass_score: 1,
+        allow_review: false,
+        is_adaptive: false,
+        proctoring_required: false,
+        shuffle_questions: false,
+    };
+    let id =
+        client.create_assessment(admin, &Symbol::new(env, "C5"), &Symbol::new(env, "M5"), &config);
+    client.publish_assessment(admin, &id);
+
+    let content_hash: BytesN<32> = env.crypto().sha256(&Bytes::new(env)).into();
+    let qid = client.add_question(
+        admin,
+        &id,
+        &QuestionType::SingleChoice,
+        &4u32,
+        &1u32,
+        &content_hash,
+        &Vec::new(env),
+        &AnswerKey::SingleChoice(1),
+    );
+    (id, qid)
+}
+
+fn single_answer(env: &Env, question_id: u64, value: u32) -> Vec<SubmittedAnswer> {
+    soroban_sdk::vec![
+        env,
+        SubmittedAnswer { question_id, value: SubmittedAnswerValue::SingleChoice(value) }
+    ]
+}
+
+#[test]
+fn test_graded_attempt_exported_to_analytics_once() {
+    let (env, client, admin) = setup();
+    let analytics_id = env.register(MockAnalytics, ());
+    let analytics = MockAnalyticsClient::new(&env, &analytics_id);
+    client.set_integration(&admin, &Some(analytics_id.clone()), &None, &None);
+
+    let (id, qid) = create_graded_assessment(&env, &client, &admin);
+    let student = Address::generate(&env);
+    let submission_id = client.start_submission(&student, &id);
+    client.submit_answers(&student, &submission_id, &single_answer(&env, qid, 1));
+
+    assert_eq!(analytics.recorded(), 1);
+    let session = analytics.last().unwrap();
+    assert_eq!(session.student, student);
+    assert_eq!(session.course_id, Symbol::new(&env, "C5"));
+    assert_eq!(session.score, Some(100));
+    assert_eq!(session.session_type, AnalyticsSessionType::Assessment);
+
+    // Resubmitting the same submission does not export it again
+    client.submit_answers(&student, &submission_id, &single_answer(&env, qid, 2));
+    assert_eq!(analytics.recorded(), 1);
+
+    // A second attempt is a separate submission and exported on its own
+    let second_id = client.start_submission(&student, &id);
+    client.submit_answers(&student, &second_id, &single_answer(&env, qid, 2));
+    assert_eq!(analytics.recorded(), 2);
+    assert_eq!(analytics.last().unwrap().score, Some(0));
+}
+
+#[test]
+fn test_analytics_export_failure_does_not_block_grading() {
+    let (env, client, admin) = setup();
+    // An address with no contract behind it makes the export call fail
+    let missing = Address::generate(&env);
+    client.set_integration(&admin, &Some(missing), &None, &None);
+
+    let (id, qid) = create_graded_assessment(&env, &client, &admin);
+    let student = Address::generate(&env);
+    let submission_id = client.start_submission(&student, &id);
+    let submission = client.submit_answers(&student, &submission_id, &single_answer(&env, qid, 1));
+
+    assert_eq!(submission.score, 4);
+    assert!(submission.passed);
+}
+
+#[contract]
+struct MockProgress;
+
+#[contractimpl]
+impl MockProgress {
+    pub fn update_progress(
+        env: Env,
+        student: Address,
+        course_id: Symbol,
+        module_id: Symbol,
+        percent: u32,
+    ) {
+        env.storage().instance().set(&(student, course_id, module_id), &percent);
+    }
+
+    pub fn module_percent(
+        env: Env,
+        student: Address,
+        course_id: Symbol,
+        module_id: Symbol,
+    ) -> Option<u32> {
+        env.storage().instance().get(&(student, course_id, module_id))
+    }
+}
+
+#[test]
+fn test_passing_attempt_marks_module_complete() {
+    let (env, client, admin) = setup();
+    let progress_id = env.register(MockProgress, ());
+    let progress = MockProgressClient::new(&env, &progress_id);
+    client.set_integration(&admin, &None, &Some(progress_id), &None);
+
+    let (id, qid) = create_graded_assessment(&env, &client, &admin);
+    let student = Address::generate(&env);
+    let submission_id = client.start_submission(&student, &id);
+    client.submit_answers(&student, &submission_id, &single_answer(&env, qid, 1));
+
+    let percent =
+        progress.module_percent(&student, &Symbol::new(&env, "C5"), &Symbol::new(&env, "M5"));
+    assert_eq!(percent, Some(100));
+}
+
+#[test]
+fn test_failing_attempt_leaves_module_progress_untouched() {
+    let (env, client, admin) = setup();
+    let progress_id = env.register(MockProgress, ());
+    let progress = MockProgressClient::new(&env, &progress_id);
+    client.set_integration(&admin, &None, &Some(progress_id), &None);
+
+    let (id, qid) = create_graded_assessment(&env, &client, &admin);
+    let student = Address::generate(&env);
+    let submission_id = client.start_submission(&student, &id);
+    let submission = client.submit_answers(&student, &submission_id, &single_answer(&env, qid, 2));
+
+    assert!(!submission.passed);
+    let percent =
+        progress.module_percent(&student, &Symbol::new(&env, "C5"), &Symbol::new(&env, "M5"));
+    assert_eq!(percent, None);
+}
+
+fn commitment_for(env: &Env, answers: &Vec<SubmittedAnswer>, salt: &BytesN<32>) -> BytesN<32> {
+    env.crypto().sha256(&(answers.clone(), salt.clone()).to_xdr(env)).into()
+}
+
+#[test]
+fn test_commit_reveal_grades_matching_answers() {
+    let (env, client, admin) = setup();
+    let (id, qid) = create_graded_assessment(&env, &client, &admin);
+    client.set_commit_reveal_required(&admin, &id, &true);
+
+    let student = Address::generate(&env);
+    let submission_id = client.start_submission(&student, &id);
+    let answers = single_answer(&env, qid, 1);
+
+    // Direct submission is rejected while commit-reveal is required
+    let direct = client.try_submit_answers(&student, &submission_id, &answers);
+    assert_eq!(direct, Err(Ok(AssessmentError::CommitmentRequired)));
+
+    let salt = BytesN::from_array(&env, &[7u8; 32]);
+    client.commit_answers(&student, &submission_id, &commitment_for(&env, &answers, &salt));
+    let submission = client.reveal_answers(&student, &submission_id, &answers, &salt);
+    assert_eq!(submission.score, 4);
+    assert!(submission.passed);
+}
+
+#[test]
+fn test_commit_reveal_rejects_mismatched_answers() {
+    let (env, client, admin) = setup();
+    let (id, qid) = create_graded_assessment(&env, &client, &admin);
+    client.set_commit_reveal_required(&admin, &id, &true);
+
+    let student = Address::generate(&env);
+    let submission_id = client.start_submission(&student, &id);
+
+    let salt = BytesN::from_array(&env, &[7u8; 32]);
+    let answers = single_answer(&env, qid, 1);
+    let missing = client.try_reveal_answers(&student, &submission_id, &answers, &salt);
+    assert_eq!(missing, Err(Ok(AssessmentError::CommitmentNotFound)));
+
+    let committed = single_answer(&env, qid, 2);
+    client.commit_answers(&student, &submission_id, &commitment_for(&env, &committed, &salt));
+    let revealed = client.try_reveal_answers(&student, &submission_id, &answers, &salt);
+    assert_eq!(revealed, Err(Ok(AssessmentError::CommitmentMismatch)));
+
+    // The committed answers only verify with the salt they were committed with
+    let wrong_salt = BytesN::from_array(&env, &[8u8; 32]);
+    let revealed = client.try_reveal_answers(&student, &submission_id, &committed, &wrong_salt);
+    assert_eq!(revealed, Err(Ok(AssessmentError::CommitmentMismatch)));
+
+    let submission = client.get_submission_details(&submission_id).unwrap();
+    assert_eq!(submission.answers.len(), 0);
+}
+
+fn create_proctored_assessment(
+    env: &Env,
+    client: &AssessmentClient,
+    admin: &Address,
+) -> (u64, u64) {
+    let config = AssessmentConfig {
+        time_limit_seconds: 0,
+        max_attempts: 2,
+        pass_score: 1,
+        allow_review: false,
+        is_adaptive: false,
+        proctoring_required: true,
+        shuffle_questions: false,
+    };
+    let id =
+        client.create_assessment(admin, &Symbol::new(env, "C6"), &Symbol::new(env, "M6"), &config);
+    client.publish_assessment(admin, &id);
+
+    let content_hash: BytesN<32> = env.crypto().sha256(&Bytes::new(env)).into();
+    let qid = client.add_question(
+        admin,
+        &id,
+        &QuestionType::SingleChoice,
+        &4u32,
+        &1u32,
+        &content_hash,
+        &Vec::new(env),
+        &AnswerKey::SingleChoice(1),
+    );
+    (id, qid)
+}
+
+fn attach_proctoring_evidence(
+    env: &Env,
+    client: &AssessmentClient,
+    admin: &Address,
+    submission_id: &BytesN<32>,
+) {
+    let evidence_hash: BytesN<32> = env.crypto().sha256(&Bytes::from_array(env, &[7u8])).into();
+    client.update_integrity_metadata(
+        admin,
+        submission_id,
+        &0u32,
+        &false,
+        &Vec::new(env),
+        &true,
+        &evidence_hash,
+    );
+}
+
+#[test]
+fn test_fresh_proctoring_evidence_accepted() {
+    let (env, client, admin) = setup();
+    client.set_proctoring_evidence_window(&admin, &600u64);
+    let (id, qid) = create_proctored_assessment(&env, &client, &admin);
+    let student = Address::generate(&env);
+    let submission_id = client.start_submission(&student, &id);
+
+    attach_proctoring_evidence(&env, &client, &admin, &submission_id);
+    env.ledger().with_mut(|li| li.timestamp += 600);
+    let submission = client.submit_answers(&student, &submission_id, &single_answer(&env, qid, 1));
+    assert!(submission.passed);
+}
+
+#[test]
+fn test_proctored_submission_without_evidence_not_gated() {
+    let (env, client, admin) = setup();
+    client.set_proctoring_evidence_window(&admin, &600u64);
+    let (id, qid) = create_proctored_assessment(&env, &client, &admin);
+    let student = Address::generate(&env);
+    let submission_id = client.start_submission(&student, &id);
+
+    env.ledger().with_mut(|li| li.timestamp += 601);
+    let submission = client.submit_answers(&student, &submission_id, &single_answer(&env, qid, 1));
+    assert!(submission.passed);
+}
+
+#[test]
+fn test_stale_proctoring_evidence_rejected() {
+    let (env, client, admin) = setup();
+    client.set_proctoring_evidence_window(&admin, &600u64);
+    assert_eq!(client.get_proctoring_evidence_window(), 600);
+    let (id, qid) = create_proctored_assessment(&env, &client, &admin);
+    let student = Address::generate(&env);
+    let submission_id = client.start_submission(&student, &id);
+
+    attach_proctoring_evidence(&env, &client, &admin, &submission_id);
+    env.ledger().with_mut(|li| li.timestamp += 601);
+    let result = client.try_submit_answers(&student, &submission_id, &single_answer(&env, qid, 1));
+    assert_eq!(result, Err(Ok(AssessmentError::ProctoringEvidenceExpired)));
+
+    // Re-attaching evidence refreshes its timestamp
+    attach_proctoring_evidence(&env, &client, &admin, &submission_id);
+    client.submit_answers(&student, &submission_id, &single_answer(&env, qid, 1));
+}
+
+fn submit_graded_attempt(
+    env: &Env,
+    client: &AssessmentClient,
+    student: &Address,
+    id: u64,
+    qid: u64,
+) {
+    let submission_id = client.start_submission(student, &id);
+    client.submit_answers(student, &submission_id, &single_answer(env, qid, 1));
+}
+
+#[test]
+fn test_retry_rejected_until_attempt_cooldown_elapses() {
+    let (env, client, admin) = setup();
+    env.ledger().with_mut(|li| li.timestamp = 10_000);
+    let (id, qid) = create_graded_assessment(&env, &client, &admin);
+    client.set_attempt_cooldown(&admin, &id, &3_600u64);
+    assert_eq!(client.get_attempt_cooldown(&id), 3_600);
+    let student = Address::generate(&env);
+
+    submit_graded_attempt(&env, &client, &student, id, qid);
+    let result = client.try_start_submission(&student, &id);
+    assert_eq!(result, Err(Ok(AssessmentError::AttemptCooldownActive)));
+
+    env.ledger().with_mut(|li| li.timestamp += 3_600);
+    submit_graded_attempt(&env, &client, &student, id, qid);
+}
+
+#[test]
+fn test_attempt_cooldown_tracked_per_assessment() {
+    let (env, client, admin) = setup();
+    env.ledger().with_mut(|li| li.timestamp = 10_000);
+    let (first, first_qid) = create_graded_assessment(&env, &client, &admin);
+    let (second, second_qid) = create_graded_assessment(&env, &client, &admin);
+    client.set_attempt_cooldown(&admin, &first, &3_600u64);
+    client.set_attempt_cooldown(&admin, &second, &3_600u64);
+    let rate_limits = AssessmentRateLimits {
+        max_submissions_per_day: 10,
+        max_answers_per_day: 10,
+        window_seconds: 86_400,
+    };
+    client.update_rate_limits(&admin, &rate_limits);
+    let student = Address::generate(&env);
+
+    submit_graded_attempt(&env, &client, &student, second, second_qid);
+    env.ledger().with_mut(|li| li.timestamp += 3_600);
+    submit_graded_attempt(&env, &client, &student, first, first_qid);
+
+    // A fresh submission elsewhere neither blocks the second assessment nor,
+    // once a new unsubmitted attempt is open, lifts the first one's cooldown
+    client.start_submission(&student, &second);
+    let result = client.try_start_submission(&student, &first);
+    assert_eq!(result, Err(Ok(AssessmentError::AttemptCooldownActive)));
+}
+
+#[test]
+fn test_accommodation_reduces_attempt_cooldown() {
+    let (env, client, admin) = setup();
+    env.ledger().with_mut(|li| li.timestamp = 10_000);
+    let (id, qid) = create_graded_assessment(&env, &client, &admin);
+    client.set_attempt_cooldown(&admin, &id, &3_600u64);
+    let student = Address::generate(&env);
+    let accommodation = AccommodationConfig {
+        extra_time_percent: 0,
+        extra_attempts: 0,
+        alt_format_provided: false,
+        cooldown_reduction_percent: 50,
+    };
+    client.set_accommodation(&admin, &student, &accommodation);
+
+    submit_graded_attempt(&env, &client, &student, id, qid);
+    env.ledger().with_mut(|li| li.timestamp += 1_799);
+    let result = client.try_start_submission(&student, &id);
+    assert_eq!(result, Err(Ok(AssessmentError::AttemptCooldownActive)));
+
+    env.ledger().with_mut(|li| li.timestamp += 1);
+    submit_graded_attempt(&env, &client, &student, id, qid);
+}
+
+fn create_essay_assessment(
+    env: &Env,
+    client: &AssessmentClient,
+    admin: &Address,
+    max_attempts: u32,
+) -> (u64, u64) {
+    let config = AssessmentConfig {
+        time_limit_seconds: 0,
+        max_attempts,
+        pass_score: 6,
+        allow_review: false,
+        is_adaptive: false,
+        proctoring_required: false,
+        shuffle_questions: false,
+    };
+    let id =
+        client.create_assessment(admin, &Symbol::new(env, "C7"), &Symbol::new(env, "M7"), &config);
+    client.publish_assessment(admin, &id);
+
+    let content_hash: BytesN<32> = env.crypto().sha256(&Bytes::new(env)).into();
+    let qid = client.add_question(
+        admin,
+        &id,
+        &QuestionType::Essay,
+        &10u32,
+        &1u32,
+        &content_hash,
+        &Vec::new(env),
+        &AnswerKey::Manual,
+    );
+    (id, qid)
+}
+
+fn essay_rubric(env: &Env) -> Vec<RubricCriterion> {
+    soroban_sdk::vec![
+        env,
+        RubricCriterion { description: String::from_str(env, "Thesis"), max_points: 4 },
+        RubricCriterion { description: String::from_str(env, "Evidence"), max_points: 6 },
+    ]
+}
+
+fn submit_essay(
+    env: &Env,
+    client: &AssessmentClient,
+    student: &Address,
+    id: u64,
+    qid: u64,
+) -> BytesN<32> {
+    let submission_id = client.start_submission(student, &id);
+    let answers = soroban_sdk::vec![
+        env,
+        SubmittedAnswer {
+            question_id: qid,
+            value: SubmittedAnswerValue::Essay(String::from_str(env, "essay")),
+        }
+    ];
+    let result = client.submit_answers(student, &submission_id, &answers);
+    assert_eq!(result.status, SubmissionStatus::RequiresManualReview);
+    submission_id
+}
+
+#[test]
+fn test_question_rubric_must_total_max_score() {
+    let (env, client, admin) = setup();
+    let (_, qid) = create_essay_assessment(&env, &client, &admin, 1);
+
+    let short = soroban_sdk::vec![
+        &env,
+        RubricCriterion { description: String::from_str(&env, "Thesis"), max_points: 4 }
+    ];
+    let result = client.try_set_question_rubric(&admin, &qid, &short);
+    assert_eq!(result, Err(Ok(AssessmentError::InvalidRubric)));
+
+    client.set_question_rubric(&admin, &qid, &essay_rubric(&env));
+    assert_eq!(client.get_question_rubric(&qid), Some(essay_rubric(&env)));
+}
+
+#[test]
+fn test_manual_grade_records_criterion_scores_against_rubric() {
+    let (env, client, admin) = setup();
+    let (id, qid) = create_essay_assessment(&env, &client, &admin, 1);
+    client.set_question_rubric(&admin, &qid, &essay_rubric(&env));
+    let student = Address::generate(&env);
+    let submission_id = submit_essay(&env, &client, &student, id, qid);
+
+    let task = client.get_manual_grading_task(&submission_id, &qid);
+    assert_eq!(task.rubric, essay_rubric(&env));
+    assert_eq!(task.max_score, 10);
+
+    let mismatched = soroban_sdk::vec![&env, 3u32, 3u32];
+    let result =
+        client.try_grade_manual_question(&admin, &submission_id, &qid, &7, &Some(mismatched));
+    assert_eq!(result, Err(Ok(AssessmentError::RubricScoreMismatch)));
+    let over_criterion = soroban_sdk::vec![&env, 5u32, 2u32];
+    let result =
+        client.try_grade_manual_question(&admin, &submission_id, &qid, &7, &Some(over_criterion));
+    assert_eq!(result, Err(Ok(AssessmentError::RubricScoreMismatch)));
+
+    let points = soroban_sdk::vec![&env, 3u32, 4u32];
+    let grade = client.grade_manual_question(&admin, &submission_id, &qid, &7, &Some(points));
+    let total: u32 = grade.criterion_scores.iter().sum();
+    assert_eq!(total, grade.score);
+    assert_eq!(client.get_manual_grade(&submission_id, &qid), Some(grade));
+
+    let submission = client.get_submission_details(&submission_id).unwrap();
+    assert_eq!(submission.score, 7);
+    assert!(submission.passed);
+    assert_eq!(submission.status, SubmissionStatus::Finalized);
+
+    let result = client.try_grade_manual_question(&admin, &submission_id, &qid, &7, &None);
+    assert_eq!(result, Err(Ok(AssessmentError::ManualReviewNotRequired)));
+}
+
+#[test]
+fn test_manual_grade_is_scoped_to_attempt() {
+    let (env, client, admin) = setup();
+    let (id, qid) = create_essay_assessment(&env, &client, &admin, 2);
+    let student = Address::generate(&env);
+
+    let submission_id = submit_essay(&env, &client, &student, id, qid);
+    client.grade_manual_question(&admin, &submission_id, &qid, &3, &None);
+    assert!(!client.get_submission_details(&submission_id).unwrap().passed);
+
+    // The retake reuses the submission id but is graded afresh
+    let retake_id = submit_essay(&env, &client, &student, id, qid);
+    assert_eq!(retake_id, submission_id);
+    assert_eq!(client.get_manual_grade(&retake_id, &qid), None);
+
+    let grade = client.grade_manual_question(&admin, &retake_id, &qid, &8, &None);
+    assert_eq!(client.get_manual_grade(&retake_id, &qid), Some(grade));
+    let submission = client.get_submission_details(&retake_id).unwrap();
+    assert_eq!(submission.attempt, 2);
+    assert_eq!(submission.score, 8);
+    assert!(submission.passed);
+    assert_eq!(submission.status, SubmissionStatus::Finalized);
+}
+
+fn create_empty_assessment(env: &Env, client: &AssessmentClient, instructor: &Address) -> u64 {
+    let config = AssessmentConfig {
+        time_limit_seconds: 0,
+        max_attempts: 1,
+        pass_score: 5,
+        allow_review: false,
+        is_adaptive: false,
+        proctoring_required: false,
+        shuffle_questions: false,
+    };
+    client.create_assessment(instructor, &Symbol::new(env, "C8"), &Symbol::new(env, "M8"), &config)
+}
+
+#[test]
+fn test_bank_question_attached_to_two_assessments_grades_both() {
+    let (env, client, admin) = setup();
+    let instructor = Address::generate(&env);
+    let content_hash: BytesN<32> = env.crypto().sha256(&Bytes::new(&env)).into();
+    let bank_qid = client.add_bank_question(
+        &instructor,
+        &BankQuestionParams {
+            question_type: QuestionType::SingleChoice,
+            max_score: 5,
+            difficulty: 2,
+            content_hash,
+            options: Vec::new(&env),
+            answer_key: AnswerKey::SingleChoice(3),
+        },
+    );
+
+    let first = create_empty_assessment(&env, &client, &instructor);
+    let second = create_empty_assessment(&env, &client, &instructor);
+    for id in [first, second] {
+        client.attach_bank_question(&instructor, &id, &bank_qid);
+        client.publish_assessment(&admin, &id);
+    }
+    let result = client.try_attach_bank_question(&instructor, &first, &bank_qid);
+    assert_eq!(result, Err(Ok(AssessmentError::QuestionAlreadyAttached)));
+
+    let student = Address::generate(&env);
+    let passed_id = client.start_submission(&student, &first);
+    let passed = client.submit_answers(&student, &passed_id, &single_answer(&env, bank_qid, 3));
+    assert_eq!((passed.score, passed.max_score), (5, 5));
+    assert!(passed.passed);
+
+    let failed_id = client.start_submission(&student, &second);
+    let failed = client.submit_answers(&student, &failed_id, &single_answer(&env, bank_qid, 1));
+    assert_eq!((failed.score, failed.max_score), (0, 5));
+    assert!(!failed.passed);
+}
+
+#[test]
+fn test_assessment_question_cannot_be_attached_from_bank() {
+    let (env, client, admin) = setup();
+    let (_, qid) = create_graded_assessment(&env, &client, &admin);
+    let other = create_empty_assessment(&env, &client, &admin);
+
+    let result = client.try_attach_bank_question(&admin, &other, &qid);
+    assert_eq!(result, Err(Ok(AssessmentError::NotBankQuestion)));
+}
@@ -44,7 +44,19 @@ pub enum AnswerKey {
 #[contracttype]
 pub struct Question {
     pub question_id: u64,
-    pub assessment_id: u64,
+    pub assessment_id: u64, // 0 for shared bank questions
+    pub question_type: QuestionType,
+    pub max_score: u32,
+    pub difficulty: u32, // 1-5 difficulty band
+    pub content_hash: BytesN<32>,
+    pub options: Vec<QuestionOption>,
+    pub answer_key: AnswerKey,
+}
+
+/// Question content submitted to the shared question bank.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct BankQuestionParams {
     pub question_type: QuestionType,
     pub max_score: u32,
     pub difficulty: u32, // 1-5 difficulty band
@@ -63,6 +75,8 @@ pub struct AssessmentConfig {
     pub allow_review: bool,
     pub is_adaptive: bool,
     pub proctoring_required: bool,
+    /// Present questions in a per-submission randomized order.
+    pub shuffle_questions: bool,
 }
 
 /// Accessibility and accommodation configuration per student.
@@ -73,6 +87,8 @@ pub struct AccommodationConfig {
     pub extra_time_percent: u32,
     /// Additional attempt allowance.
     pub extra_attempts: u32,
+    /// Cooldown reduction percentage between attempts (e.g. 50 => half the cooldown).
+    pub cooldown_reduction_percent: u32,
     /// Indicates that alternative formats are provisioned off-chain.
     pub alt_format_provided: bool,
 }
@@ -162,6 +178,38 @@ pub struct Submission {
     pub integrity: IntegrityMetadata,
 }
 
+/// One scoring criterion of a manually graded question's rubric.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct RubricCriterion {
+    pub description: String,
+    pub max_points: u32,
+}
+
+/// Everything a grader needs to score one manually graded answer.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ManualGradingTask {
+    pub submission_id: BytesN<32>,
+    pub question_id: u64,
+    pub max_score: u32,
+    pub answer: SubmittedAnswerValue,
+    /// Empty if no rubric has been configured for the question.
+    pub rubric: Vec<RubricCriterion>,
+}
+
+/// Grade recorded for one manually graded answer.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ManualGrade {
+    pub question_id: u64,
+    pub score: u32,
+    /// Points awarded per rubric criterion, in rubric order; empty if graded without a rubric.
+    pub criterion_scores: Vec<u32>,
+    pub grader: Address,
+    pub graded_at: u64,
+}
+
 /// Per-student adaptive testing state.
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[contracttype]
@@ -179,6 +227,37 @@ pub struct IntegrationConfig {
     pub security_monitor_contract: Option<Address>,
 }
 
+/// Session category mirrored from the analytics contract's `SessionType`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+#[repr(u32)]
+pub enum AnalyticsSessionType {
+    Study,
+    Assessment,
+    Practice,
+    Review,
+}
+
+/// Completed session exported to the analytics contract's `record_session`.
+///
+/// Field layout mirrors the analytics `LearningSession` so the value decodes
+/// on the receiving side.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct AnalyticsSession {
+    pub session_id: BytesN<32>,
+    pub student: Address,
+    pub course_id: Symbol,
+    pub module_id: Symbol,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub completion_percentage: u32,
+    pub time_spent: u64,
+    pub interactions: u32,
+    pub score: Option<u32>,
+    pub session_type: AnalyticsSessionType,
+}
+
 /// Configurable rate limits for assessment operations.
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[contracttype]
@@ -201,10 +280,24 @@ pub enum DataKey {
     Question(u64),
     Submission(BytesN<32>),
     StudentAssessmentSubmissions(Address, u64), // (student, assessment_id)
-    StudentAllSubmissions(Address), // student -> Vec<BytesN<32>>
+    StudentAllSubmissions(Address),             // student -> Vec<BytesN<32>>
     Schedule(u64),
     Accommodation(Address),
-    Adaptive(Address, u64),  // (student, assessment_id)
-    RateLimit(Address, u64), // (user, operation_id) -> RateLimitState
-    RateLimitCfg,            // AssessmentRateLimits
+    Adaptive(Address, u64),        // (student, assessment_id)
+    RateLimit(Address, u64),       // (user, operation_id) -> RateLimitState
+    RateLimitCfg,                  // AssessmentRateLimits
+    AnalyticsExported(BytesN<32>), // analytics session_id -> attempt recorded in analytics
+    CommitRevealRequired(u64),     // assessment_id -> bool
+    AnswerCommitment(BytesN<32>),  // submission_id -> sha256 of XDR-encoded answers
+    ProctoringEvidenceWindow,      // seconds proctoring evidence stays valid; 0 = no expiry
+    AttemptCooldown(u64),          // assessment_id -> seconds between attempts
+    QuestionRubric(u64),           // question_id -> Vec<RubricCriterion>
+    BankQuestionAuthor(u64),       // bank question_id -> authoring instructor
+    LastSubmittedAt(Address, u64), // (student, assessment_id) -> last submission timestamp
+    /// (submission_id, assessment_id, attempt, question_id) -> ManualGrade
+    ManualGrade(BytesN<32>, u64, u32, u64),
+    /// (submission_id, assessment_id, attempt) -> question ids in presented order
+    QuestionOrder(BytesN<32>, u64, u32),
+    /// (submission_id, assessment_id, attempt) -> ledger timestamp the proctoring evidence was attached
+    ProctoringEvidenceAt(BytesN<32>, u64, u32),
 }
@@ -96,6 +96,26 @@ pub enum CertificateError {
     InvalidProof = 100,
     /// The zero-knowledge verification process failed.
     VerificationFailed = 101,
+
+    // Transfers
+    /// The certificate already has a pending transfer awaiting acceptance.
+    TransferAlreadyPending = 110,
+    /// No pending transfer exists for the certificate.
+    TransferNotFound = 111,
+
+    // Issuance approval
+    /// The issuance approval workflow is enabled; submit an issuance request instead.
+    IssuanceApprovalRequired = 120,
+    /// No issuance request was found with the given ID.
+    IssuanceRequestNotFound = 121,
+
+    // Archival
+    /// The contract is archived and read-only; use the migrated contract instead.
+    Archived = 130,
+
+    // Verification log
+    /// The caller is not a registered verifier and may not append to verification logs.
+    VerifierNotRegistered = 140,
 }
 
 impl CertificateError {
@@ -139,6 +159,12 @@ impl CertificateError {
             Self::RateLimitExceeded => "CERT-090",
             Self::InvalidProof => "CERT-100",
             Self::VerificationFailed => "CERT-101",
+            Self::TransferAlreadyPending => "CERT-110",
+            Self::TransferNotFound => "CERT-111",
+            Self::IssuanceApprovalRequired => "CERT-120",
+            Self::IssuanceRequestNotFound => "CERT-121",
+            Self::Archived => "CERT-130",
+            Self::VerifierNotRegistered => "CERT-140",
         }
     }
 
@@ -167,6 +193,16 @@ impl CertificateError {
             Self::InvalidProof | Self::VerificationFailed => {
                 "Verify the zero-knowledge proof data and re-generate the proof if necessary"
             }
+            Self::TransferAlreadyPending => {
+                "Wait for the recipient to accept or cancel the pending transfer before retrying"
+            }
+            Self::Archived => "Send state changes to the contract this one was migrated to",
+            Self::VerifierNotRegistered => {
+                "Ask the contract admin to register the address with add_verifier"
+            }
+            Self::IssuanceApprovalRequired => {
+                "Submit the certificate through request_certificate_issuance and await approval"
+            }
             _ => "Review the certificate workflow state and retry the next valid operation",
         }
     }
@@ -1,6 +1,6 @@
 use shared::emit_certification_event;
 use shared::event_schema::{
-    BatchCompletedEvent, CertificateReissuedEvent, CertificateSharedEvent,
+    BatchCompletedEvent, BatchStartedEvent, CertificateReissuedEvent, CertificateSharedEvent,
     CertificateVerifiedEvent, CertificationEventData, CertificationIssuedEvent,
     CertificationRevokedEvent, ComplianceCheckedEvent, ComplianceViolationEvent,
     MultisigApprovalGrantedEvent, MultisigConfigUpdatedEvent, MultisigRequestApprovedEvent,
@@ -137,6 +137,16 @@ pub fn emit_multisig_config_updated(env: &Env, course_id: &String, admin: &Addre
     );
 }
 
+/// Emit when a batch operation starts.
+pub fn emit_batch_started(env: &Env, admin: &Address, total: u32) {
+    emit_certification_event!(
+        env,
+        symbol_short!("cert"),
+        admin.clone(),
+        CertificationEventData::BatchStarted(BatchStartedEvent { total })
+    );
+}
+
 /// Emit when a batch operation completes.
 pub fn emit_batch_completed(env: &Env, admin: &Address, total: u32, succeeded: u32, failed: u32) {
     emit_certification_event!(
@@ -8,10 +8,13 @@ pub mod two_factor_integration;
 pub mod types;
 
 #[cfg(test)]
-mod test;
+mod lifecycle_tests;
 
 use errors::CertificateError;
-use soroban_sdk::{contract, contractimpl, symbol_short, Address, BytesN, Env, Vec};
+use soroban_sdk::{
+    contract, contractimpl, vec, Address, BytesN, Env, IntoVal,
+    String, Symbol, Vec,
+};
 use types::CertificateStatus;
 
 #[contract]
@@ -19,39 +22,76 @@ pub struct CertificateContract;
 
 #[contractimpl]
 impl CertificateContract {
-    /// Initialize the certificate contract with an admin address.
-    pub fn initialize(env: Env, admin: Address) -> Result<(), CertificateError> {
+    /// Initialize the certificate contract with an admin address and the
+    /// maximum number of certificates `batch_issue_certificates` accepts per call.
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        max_batch_size: u32,
+    ) -> Result<(), CertificateError> {
         if storage::is_initialized(&env) {
             return Err(CertificateError::AlreadyInitialized);
         }
+        if max_batch_size == 0 {
+            return Err(CertificateError::InvalidConfig);
+        }
         storage::set_admin(&env, &admin);
+        storage::set_max_batch_size(&env, max_batch_size);
         storage::set_initialized(&env);
         Ok(())
     }
 
+    /// Freeze the contract to reads only, e.g. after migrating to a new
+    /// version through the proxy. While on, every mutating entrypoint fails
+    /// with `Archived`; getters keep working. Stays on until explicitly unset.
+    pub fn set_archival_mode(env: Env, admin: Address, on: bool) -> Result<(), CertificateError> {
+        require_admin(&env, &admin)?;
+        storage::set_archived(&env, on);
+        Ok(())
+    }
+
+    /// Return whether the contract is in read-only archival mode.
+    pub fn is_archived(env: Env) -> bool {
+        storage::is_archived(&env)
+    }
+
     /// Scan all issued certificates and remove storage entries for those that have
     /// passed their `expiry_date`, freeing ledger memory (fixes #439).
     ///
+    /// Active certificates flagged with `set_auto_revoke_on_expiry` are revoked
+    /// first, exactly as `update_expired_certificates` would, so their
+    /// revocation record and event survive the cleanup.
+    ///
     /// Only the contract admin may call this function.
     /// Returns the number of expired certificate entries that were cleaned up.
     pub fn cleanup_expired_certificates(
         env: Env,
         caller: Address,
     ) -> Result<u32, CertificateError> {
+        require_not_archived(&env)?;
         require_initialized(&env)?;
         require_admin(&env, &caller)?;
 
         let now = env.ledger().timestamp();
         let all_ids = storage::get_all_certificates(&env);
         let mut remaining: Vec<BytesN<32>> = Vec::new(&env);
+        let mut analytics = storage::get_analytics(&env);
         let mut cleaned: u32 = 0;
 
         for cert_id in all_ids.iter() {
             match storage::get_certificate(&env, &cert_id) {
                 Some(cert) => {
                     if cert.expiry_date > 0 && now >= cert.expiry_date {
-                        // Remove the storage entry to release ledger memory
+                        if cert.status == CertificateStatus::Active
+                            && storage::is_auto_revoke_on_expiry(&env, &cert_id)
+                        {
+                            revoke_at_expiry(&env, &cert_id, &caller, &mut analytics);
+                            analytics.active_certificates =
+                                analytics.active_certificates.saturating_sub(1);
+                        }
+                        // Remove the storage entries to release ledger memory
                         storage::remove_certificate(&env, &cert_id);
+                        storage::set_auto_revoke_on_expiry(&env, &cert_id, false);
                         cleaned += 1;
                     } else {
                         remaining.push_back(cert_id);
@@ -64,14 +104,86 @@ impl CertificateContract {
         }
 
         storage::set_all_certificates(&env, &remaining);
+        storage::set_analytics(&env, &analytics);
         Ok(cleaned)
     }
 
+    /// Move every active certificate past its `expiry_date` to `Expired`, or
+    /// to `Revoked` if it was flagged with `set_auto_revoke_on_expiry`.
+    ///
+    /// Auto-revoked certificates get a revocation record that is not eligible
+    /// for reissuance and emit the revoked event. Only the contract admin may
+    /// call this function. Returns the number of certificates transitioned.
+    pub fn update_expired_certificates(env: Env, caller: Address) -> Result<u32, CertificateError> {
+        require_not_archived(&env)?;
+        require_initialized(&env)?;
+        require_admin(&env, &caller)?;
+
+        let now = env.ledger().timestamp();
+        let mut analytics = storage::get_analytics(&env);
+        let mut updated: u32 = 0;
+
+        for cert_id in storage::get_all_certificates(&env).iter() {
+            let mut cert = match storage::get_certificate(&env, &cert_id) {
+                Some(cert) => cert,
+                None => continue,
+            };
+            if cert.status != CertificateStatus::Active
+                || cert.expiry_date == 0
+                || now < cert.expiry_date
+            {
+                continue;
+            }
+
+            if storage::is_auto_revoke_on_expiry(&env, &cert_id) {
+                cert.status = CertificateStatus::Revoked;
+                revoke_at_expiry(&env, &cert_id, &caller, &mut analytics);
+            } else {
+                cert.status = CertificateStatus::Expired;
+                analytics.total_expired += 1;
+            }
+            storage::set_certificate(&env, &cert_id, &cert);
+            analytics.active_certificates = analytics.active_certificates.saturating_sub(1);
+            updated += 1;
+        }
+
+        storage::set_analytics(&env, &analytics);
+        Ok(updated)
+    }
+
+    /// Flag a certificate to be revoked rather than expired by
+    /// `update_expired_certificates`, for credentials that must not remain on
+    /// record as valid after their expiry date.
+    pub fn set_auto_revoke_on_expiry(
+        env: Env,
+        admin: Address,
+        certificate_id: BytesN<32>,
+        enabled: bool,
+    ) -> Result<(), CertificateError> {
+        require_not_archived(&env)?;
+        require_admin(&env, &admin)?;
+        if storage::get_certificate(&env, &certificate_id).is_none() {
+            return Err(CertificateError::CertificateNotFound);
+        }
+        storage::set_auto_revoke_on_expiry(&env, &certificate_id, enabled);
+        Ok(())
+    }
+
+    /// Return whether a certificate is revoked, rather than expired, at expiry.
+    pub fn is_auto_revoke_on_expiry(env: Env, certificate_id: BytesN<32>) -> bool {
+        storage::is_auto_revoke_on_expiry(&env, &certificate_id)
+    }
+
     /// Return the number of certificates currently tracked in the global index.
     pub fn get_certificate_count(env: Env) -> u32 {
         storage::get_all_certificates(&env).len()
     }
 
+    /// Return the maximum number of certificates accepted per batch.
+    pub fn get_max_batch_size(env: Env) -> u32 {
+        storage::get_max_batch_size(&env)
+    }
+
     pub fn get_certificate(env: Env, certificate_id: BytesN<32>) -> Option<types::Certificate> {
         storage::get_certificate(&env, &certificate_id)
     }
@@ -90,13 +202,179 @@ impl CertificateContract {
         }
     }
 
+    /// Replace the translated titles and descriptions of an issued certificate.
+    pub fn set_certificate_localization(
+        env: Env,
+        admin: Address,
+        certificate_id: BytesN<32>,
+        localization: types::CertificateLocalization,
+    ) -> Result<(), CertificateError> {
+        require_not_archived(&env)?;
+        require_admin(&env, &admin)?;
+        if storage::get_certificate(&env, &certificate_id).is_none() {
+            return Err(CertificateError::CertificateNotFound);
+        }
+        storage::set_localization(&env, &certificate_id, &localization);
+        Ok(())
+    }
+
+    /// Return a certificate's title and description in `language`, falling
+    /// back to the default text for any field without a translation.
+    pub fn get_certificate_localized(
+        env: Env,
+        certificate_id: BytesN<32>,
+        language: String,
+    ) -> Option<types::LocalizedCertificateText> {
+        let cert = storage::get_certificate(&env, &certificate_id)?;
+        let localization = storage::get_localization(&env, &certificate_id);
+        let (title, description) = match localization {
+            Some(l) => (
+                l.titles.get(language.clone()).unwrap_or(cert.title),
+                l.descriptions.get(language.clone()).unwrap_or(cert.description),
+            ),
+            None => (cert.title, cert.description),
+        };
+        Some(types::LocalizedCertificateText { language, title, description })
+    }
+
+    /// Allow `verifier` to append to certificate verification logs.
+    pub fn add_verifier(
+        env: Env,
+        admin: Address,
+        verifier: Address,
+    ) -> Result<(), CertificateError> {
+        require_not_archived(&env)?;
+        require_admin(&env, &admin)?;
+        storage::set_verifier(&env, &verifier, true);
+        Ok(())
+    }
+
+    /// Revoke a verifier's permission to append to verification logs. Records
+    /// it already logged are kept.
+    pub fn remove_verifier(
+        env: Env,
+        admin: Address,
+        verifier: Address,
+    ) -> Result<(), CertificateError> {
+        require_not_archived(&env)?;
+        require_admin(&env, &admin)?;
+        storage::set_verifier(&env, &verifier, false);
+        Ok(())
+    }
+
+    /// Return whether `verifier` may append to verification logs.
+    pub fn is_verifier(env: Env, verifier: Address) -> bool {
+        storage::is_verifier(&env, &verifier)
+    }
+
+    /// Verify a certificate on behalf of `verifier` and append the outcome to
+    /// the certificate's verification log for later audit.
+    ///
+    /// Only registered verifiers (see `add_verifier`) may log, so the bounded
+    /// log cannot be flooded by arbitrary addresses.
+    pub fn log_verification(
+        env: Env,
+        verifier: Address,
+        certificate_id: BytesN<32>,
+    ) -> Result<types::VerificationRecord, CertificateError> {
+        require_not_archived(&env)?;
+        verifier.require_auth();
+        if !storage::is_verifier(&env, &verifier) {
+            return Err(CertificateError::VerifierNotRegistered);
+        }
+        if storage::get_certificate(&env, &certificate_id).is_none() {
+            return Err(CertificateError::CertificateNotFound);
+        }
+
+        let record = types::VerificationRecord {
+            certificate_id: certificate_id.clone(),
+            verifier: verifier.clone(),
+            verified_at: env.ledger().timestamp(),
+            is_valid: Self::verify_certificate(env.clone(), certificate_id.clone()),
+        };
+        storage::add_verification_record(&env, &certificate_id, &record);
+        events::emit_certificate_verified(&env, &verifier, &certificate_id, record.is_valid);
+        Ok(record)
+    }
+
+    /// Return up to `limit` verification records for a certificate, oldest
+    /// first, starting at `offset`.
+    pub fn get_verification_log(
+        env: Env,
+        certificate_id: BytesN<32>,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<types::VerificationRecord> {
+        let records = storage::get_verification_log(&env, &certificate_id);
+        let start = offset.min(records.len());
+        let end = start.saturating_add(limit).min(records.len());
+        records.slice(start..end)
+    }
+
+    /// Set how many verification records are kept per certificate; older
+    /// records are dropped once a log grows past the limit.
+    pub fn set_verification_log_limit(
+        env: Env,
+        admin: Address,
+        limit: u32,
+    ) -> Result<(), CertificateError> {
+        require_not_archived(&env)?;
+        require_admin(&env, &admin)?;
+        if limit == 0 {
+            return Err(CertificateError::InvalidConfig);
+        }
+        storage::set_verification_log_limit(&env, limit);
+        Ok(())
+    }
+
+    /// Return the number of verification records kept per certificate.
+    pub fn get_verification_log_limit(env: Env) -> u32 {
+        storage::get_verification_log_limit(&env)
+    }
+
+    /// Configure the token reward minted to students when a certificate is
+    /// issued; `None` turns rewards off.
+    pub fn set_token_reward(
+        env: Env,
+        admin: Address,
+        config: Option<types::TokenRewardConfig>,
+    ) -> Result<(), CertificateError> {
+        require_not_archived(&env)?;
+        require_admin(&env, &admin)?;
+        if matches!(&config, Some(config) if config.amount == 0) {
+            return Err(CertificateError::InvalidConfig);
+        }
+        storage::set_token_reward(&env, &config);
+        Ok(())
+    }
+
+    /// Return the token reward configuration, if rewards are enabled.
+    pub fn get_token_reward(env: Env) -> Option<types::TokenRewardConfig> {
+        storage::get_token_reward(&env)
+    }
+
+    /// Issue a batch of certificates. Batches larger than the configured
+    /// maximum are rejected with `BatchTooLarge` before anything is written.
+    ///
+    /// Fails with `IssuanceApprovalRequired` while the issuance approval
+    /// workflow is enabled.
     pub fn batch_issue_certificates(
         env: Env,
         admin: Address,
         params_list: Vec<types::MintCertificateParams>,
-    ) -> types::BatchResult {
-        require_admin(&env, &admin);
-        admin.require_auth();
+    ) -> Result<types::BatchResult, CertificateError> {
+        require_not_archived(&env)?;
+        require_admin(&env, &admin)?;
+        if storage::is_issuance_approval_required(&env) {
+            return Err(CertificateError::IssuanceApprovalRequired);
+        }
+        if params_list.is_empty() {
+            return Err(CertificateError::BatchEmpty);
+        }
+        if params_list.len() > storage::get_max_batch_size(&env) {
+            return Err(CertificateError::BatchTooLarge);
+        }
+        events::emit_batch_started(&env, &admin, params_list.len());
 
         let mut succeeded = 0;
         let mut failed = 0;
@@ -105,69 +383,66 @@ impl CertificateContract {
         for params in params_list.iter() {
             // Check if multi-sig is required for this course
             if let Some(config) = storage::get_multisig_config(&env, &params.course_id) {
-                // Create multi-sig request ID by hashing the certificate ID + student address
-                let mut hasher = env.crypto().sha256(&params.certificate_id.clone().into());
                 // For simplicity, we just use the cert ID hash as the request ID
-                let request_id: BytesN<32> = hasher.into();
+                let request_id: BytesN<32> =
+                    env.crypto().sha256(&params.certificate_id.clone().into()).into();
 
-                let request = types::MultiSigRequest {
+                let now = env.ledger().timestamp();
+                let request = types::MultiSigCertificateRequest {
                     request_id: request_id.clone(),
-                    params: params.clone(),
-                    approvals: Vec::new(&env),
+                    certificate_params: params.clone(),
+                    requester: admin.clone(),
+                    required_approvals: config.required_approvals,
+                    current_approvals: 0,
+                    approvers: config.authorized_approvers.clone(),
+                    approval_records: Vec::new(&env),
                     status: types::MultiSigRequestStatus::Pending,
-                    created_at: env.ledger().timestamp(),
+                    created_at: now,
+                    expires_at: now + config.timeout_duration,
+                    reason: String::from_str(&env, ""),
+                    priority: config.priority.clone(),
                 };
                 storage::set_multisig_request(&env, &request_id, &request);
                 storage::add_pending_request(&env, &request_id);
-                
-                events::emit_multisig_request_created(&env, &request_id, &params.course_id);
+
+                events::emit_multisig_request_created(
+                    &env,
+                    &request_id,
+                    &admin,
+                    &params.course_id,
+                );
                 succeeded += 1; // Counted as "submitted"
             } else {
                 // Issue immediately
-                let cert = types::Certificate {
-                    certificate_id: params.certificate_id.clone(),
-                    course_id: params.course_id.clone(),
-                    student: params.student.clone(),
-                    title: params.title.clone(),
-                    description: params.description.clone(),
-                    metadata_uri: params.metadata_uri.clone(),
-                    issued_at: env.ledger().timestamp(),
-                    expiry_date: params.expiry_date,
-                    status: types::CertificateStatus::Active,
-                    issuer: admin.clone(),
-                    version: 1,
-                    blockchain_anchor: None,
-                    template_id: None,
-                    share_count: 0,
-                };
-                storage::set_certificate(&env, &params.certificate_id, &cert);
-                storage::add_student_certificate(&env, &params.student, &params.certificate_id);
-                storage::add_to_all_certificates(&env, &params.certificate_id);
-                
-                // Update analytics
-                let mut analytics = storage::get_analytics(&env);
-                analytics.total_issued += 1;
-                analytics.active_certificates += 1;
-                storage::set_analytics(&env, &analytics);
-
-                events::emit_certificate_issued(&env, &params.certificate_id, &params.student);
-                certificate_ids.push_back(params.certificate_id.clone());
-                succeeded += 1;
+                match issue_certificate(&env, &params, &admin) {
+                    Ok(()) => {
+                        certificate_ids.push_back(params.certificate_id.clone());
+                        succeeded += 1;
+                    }
+                    Err(_) => failed += 1,
+                }
             }
         }
 
-        types::BatchResult {
+        events::emit_batch_completed(&env, &admin, params_list.len(), succeeded, failed);
+
+        Ok(types::BatchResult {
             total: params_list.len(),
             succeeded,
             failed,
             certificate_ids,
-        }
+        })
     }
 
-    pub fn configure_multisig(env: Env, admin: Address, config: types::MultiSigConfig) {
-        require_admin(&env, &admin);
-        admin.require_auth();
+    pub fn configure_multisig(
+        env: Env,
+        admin: Address,
+        config: types::MultiSigConfig,
+    ) -> Result<(), CertificateError> {
+        require_not_archived(&env)?;
+        require_admin(&env, &admin)?;
         storage::set_multisig_config(&env, &config.course_id, &config);
+        Ok(())
     }
 
     pub fn process_multisig_approval(
@@ -176,75 +451,157 @@ impl CertificateContract {
         request_id: BytesN<32>,
         approved: bool,
     ) -> Result<(), CertificateError> {
+        require_not_archived(&env)?;
         approver.require_auth();
 
         let mut request = storage::get_multisig_request(&env, &request_id)
-            .ok_or(CertificateError::RequestNotFound)?;
+            .ok_or(CertificateError::MultiSigRequestNotFound)?;
 
         if request.status != types::MultiSigRequestStatus::Pending {
-            return Err(CertificateError::InvalidStatus);
+            return Err(CertificateError::RequestNotPending);
         }
 
-        let config = storage::get_multisig_config(&env, &request.params.course_id)
-            .ok_or(CertificateError::ConfigNotFound)?;
-
         // Verify approver is authorized
-        if !config.authorized_approvers.contains(&approver) {
-            return Err(CertificateError::NotAuthorized);
+        if !request.approvers.contains(&approver) {
+            return Err(CertificateError::ApproverNotAuthorized);
+        }
+        if request.approval_records.iter().any(|record| record.approver == approver) {
+            return Err(CertificateError::AlreadyApproved);
         }
 
-        // Add approval
-        if approved {
-            if !request.approvals.contains(&approver) {
-                request.approvals.push_back(approver.clone());
-            }
-        } else {
+        request.approval_records.push_back(types::ApprovalRecord {
+            approver: approver.clone(),
+            approved,
+            timestamp: env.ledger().timestamp(),
+            signature_hash: None,
+            comments: String::from_str(&env, ""),
+        });
+
+        if !approved {
             request.status = types::MultiSigRequestStatus::Rejected;
             storage::set_multisig_request(&env, &request_id, &request);
             storage::remove_pending_request(&env, &request_id);
+            events::emit_multisig_request_rejected(&env, &request_id, &approver);
             return Ok(());
         }
 
+        request.current_approvals += 1;
+        events::emit_multisig_approval_granted(
+            &env,
+            &request_id,
+            &approver,
+            request.current_approvals,
+            request.required_approvals,
+        );
+
         // Check threshold
-        if request.approvals.len() >= config.required_approvals {
+        if request.current_approvals >= request.required_approvals {
+            // Execute: Issue the certificate with the final approver as "issuer" context
+            issue_certificate(&env, &request.certificate_params, &approver)?;
             request.status = types::MultiSigRequestStatus::Executed;
-            
-            // Execute: Issue the certificate
-            let params = &request.params;
-            let cert = types::Certificate {
-                certificate_id: params.certificate_id.clone(),
-                course_id: params.course_id.clone(),
-                student: params.student.clone(),
-                title: params.title.clone(),
-                description: params.description.clone(),
-                metadata_uri: params.metadata_uri.clone(),
-                issued_at: env.ledger().timestamp(),
-                expiry_date: params.expiry_date,
-                status: types::CertificateStatus::Active,
-                issuer: approver.clone(), // Final approver as "issuer" context
-                version: 1,
-                blockchain_anchor: None,
-                template_id: None,
-                share_count: 0,
-            };
-            storage::set_certificate(&env, &params.certificate_id, &cert);
-            storage::add_student_certificate(&env, &params.student, &params.certificate_id);
-            storage::add_to_all_certificates(&env, &params.certificate_id);
-            
-            // Update analytics
-            let mut analytics = storage::get_analytics(&env);
-            analytics.total_issued += 1;
-            analytics.active_certificates += 1;
-            storage::set_analytics(&env, &analytics);
-
             storage::remove_pending_request(&env, &request_id);
-            events::emit_certificate_issued(&env, &params.certificate_id, &params.student);
+            events::emit_multisig_request_approved(&env, &request_id, &approver);
         }
 
         storage::set_multisig_request(&env, &request_id, &request);
         Ok(())
     }
 
+    pub fn get_multisig_request(
+        env: Env,
+        request_id: BytesN<32>,
+    ) -> Option<types::MultiSigCertificateRequest> {
+        storage::get_multisig_request(&env, &request_id)
+    }
+
+    /// Configure the request-then-approve issuance workflow. While enabled,
+    /// direct minting is refused and certificates are only issued once one of
+    /// `config.approvers` approves a request.
+    pub fn configure_issuance_workflow(
+        env: Env,
+        admin: Address,
+        config: types::IssuanceWorkflowConfig,
+    ) -> Result<(), CertificateError> {
+        require_not_archived(&env)?;
+        require_admin(&env, &admin)?;
+        if config.enabled && config.approvers.is_empty() {
+            return Err(CertificateError::InvalidConfig);
+        }
+        storage::set_issuance_workflow(&env, &config);
+        Ok(())
+    }
+
+    /// Return the issuance workflow settings, if configured.
+    pub fn get_issuance_workflow(env: Env) -> Option<types::IssuanceWorkflowConfig> {
+        storage::get_issuance_workflow(&env)
+    }
+
+    /// Submit a certificate for approval; returns the pending request's ID.
+    pub fn request_certificate_issuance(
+        env: Env,
+        requester: Address,
+        params: types::MintCertificateParams,
+    ) -> Result<u64, CertificateError> {
+        require_not_archived(&env)?;
+        require_initialized(&env)?;
+        requester.require_auth();
+        if storage::get_certificate(&env, &params.certificate_id).is_some() {
+            return Err(CertificateError::CertificateAlreadyExists);
+        }
+
+        let request = types::IssuanceRequest {
+            request_id: storage::next_issuance_request_id(&env),
+            requester,
+            params,
+            status: types::IssuanceRequestStatus::Pending,
+            requested_at: env.ledger().timestamp(),
+            decided_by: None,
+            decided_at: 0,
+        };
+        storage::set_issuance_request(&env, &request);
+        Ok(request.request_id)
+    }
+
+    /// Approve a pending issuance request and mint its certificate.
+    ///
+    /// Only configured issuance approvers may approve, and never their own
+    /// request. Returns the minted certificate's ID.
+    pub fn approve_issuance(
+        env: Env,
+        approver: Address,
+        request_id: u64,
+    ) -> Result<BytesN<32>, CertificateError> {
+        require_not_archived(&env)?;
+        let mut request = load_pending_issuance_request(&env, &approver, request_id)?;
+        issue_certificate(&env, &request.params, &approver)?;
+
+        request.status = types::IssuanceRequestStatus::Approved;
+        request.decided_by = Some(approver);
+        request.decided_at = env.ledger().timestamp();
+        storage::set_issuance_request(&env, &request);
+        Ok(request.params.certificate_id)
+    }
+
+    /// Reject a pending issuance request; no certificate is minted.
+    pub fn reject_issuance(
+        env: Env,
+        approver: Address,
+        request_id: u64,
+    ) -> Result<(), CertificateError> {
+        require_not_archived(&env)?;
+        let mut request = load_pending_issuance_request(&env, &approver, request_id)?;
+
+        request.status = types::IssuanceRequestStatus::Rejected;
+        request.decided_by = Some(approver);
+        request.decided_at = env.ledger().timestamp();
+        storage::set_issuance_request(&env, &request);
+        Ok(())
+    }
+
+    pub fn get_issuance_request(env: Env, request_id: u64) -> Option<types::IssuanceRequest> {
+        storage::get_issuance_request(&env, request_id)
+    }
+
     pub fn get_analytics(env: Env) -> types::CertificateAnalytics {
         storage::get_analytics(&env)
     }
@@ -259,14 +616,14 @@ impl CertificateContract {
         certificate_id: BytesN<32>,
         reason: String,
     ) -> Result<(), CertificateError> {
-        require_admin(&env, &admin);
-        admin.require_auth();
+        require_not_archived(&env)?;
+        require_admin(&env, &admin)?;
 
         let mut cert = storage::get_certificate(&env, &certificate_id)
             .ok_or(CertificateError::CertificateNotFound)?;
 
         if cert.status == types::CertificateStatus::Revoked {
-            return Err(CertificateError::InvalidStatus);
+            return Err(CertificateError::CertificateRevoked);
         }
 
         cert.status = types::CertificateStatus::Revoked;
@@ -289,7 +646,7 @@ impl CertificateContract {
         }
         storage::set_analytics(&env, &analytics);
 
-        events::emit_certificate_revoked(&env, &certificate_id);
+        events::emit_certificate_revoked(&env, &certificate_id, &admin);
         Ok(())
     }
 
@@ -297,32 +654,123 @@ impl CertificateContract {
         storage::get_revocation(&env, &certificate_id)
     }
 
+    /// Start an escrow-style transfer of `certificate_id` from its owner to `to`.
+    ///
+    /// Ownership does not change until the recipient calls `accept_transfer`.
+    /// Only one transfer may be pending per certificate.
+    pub fn initiate_transfer(
+        env: Env,
+        from: Address,
+        to: Address,
+        certificate_id: BytesN<32>,
+    ) -> Result<(), CertificateError> {
+        require_not_archived(&env)?;
+        from.require_auth();
+
+        let cert = storage::get_certificate(&env, &certificate_id)
+            .ok_or(CertificateError::CertificateNotFound)?;
+        if cert.student != from {
+            return Err(CertificateError::Unauthorized);
+        }
+        if cert.status != CertificateStatus::Active {
+            return Err(CertificateError::CertificateRevoked);
+        }
+        if to == from {
+            return Err(CertificateError::InvalidInput);
+        }
+        if storage::get_pending_transfer(&env, &certificate_id).is_some() {
+            return Err(CertificateError::TransferAlreadyPending);
+        }
+
+        let transfer = types::PendingTransfer {
+            certificate_id: certificate_id.clone(),
+            from,
+            to,
+            initiated_at: env.ledger().timestamp(),
+        };
+        storage::set_pending_transfer(&env, &certificate_id, &transfer);
+        Ok(())
+    }
+
+    /// Complete a pending transfer, making `to` the certificate owner.
+    pub fn accept_transfer(
+        env: Env,
+        to: Address,
+        certificate_id: BytesN<32>,
+    ) -> Result<(), CertificateError> {
+        require_not_archived(&env)?;
+        to.require_auth();
+
+        let transfer = storage::get_pending_transfer(&env, &certificate_id)
+            .ok_or(CertificateError::TransferNotFound)?;
+        if transfer.to != to {
+            return Err(CertificateError::Unauthorized);
+        }
+
+        let mut cert = storage::get_certificate(&env, &certificate_id)
+            .ok_or(CertificateError::CertificateNotFound)?;
+        if cert.status != CertificateStatus::Active {
+            return Err(CertificateError::CertificateRevoked);
+        }
+
+        cert.student = to.clone();
+        storage::set_certificate(&env, &certificate_id, &cert);
+        storage::remove_student_certificate(&env, &transfer.from, &certificate_id);
+        storage::add_student_certificate(&env, &to, &certificate_id);
+        storage::remove_pending_transfer(&env, &certificate_id);
+        Ok(())
+    }
+
+    /// Withdraw a pending transfer. Only the sender may cancel.
+    pub fn cancel_transfer(
+        env: Env,
+        from: Address,
+        certificate_id: BytesN<32>,
+    ) -> Result<(), CertificateError> {
+        require_not_archived(&env)?;
+        from.require_auth();
+
+        let transfer = storage::get_pending_transfer(&env, &certificate_id)
+            .ok_or(CertificateError::TransferNotFound)?;
+        if transfer.from != from {
+            return Err(CertificateError::Unauthorized);
+        }
+
+        storage::remove_pending_transfer(&env, &certificate_id);
+        Ok(())
+    }
+
+    pub fn get_pending_transfer(
+        env: Env,
+        certificate_id: BytesN<32>,
+    ) -> Option<types::PendingTransfer> {
+        storage::get_pending_transfer(&env, &certificate_id)
+    }
+
     pub fn share_achievement(
         env: Env,
         user: Address,
         certificate_id: BytesN<32>,
-        platform: u32,
-        custom_message: String,
-    ) -> types::ShareRecord {
+        platform: String,
+    ) -> Result<types::ShareRecord, CertificateError> {
+        require_not_archived(&env)?;
         user.require_auth();
 
         let mut cert = storage::get_certificate(&env, &certificate_id)
-            .ok_or(CertificateError::CertificateNotFound)
-            .unwrap();
+            .ok_or(CertificateError::CertificateNotFound)?;
+        if cert.student != user {
+            return Err(CertificateError::Unauthorized);
+        }
 
         let share = types::ShareRecord {
             certificate_id: certificate_id.clone(),
-            user: user.clone(),
-            platform,
-            custom_message,
-            share_url: String::from_str(&env, "https://strellerminds.com/verify/"), // Mock URL prefix
-            timestamp: env.ledger().timestamp(),
-            engagement_count: 0,
-            verified: true,
+            shared_by: user.clone(),
+            shared_at: env.ledger().timestamp(),
+            platform: platform.clone(),
+            verification_url: String::from_str(&env, "https://strellerminds.com/verify/"), // Mock URL prefix
         };
+        storage::add_share_record(&env, &certificate_id, &share);
 
-        storage::set_share_record(&env, &certificate_id, &user, &share);
-        
         cert.share_count += 1;
         storage::set_certificate(&env, &certificate_id, &cert);
 
@@ -331,36 +779,25 @@ impl CertificateContract {
         analytics.total_shared += 1;
         storage::set_analytics(&env, &analytics);
 
-        events::emit_certificate_shared(&env, &certificate_id, &user, platform);
-        share
+        events::emit_certificate_shared(&env, &certificate_id, &user, &platform);
+        Ok(share)
     }
 
     pub fn get_certificate_shares(env: Env, certificate_id: BytesN<32>) -> Vec<types::ShareRecord> {
-        storage::get_certificate_shares(&env, &certificate_id)
+        storage::get_share_records(&env, &certificate_id)
     }
 
+    /// Return the shares `user` made of the certificates they currently hold.
     pub fn get_user_shares(env: Env, user: Address) -> Vec<types::ShareRecord> {
-        storage::get_user_shares(&env, &user)
-    }
-
-    pub fn update_engagement(
-        env: Env,
-        admin: Address,
-        certificate_id: BytesN<32>,
-        user: Address,
-        platform: u32,
-        engagement_count: u32,
-    ) -> Result<(), CertificateError> {
-        require_admin(&env, &admin);
-        admin.require_auth();
-
-        let mut share = storage::get_share_record(&env, &certificate_id, &user)
-            .ok_or(CertificateError::ShareNotFound)?;
-
-        share.engagement_count = engagement_count;
-        storage::set_share_record(&env, &certificate_id, &user, &share);
-
-        Ok(())
+        let mut shares = Vec::new(&env);
+        for certificate_id in storage::get_student_certificates(&env, &user).iter() {
+            for share in storage::get_share_records(&env, &certificate_id).iter() {
+                if share.shared_by == user {
+                    shares.push_back(share);
+                }
+            }
+        }
+        shares
     }
 }
 
@@ -376,6 +813,119 @@ fn require_admin(env: &Env, caller: &Address) -> Result<(), CertificateError> {
     Ok(())
 }
 
+/// Record a non-reissuable revocation for a certificate flagged with
+/// `set_auto_revoke_on_expiry` that has reached its expiry date.
+fn revoke_at_expiry(
+    env: &Env,
+    cert_id: &BytesN<32>,
+    revoked_by: &Address,
+    analytics: &mut types::CertificateAnalytics,
+) {
+    storage::set_revocation(
+        env,
+        cert_id,
+        &types::RevocationRecord {
+            certificate_id: cert_id.clone(),
+            revoked_by: revoked_by.clone(),
+            revoked_at: env.ledger().timestamp(),
+            reason: String::from_str(env, "Expired"),
+            reissuance_eligible: false,
+        },
+    );
+    analytics.total_revoked += 1;
+    events::emit_certificate_revoked(env, cert_id, revoked_by);
+}
+
+/// Mint the configured token reward to a student whose certificate was issued.
+///
+/// Best-effort: a missing configuration or a failing token call never blocks
+/// issuance.
+fn reward_student(env: &Env, student: &Address) {
+    let reward = match storage::get_token_reward(env) {
+        Some(reward) => reward,
+        None => return,
+    };
+
+    let _ = env.try_invoke_contract::<(), soroban_sdk::Error>(
+        &reward.token_contract,
+        &Symbol::new(env, "mint"),
+        vec![env, student.into_val(env), reward.amount.into_val(env)],
+    );
+}
+
+/// Write a newly issued certificate and update indexes, analytics, and rewards.
+fn issue_certificate(
+    env: &Env,
+    params: &types::MintCertificateParams,
+    issuer: &Address,
+) -> Result<(), CertificateError> {
+    if storage::get_certificate(env, &params.certificate_id).is_some() {
+        return Err(CertificateError::CertificateAlreadyExists);
+    }
+
+    let cert = types::Certificate {
+        certificate_id: params.certificate_id.clone(),
+        course_id: params.course_id.clone(),
+        student: params.student.clone(),
+        title: params.title.clone(),
+        description: params.description.clone(),
+        metadata_uri: params.metadata_uri.clone(),
+        issued_at: env.ledger().timestamp(),
+        expiry_date: params.expiry_date,
+        status: types::CertificateStatus::Active,
+        issuer: issuer.clone(),
+        version: 1,
+        blockchain_anchor: None,
+        template_id: None,
+        share_count: 0,
+    };
+    storage::set_certificate(env, &params.certificate_id, &cert);
+    storage::add_student_certificate(env, &params.student, &params.certificate_id);
+    storage::add_to_all_certificates(env, &params.certificate_id);
+
+    let mut analytics = storage::get_analytics(env);
+    analytics.total_issued += 1;
+    analytics.active_certificates += 1;
+    storage::set_analytics(env, &analytics);
+
+    events::emit_certificate_issued(
+        env,
+        &params.certificate_id,
+        &params.student,
+        &params.course_id,
+    );
+    reward_student(env, &params.student);
+    Ok(())
+}
+
+/// Load a pending issuance request after checking `approver` may decide it.
+fn load_pending_issuance_request(
+    env: &Env,
+    approver: &Address,
+    request_id: u64,
+) -> Result<types::IssuanceRequest, CertificateError> {
+    approver.require_auth();
+    let request = storage::get_issuance_request(env, request_id)
+        .ok_or(CertificateError::IssuanceRequestNotFound)?;
+
+    let is_approver = storage::get_issuance_workflow(env)
+        .is_some_and(|config| config.approvers.contains(approver));
+    if !is_approver || request.requester == *approver {
+        return Err(CertificateError::ApproverNotAuthorized);
+    }
+    if request.status != types::IssuanceRequestStatus::Pending {
+        return Err(CertificateError::RequestNotPending);
+    }
+    Ok(request)
+}
+
+fn require_not_archived(env: &Env) -> Result<(), CertificateError> {
+    if storage::is_archived(env) {
+        return Err(CertificateError::Archived);
+    }
+    Ok(())
+}
+
 fn require_initialized(env: &Env) -> Result<(), CertificateError> {
     if !storage::is_initialized(env) {
         return Err(CertificateError::NotInitialized);
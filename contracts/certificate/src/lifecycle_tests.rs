@@ -0,0 +1,875 @@
+use shared::event_schema::{CertificationEventData, EventData};
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, Events as _, Ledger as _},
+    Address, BytesN, Env, Map, String, Symbol, TryFromVal, Vec,
+};
+
+use crate::{
+    errors::CertificateError,
+    types::{
+        CertDataKey, CertificateLocalization, CertificatePriority, CertificateStatus,
+        IssuanceRequestStatus, IssuanceWorkflowConfig, MintCertificateParams, MultiSigConfig,
+        MultiSigRequestStatus, TokenRewardConfig,
+    },
+    CertificateContract, CertificateContractClient,
+};
+
+// ─────────────────────────────────────────────────────────────
+// Helper utilities
+// ─────────────────────────────────────────────────────────────
+const MAX_BATCH_SIZE: u32 = 25;
+
+fn setup_env() -> (Env, CertificateContractClient<'static>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(CertificateContract, ());
+    let client = CertificateContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+
+    client.initialize(&admin, &MAX_BATCH_SIZE);
+    (env, client, admin)
+}
+
+fn make_cert_params(env: &Env, course_id: &str, student: &Address) -> MintCertificateParams {
+    MintCertificateParams {
+        certificate_id: BytesN::from_array(env, &[1u8; 32]),
+        course_id: String::from_str(env, course_id),
+        student: student.clone(),
+        title: String::from_str(env, "Test Certificate"),
+        description: String::from_str(env, "Certificate for testing"),
+        metadata_uri: String::from_str(env, "https://example.com/cert/metadata"),
+        expiry_date: env.ledger().timestamp() + 31_536_000, // 1 year
+    }
+}
+
+fn make_multisig_config(
+    env: &Env,
+    course_id: &str,
+    approvers: &[Address],
+    required: u32,
+) -> MultiSigConfig {
+    let mut appr_vec: Vec<Address> = Vec::new(env);
+    for a in approvers {
+        appr_vec.push_back(a.clone());
+    }
+    MultiSigConfig {
+        course_id: String::from_str(env, course_id),
+        required_approvals: required,
+        authorized_approvers: appr_vec,
+        timeout_duration: 604_800, // 7 days
+        priority: CertificatePriority::Enterprise,
+        auto_execute: true,
+    }
+}
+
+// ─────────────────────────────────────────────────────────────
+// 1. Initialisation tests
+// ─────────────────────────────────────────────────────────────
+#[test]
+fn test_initialize() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(CertificateContract, ());
+    let client = CertificateContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+
+    client.initialize(&admin, &MAX_BATCH_SIZE);
+}
+
+#[test]
+fn test_double_initialize_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(CertificateContract, ());
+    let client = CertificateContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+
+    client.initialize(&admin, &MAX_BATCH_SIZE);
+    let result = client.try_initialize(&admin, &MAX_BATCH_SIZE);
+    assert!(result.is_err());
+}
+
+// ─────────────────────────────────────────────────────────────
+// 2. Multi-Sig Approval Flow
+// ─────────────────────────────────────────────────────────────
+/// Request ID `batch_issue_certificates` assigns to a multi-sig gated certificate.
+fn multisig_request_id(env: &Env, certificate_id: &BytesN<32>) -> BytesN<32> {
+    env.crypto().sha256(&certificate_id.clone().into()).into()
+}
+
+#[test]
+fn test_multisig_certificate_issued_once_threshold_met() {
+    let (env, client, admin) = setup_env();
+    let approvers = [Address::generate(&env), Address::generate(&env), Address::generate(&env)];
+    client.configure_multisig(&admin, &make_multisig_config(&env, "MULTISIG", &approvers, 2));
+
+    let student = Address::generate(&env);
+    let params = make_cert_params(&env, "MULTISIG", &student);
+    let mut list: Vec<MintCertificateParams> = Vec::new(&env);
+    list.push_back(params.clone());
+    client.batch_issue_certificates(&admin, &list);
+
+    // Nothing is minted until enough approvers sign off
+    let request_id = multisig_request_id(&env, &params.certificate_id);
+    assert!(client.get_certificate(&params.certificate_id).is_none());
+    assert_eq!(client.get_analytics().pending_requests, 1);
+
+    client.process_multisig_approval(&approvers[0], &request_id, &true);
+    let result = client.try_process_multisig_approval(&approvers[0], &request_id, &true);
+    assert_eq!(result, Err(Ok(CertificateError::AlreadyApproved)));
+    let request = client.get_multisig_request(&request_id).unwrap();
+    assert_eq!(request.current_approvals, 1);
+    assert_eq!(request.status, MultiSigRequestStatus::Pending);
+
+    client.process_multisig_approval(&approvers[1], &request_id, &true);
+    let request = client.get_multisig_request(&request_id).unwrap();
+    assert_eq!(request.status, MultiSigRequestStatus::Executed);
+    let cert = client.get_certificate(&params.certificate_id).unwrap();
+    assert_eq!(cert.status, CertificateStatus::Active);
+    assert_eq!(cert.issuer, approvers[1]);
+    assert_eq!(client.get_analytics().pending_requests, 0);
+}
+
+#[test]
+fn test_multisig_rejection_and_unauthorized_approver() {
+    let (env, client, admin) = setup_env();
+    let approvers = [Address::generate(&env), Address::generate(&env)];
+    client.configure_multisig(&admin, &make_multisig_config(&env, "MULTISIG", &approvers, 2));
+
+    let params = make_cert_params(&env, "MULTISIG", &Address::generate(&env));
+    let mut list: Vec<MintCertificateParams> = Vec::new(&env);
+    list.push_back(params.clone());
+    client.batch_issue_certificates(&admin, &list);
+    let request_id = multisig_request_id(&env, &params.certificate_id);
+
+    let result = client.try_process_multisig_approval(&Address::generate(&env), &request_id, &true);
+    assert_eq!(result, Err(Ok(CertificateError::ApproverNotAuthorized)));
+
+    client.process_multisig_approval(&approvers[0], &request_id, &false);
+    let request = client.get_multisig_request(&request_id).unwrap();
+    assert_eq!(request.status, MultiSigRequestStatus::Rejected);
+    let result = client.try_process_multisig_approval(&approvers[1], &request_id, &true);
+    assert_eq!(result, Err(Ok(CertificateError::RequestNotPending)));
+    assert!(client.get_certificate(&params.certificate_id).is_none());
+}
+
+// ─────────────────────────────────────────────────────────────
+// 3. Batch Certificate Issuance
+// ─────────────────────────────────────────────────────────────
+#[test]
+fn test_batch_issue_certificates() {
+    let (env, client, admin) = setup_env();
+
+    let mut params_list: Vec<MintCertificateParams> = Vec::new(&env);
+    for i in 0u8..3 {
+        let student = Address::generate(&env);
+        let mut cert_id_bytes = [0u8; 32];
+        cert_id_bytes[0] = 10 + i;
+        let params = MintCertificateParams {
+            certificate_id: BytesN::from_array(&env, &cert_id_bytes),
+            course_id: String::from_str(&env, "BATCH_COURSE"),
+            student,
+            title: String::from_str(&env, "Batch Cert"),
+            description: String::from_str(&env, "Batch issued"),
+            metadata_uri: String::from_str(&env, "https://example.com/batch"),
+            expiry_date: env.ledger().timestamp() + 31_536_000,
+        };
+        params_list.push_back(params);
+    }
+
+    let result = client.batch_issue_certificates(&admin, &params_list);
+    assert_eq!(result.total, 3);
+    assert_eq!(result.succeeded, 3);
+    assert_eq!(result.failed, 0);
+    assert_eq!(result.certificate_ids.len(), 3);
+
+    // Verify student certificates
+    let _student_certs = client.get_student_certificates(&params_list.first().unwrap().student);
+    // Note: since students were randomly generated each iteration, we need to check the last one
+    let last_student = params_list.last().unwrap().student.clone();
+    let last_certs = client.get_student_certificates(&last_student);
+    assert_eq!(last_certs.len(), 1, "Student should have 1 certificate");
+
+    // Verify analytics
+    let analytics = client.get_analytics();
+    assert_eq!(analytics.total_issued, 3);
+    assert_eq!(analytics.active_certificates, 3);
+}
+
+#[test]
+fn test_batch_issue_certificates_duplicate() {
+    let (env, client, admin) = setup_env();
+
+    let mut params_list: Vec<MintCertificateParams> = Vec::new(&env);
+    let student = Address::generate(&env);
+
+    // Add same certificate twice
+    let mut cert_id_bytes = [0u8; 32];
+    cert_id_bytes[0] = 10;
+    let params = MintCertificateParams {
+        certificate_id: BytesN::from_array(&env, &cert_id_bytes),
+        course_id: String::from_str(&env, "BATCH_COURSE"),
+        student: student.clone(),
+        title: String::from_str(&env, "Batch Cert"),
+        description: String::from_str(&env, "Batch issued"),
+        metadata_uri: String::from_str(&env, "https://example.com/batch"),
+        expiry_date: env.ledger().timestamp() + 31_536_000,
+    };
+
+    params_list.push_back(params.clone());
+    params_list.push_back(params.clone());
+
+    let result = client.batch_issue_certificates(&admin, &params_list);
+    assert_eq!(result.total, 2);
+    assert_eq!(result.succeeded, 1, "Duplicate should be ignored");
+    assert_eq!(result.failed, 1, "Duplicate should fail");
+}
+
+fn make_batch(env: &Env, size: u32) -> Vec<MintCertificateParams> {
+    let mut params_list: Vec<MintCertificateParams> = Vec::new(env);
+    for i in 0..size {
+        let mut cert_id_bytes = [0u8; 32];
+        cert_id_bytes[0] = 0xB0;
+        cert_id_bytes[1] = i as u8;
+        let mut params = make_cert_params(env, "CAPPED_COURSE", &Address::generate(env));
+        params.certificate_id = BytesN::from_array(env, &cert_id_bytes);
+        params_list.push_back(params);
+    }
+    params_list
+}
+
+#[test]
+fn test_batch_at_max_size_succeeds() {
+    let (env, client, admin) = setup_env();
+    assert_eq!(client.get_max_batch_size(), MAX_BATCH_SIZE);
+
+    let result = client.batch_issue_certificates(&admin, &make_batch(&env, MAX_BATCH_SIZE));
+    assert_eq!(result.succeeded, MAX_BATCH_SIZE);
+    assert_eq!(client.get_analytics().total_issued, MAX_BATCH_SIZE);
+}
+
+#[test]
+fn test_batch_over_max_size_rejected_without_writes() {
+    let (env, client, admin) = setup_env();
+    let params_list = make_batch(&env, MAX_BATCH_SIZE + 1);
+
+    let result = client.try_batch_issue_certificates(&admin, &params_list);
+    assert_eq!(result, Err(Ok(CertificateError::BatchTooLarge)));
+
+    assert_eq!(client.get_certificate_count(), 0);
+    assert_eq!(client.get_analytics().total_issued, 0);
+    assert!(client.get_certificate(&params_list.first().unwrap().certificate_id).is_none());
+}
+
+#[test]
+fn test_pre_upgrade_deployment_uses_default_batch_size() {
+    let (env, client, admin) = setup_env();
+    // Contracts initialized before the cap was configurable have no stored value
+    env.as_contract(&client.address, || {
+        env.storage().instance().remove(&CertDataKey::MaxBatchSize);
+    });
+    assert_eq!(client.get_max_batch_size(), u32::MAX);
+
+    // Those contracts never capped batches, so one over the new default still goes through
+    let result = client.batch_issue_certificates(&admin, &make_batch(&env, MAX_BATCH_SIZE + 1));
+    assert_eq!(result.succeeded, MAX_BATCH_SIZE + 1);
+}
+
+#[test]
+fn test_initialize_rejects_zero_batch_size() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(CertificateContract, ());
+    let client = CertificateContractClient::new(&env, &contract_id);
+
+    let result = client.try_initialize(&Address::generate(&env), &0);
+    assert_eq!(result, Err(Ok(CertificateError::InvalidConfig)));
+}
+
+#[test]
+fn test_batch_empty_fails() {
+    let (env, client, admin) = setup_env();
+    let empty: Vec<MintCertificateParams> = Vec::new(&env);
+
+    let result = client.try_batch_issue_certificates(&admin, &empty);
+    assert!(result.is_err());
+}
+
+/// Event type topic of every standard event emitted by the last call.
+fn emitted_event_types(env: &Env) -> Vec<Symbol> {
+    let mut types = Vec::new(env);
+    for (_, topics, _) in env.events().all().iter() {
+        if let Some(Ok(event_type)) = topics.get(3).map(|t| Symbol::try_from_val(env, &t)) {
+            types.push_back(event_type);
+        }
+    }
+    types
+}
+
+/// Payloads of the standard certification events emitted by the last call.
+fn emitted_certification_events(env: &Env) -> Vec<CertificationEventData> {
+    let mut events = Vec::new(env);
+    for (_, _, data) in env.events().all().iter() {
+        if let Ok((_, _, _, _, EventData::Certification(event))) =
+            <(u32, u64, BytesN<32>, u32, EventData)>::try_from_val(env, &data)
+        {
+            events.push_back(event);
+        }
+    }
+    events
+}
+
+#[test]
+fn test_batch_emits_started_and_completed_events() {
+    let (env, client, admin) = setup_env();
+    let mut params_list = make_batch(&env, 3);
+    // Re-issuing the first certificate is a known failure
+    params_list.push_back(params_list.first().unwrap());
+
+    let result = client.batch_issue_certificates(&admin, &params_list);
+    assert_eq!(result.succeeded, 3);
+    assert_eq!(result.failed, 1);
+
+    let types = emitted_event_types(&env);
+    assert_eq!(types.first().unwrap(), Symbol::new(&env, "batch_started"));
+    assert_eq!(types.last().unwrap(), Symbol::new(&env, "batch_completed"));
+    let issued = types.iter().filter(|t| *t == Symbol::new(&env, "cert_issued")).count();
+    assert_eq!(issued, 3);
+
+    let events = emitted_certification_events(&env);
+    match events.first().unwrap() {
+        CertificationEventData::BatchStarted(started) => assert_eq!(started.total, 4),
+        other => panic!("expected batch_started, got {:?}", other),
+    }
+    match events.last().unwrap() {
+        CertificationEventData::BatchCompleted(completed) => {
+            assert_eq!(completed.total, 4);
+            assert_eq!(completed.succeeded, 3);
+            assert_eq!(completed.failed, 1);
+        }
+        other => panic!("expected batch_completed, got {:?}", other),
+    }
+}
+
+#[contract]
+struct MockToken;
+
+#[contractimpl]
+impl MockToken {
+    pub fn mint(env: Env, to: Address, amount: u64) {
+        let balance: u64 = env.storage().instance().get(&to).unwrap_or(0);
+        env.storage().instance().set(&to, &(balance + amount));
+    }
+
+    pub fn balance(env: Env, account: Address) -> u64 {
+        env.storage().instance().get(&account).unwrap_or(0)
+    }
+}
+
+#[test]
+fn test_batch_issue_rewards_student_tokens_when_configured() {
+    let (env, client, admin) = setup_env();
+    let token_id = env.register(MockToken, ());
+    let token = MockTokenClient::new(&env, &token_id);
+    let config = TokenRewardConfig { token_contract: token_id, amount: 50 };
+    client.set_token_reward(&admin, &Some(config.clone()));
+    assert_eq!(client.get_token_reward(), Some(config));
+
+    let student = Address::generate(&env);
+    let mut params_list: Vec<MintCertificateParams> = Vec::new(&env);
+    params_list.push_back(make_cert_params(&env, "REWARD_COURSE", &student));
+    client.batch_issue_certificates(&admin, &params_list);
+
+    assert_eq!(token.balance(&student), 50);
+}
+
+#[test]
+fn test_batch_issue_skips_token_reward_when_not_configured() {
+    let (env, client, admin) = setup_env();
+    let token_id = env.register(MockToken, ());
+    let token = MockTokenClient::new(&env, &token_id);
+    client.set_token_reward(
+        &admin,
+        &Some(TokenRewardConfig { token_contract: token_id.clone(), amount: 50 }),
+    );
+    client.set_token_reward(&admin, &None);
+    assert_eq!(client.get_token_reward(), None);
+
+    let student = Address::generate(&env);
+    let mut params_list: Vec<MintCertificateParams> = Vec::new(&env);
+    params_list.push_back(make_cert_params(&env, "REWARD_COURSE", &student));
+    let result = client.batch_issue_certificates(&admin, &params_list);
+
+    assert_eq!(result.succeeded, 1);
+    assert_eq!(token.balance(&student), 0);
+
+    let zero = TokenRewardConfig { token_contract: token_id, amount: 0 };
+    let result = client.try_set_token_reward(&admin, &Some(zero));
+    assert_eq!(result, Err(Ok(CertificateError::InvalidConfig)));
+}
+
+// ─────────────────────────────────────────────────────────────
+// 4. Certificate Verification
+// ─────────────────────────────────────────────────────────────
+#[test]
+fn test_verify_certificate() {
+    let (env, client, admin) = setup_env();
+
+    let mut params_list: Vec<MintCertificateParams> = Vec::new(&env);
+    let student = Address::generate(&env);
+    let params = make_cert_params(&env, "VERIFY_COURSE", &student);
+    params_list.push_back(params.clone());
+
+    client.batch_issue_certificates(&admin, &params_list);
+
+    let is_valid = client.verify_certificate(&params.certificate_id);
+    assert!(is_valid);
+}
+
+#[test]
+fn test_verification_log_pages_records() {
+    let (env, client, admin) = setup_env();
+    let student = Address::generate(&env);
+    let params = make_cert_params(&env, "VERIFY_COURSE", &student);
+    let mut params_list: Vec<MintCertificateParams> = Vec::new(&env);
+    params_list.push_back(params.clone());
+    client.batch_issue_certificates(&admin, &params_list);
+
+    let mut verifiers: Vec<Address> = Vec::new(&env);
+    for i in 0..5u64 {
+        env.ledger().with_mut(|li| li.timestamp = 1_000 + i);
+        let verifier = Address::generate(&env);
+        client.add_verifier(&admin, &verifier);
+        assert!(client.log_verification(&verifier, &params.certificate_id).is_valid);
+        verifiers.push_back(verifier);
+    }
+
+    let first_page = client.get_verification_log(&params.certificate_id, &0, &2);
+    assert_eq!(first_page.len(), 2);
+    assert_eq!(first_page.get(0).unwrap().verifier, verifiers.get(0).unwrap());
+    assert_eq!(first_page.get(1).unwrap().verified_at, 1_001);
+
+    let last_page = client.get_verification_log(&params.certificate_id, &4, &2);
+    assert_eq!(last_page.len(), 1);
+    assert_eq!(last_page.get(0).unwrap().verifier, verifiers.get(4).unwrap());
+    assert!(client.get_verification_log(&params.certificate_id, &5, &2).is_empty());
+}
+
+#[test]
+fn test_verification_log_bounded_by_limit() {
+    let (env, client, admin) = setup_env();
+    let student = Address::generate(&env);
+    let params = make_cert_params(&env, "VERIFY_COURSE", &student);
+    let mut params_list: Vec<MintCertificateParams> = Vec::new(&env);
+    params_list.push_back(params.clone());
+    client.batch_issue_certificates(&admin, &params_list);
+    client.set_verification_log_limit(&admin, &3);
+
+    let mut last_verifier = Address::generate(&env);
+    for _ in 0..5 {
+        last_verifier = Address::generate(&env);
+        client.add_verifier(&admin, &last_verifier);
+        client.log_verification(&last_verifier, &params.certificate_id);
+    }
+
+    let log = client.get_verification_log(&params.certificate_id, &0, &10);
+    assert_eq!(log.len(), 3);
+    assert_eq!(log.last().unwrap().verifier, last_verifier);
+
+    let missing = BytesN::from_array(&env, &[0xEE; 32]);
+    let result = client.try_log_verification(&last_verifier, &missing);
+    assert_eq!(result, Err(Ok(CertificateError::CertificateNotFound)));
+}
+
+#[test]
+fn test_unregistered_verifier_cannot_log() {
+    let (env, client, admin) = setup_env();
+    let student = Address::generate(&env);
+    let params = make_cert_params(&env, "VERIFY_COURSE", &student);
+    let mut params_list: Vec<MintCertificateParams> = Vec::new(&env);
+    params_list.push_back(params.clone());
+    client.batch_issue_certificates(&admin, &params_list);
+
+    let stranger = Address::generate(&env);
+    let result = client.try_log_verification(&stranger, &params.certificate_id);
+    assert_eq!(result, Err(Ok(CertificateError::VerifierNotRegistered)));
+
+    let verifier = Address::generate(&env);
+    client.add_verifier(&admin, &verifier);
+    assert!(client.is_verifier(&verifier));
+    client.log_verification(&verifier, &params.certificate_id);
+
+    client.remove_verifier(&admin, &verifier);
+    assert!(!client.is_verifier(&verifier));
+    let result = client.try_log_verification(&verifier, &params.certificate_id);
+    assert_eq!(result, Err(Ok(CertificateError::VerifierNotRegistered)));
+    assert_eq!(client.get_verification_log(&params.certificate_id, &0, &10).len(), 1);
+}
+
+// ─────────────────────────────────────────────────────────────
+// 5. Revocation
+// ─────────────────────────────────────────────────────────────
+#[test]
+fn test_revoke_certificate() {
+    let (env, client, admin) = setup_env();
+
+    let student = Address::generate(&env);
+    let params = make_cert_params(&env, "REVOKE_COURSE", &student);
+    let mut list: Vec<MintCertificateParams> = Vec::new(&env);
+    list.push_back(params.clone());
+    client.batch_issue_certificates(&admin, &list);
+
+    client.revoke_certificate(
+        &admin,
+        &params.certificate_id,
+        &String::from_str(&env, "Academic dishonesty"),
+    );
+
+    let cert = client.get_certificate(&params.certificate_id).unwrap();
+    assert_eq!(cert.status, CertificateStatus::Revoked);
+
+    let analytics = client.get_analytics();
+    assert_eq!(analytics.total_revoked, 1);
+}
+
+// ─────────────────────────────────────────────────────────────
+// 6. Escrow Transfers
+// ─────────────────────────────────────────────────────────────
+fn issue_transferable_cert(
+    env: &Env,
+    client: &CertificateContractClient,
+    admin: &Address,
+    owner: &Address,
+) -> BytesN<32> {
+    let params = make_cert_params(env, "TRANSFER_COURSE", owner);
+    let mut list: Vec<MintCertificateParams> = Vec::new(env);
+    list.push_back(params.clone());
+    client.batch_issue_certificates(admin, &list);
+    params.certificate_id
+}
+
+#[test]
+fn test_initiate_and_accept_transfer() {
+    let (env, client, admin) = setup_env();
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let cert_id = issue_transferable_cert(&env, &client, &admin, &owner);
+
+    client.initiate_transfer(&owner, &buyer, &cert_id);
+    // Ownership does not move until the recipient accepts
+    assert_eq!(client.get_certificate(&cert_id).unwrap().student, owner);
+    assert!(client.get_pending_transfer(&cert_id).is_some());
+
+    client.accept_transfer(&buyer, &cert_id);
+
+    assert_eq!(client.get_certificate(&cert_id).unwrap().student, buyer);
+    assert!(client.get_student_certificates(&owner).is_empty());
+    assert_eq!(client.get_student_certificates(&buyer).len(), 1);
+    assert!(client.get_pending_transfer(&cert_id).is_none());
+}
+
+#[test]
+fn test_cancelled_transfer_cannot_be_accepted() {
+    let (env, client, admin) = setup_env();
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let cert_id = issue_transferable_cert(&env, &client, &admin, &owner);
+
+    client.initiate_transfer(&owner, &buyer, &cert_id);
+    client.cancel_transfer(&owner, &cert_id);
+
+    let result = client.try_accept_transfer(&buyer, &cert_id);
+    assert_eq!(result, Err(Ok(CertificateError::TransferNotFound)));
+    assert_eq!(client.get_certificate(&cert_id).unwrap().student, owner);
+}
+
+#[test]
+fn test_double_initiate_transfer_rejected() {
+    let (env, client, admin) = setup_env();
+    let owner = Address::generate(&env);
+    let cert_id = issue_transferable_cert(&env, &client, &admin, &owner);
+
+    client.initiate_transfer(&owner, &Address::generate(&env), &cert_id);
+    let result = client.try_initiate_transfer(&owner, &Address::generate(&env), &cert_id);
+    assert_eq!(result, Err(Ok(CertificateError::TransferAlreadyPending)));
+}
+
+// ─────────────────────────────────────────────────────────────
+// 7. Expiry Status Updates
+// ─────────────────────────────────────────────────────────────
+fn issue_and_expire(
+    env: &Env,
+    client: &CertificateContractClient,
+    admin: &Address,
+    auto_revoke: bool,
+) -> BytesN<32> {
+    let student = Address::generate(env);
+    let params = make_cert_params(env, "EXPIRY_COURSE", &student);
+    let mut list: Vec<MintCertificateParams> = Vec::new(env);
+    list.push_back(params.clone());
+    client.batch_issue_certificates(admin, &list);
+    if auto_revoke {
+        client.set_auto_revoke_on_expiry(admin, &params.certificate_id, &true);
+    }
+
+    // Nothing changes before the expiry date
+    assert_eq!(client.update_expired_certificates(admin), 0);
+    env.ledger().with_mut(|li| li.timestamp = params.expiry_date);
+    assert_eq!(client.update_expired_certificates(admin), 1);
+    params.certificate_id
+}
+
+#[test]
+fn test_auto_revoke_certificate_revoked_at_expiry() {
+    let (env, client, admin) = setup_env();
+    let cert_id = issue_and_expire(&env, &client, &admin, true);
+    assert!(emitted_event_types(&env).contains(Symbol::new(&env, "cert_revoked")));
+    assert!(client.is_auto_revoke_on_expiry(&cert_id));
+
+    let cert = client.get_certificate(&cert_id).unwrap();
+    assert_eq!(cert.status, CertificateStatus::Revoked);
+    let record = client.get_revocation_record(&cert_id).unwrap();
+    assert!(!record.reissuance_eligible);
+
+    let analytics = client.get_analytics();
+    assert_eq!(analytics.total_revoked, 1);
+    assert_eq!(analytics.total_expired, 0);
+}
+
+#[test]
+fn test_certificate_without_auto_revoke_expires() {
+    let (env, client, admin) = setup_env();
+    let cert_id = issue_and_expire(&env, &client, &admin, false);
+
+    let cert = client.get_certificate(&cert_id).unwrap();
+    assert_eq!(cert.status, CertificateStatus::Expired);
+    assert!(client.get_revocation_record(&cert_id).is_none());
+
+    let analytics = client.get_analytics();
+    assert_eq!(analytics.total_expired, 1);
+    assert_eq!(analytics.total_revoked, 0);
+}
+
+#[test]
+fn test_cleanup_revokes_flagged_certificates_before_removal() {
+    let (env, client, admin) = setup_env();
+    let student = Address::generate(&env);
+    let params = make_cert_params(&env, "EXPIRY_COURSE", &student);
+    let mut list: Vec<MintCertificateParams> = Vec::new(&env);
+    list.push_back(params.clone());
+    client.batch_issue_certificates(&admin, &list);
+    client.set_auto_revoke_on_expiry(&admin, &params.certificate_id, &true);
+
+    env.ledger().with_mut(|li| li.timestamp = params.expiry_date);
+    assert_eq!(client.cleanup_expired_certificates(&admin), 1);
+    assert!(emitted_event_types(&env).contains(Symbol::new(&env, "cert_revoked")));
+
+    assert!(client.get_certificate(&params.certificate_id).is_none());
+    assert!(!client.is_auto_revoke_on_expiry(&params.certificate_id));
+    let record = client.get_revocation_record(&params.certificate_id).unwrap();
+    assert!(!record.reissuance_eligible);
+    assert_eq!(client.get_analytics().total_revoked, 1);
+}
+
+// ─────────────────────────────────────────────────────────────
+// 8. Localization
+// ─────────────────────────────────────────────────────────────
+fn spanish_localization(env: &Env) -> CertificateLocalization {
+    let mut titles = Map::new(env);
+    titles.set(String::from_str(env, "es"), String::from_str(env, "Certificado de Prueba"));
+    let mut descriptions = Map::new(env);
+    descriptions.set(String::from_str(env, "es"), String::from_str(env, "Certificado de pruebas"));
+    CertificateLocalization { titles, descriptions }
+}
+
+#[test]
+fn test_localization_returned_for_language() {
+    let (env, client, admin) = setup_env();
+    let student = Address::generate(&env);
+    let params = make_cert_params(&env, "LOCALE_COURSE", &student);
+    let mut list: Vec<MintCertificateParams> = Vec::new(&env);
+    list.push_back(params.clone());
+    client.batch_issue_certificates(&admin, &list);
+    client.set_certificate_localization(
+        &admin,
+        &params.certificate_id,
+        &spanish_localization(&env),
+    );
+
+    let text = client
+        .get_certificate_localized(&params.certificate_id, &String::from_str(&env, "es"))
+        .unwrap();
+    assert_eq!(text.title, String::from_str(&env, "Certificado de Prueba"));
+    assert_eq!(text.description, String::from_str(&env, "Certificado de pruebas"));
+}
+
+#[test]
+fn test_localization_falls_back_to_default_for_missing_language() {
+    let (env, client, admin) = setup_env();
+    let student = Address::generate(&env);
+    let params = make_cert_params(&env, "LOCALE_COURSE", &student);
+    let mut list: Vec<MintCertificateParams> = Vec::new(&env);
+    list.push_back(params.clone());
+    client.batch_issue_certificates(&admin, &list);
+    client.set_certificate_localization(
+        &admin,
+        &params.certificate_id,
+        &spanish_localization(&env),
+    );
+
+    let text = client
+        .get_certificate_localized(&params.certificate_id, &String::from_str(&env, "fr"))
+        .unwrap();
+    assert_eq!(text.title, params.title);
+    assert_eq!(text.description, params.description);
+
+    let unknown = BytesN::from_array(&env, &[9u8; 32]);
+    let result =
+        client.try_set_certificate_localization(&admin, &unknown, &spanish_localization(&env));
+    assert_eq!(result, Err(Ok(CertificateError::CertificateNotFound)));
+}
+
+// ─────────────────────────────────────────────────────────────
+// 9. Issuance Approval Workflow
+// ─────────────────────────────────────────────────────────────
+fn enable_issuance_workflow(
+    env: &Env,
+    client: &CertificateContractClient,
+    admin: &Address,
+) -> Address {
+    let approver = Address::generate(env);
+    let mut approvers: Vec<Address> = Vec::new(env);
+    approvers.push_back(approver.clone());
+    client.configure_issuance_workflow(admin, &IssuanceWorkflowConfig { enabled: true, approvers });
+    approver
+}
+
+#[test]
+fn test_approved_issuance_request_mints_certificate() {
+    let (env, client, admin) = setup_env();
+    let approver = enable_issuance_workflow(&env, &client, &admin);
+    let instructor = Address::generate(&env);
+    let student = Address::generate(&env);
+    let params = make_cert_params(&env, "HIGH_VALUE", &student);
+
+    let request_id = client.request_certificate_issuance(&instructor, &params);
+    // Nothing is minted while the request is pending
+    assert!(client.get_certificate(&params.certificate_id).is_none());
+
+    let cert_id = client.approve_issuance(&approver, &request_id);
+    assert_eq!(cert_id, params.certificate_id);
+    let cert = client.get_certificate(&cert_id).unwrap();
+    assert_eq!(cert.student, student);
+    assert_eq!(cert.issuer, approver);
+    assert_eq!(client.get_student_certificates(&student).len(), 1);
+
+    let request = client.get_issuance_request(&request_id).unwrap();
+    assert_eq!(request.status, IssuanceRequestStatus::Approved);
+    assert_eq!(request.decided_by, Some(approver));
+    assert_eq!(client.get_analytics().total_issued, 1);
+}
+
+#[test]
+fn test_rejected_issuance_request_mints_nothing() {
+    let (env, client, admin) = setup_env();
+    let approver = enable_issuance_workflow(&env, &client, &admin);
+    let instructor = Address::generate(&env);
+    let student = Address::generate(&env);
+    let params = make_cert_params(&env, "HIGH_VALUE", &student);
+
+    let request_id = client.request_certificate_issuance(&instructor, &params);
+    client.reject_issuance(&approver, &request_id);
+
+    assert!(client.get_certificate(&params.certificate_id).is_none());
+    assert!(client.get_student_certificates(&student).is_empty());
+    assert_eq!(
+        client.get_issuance_request(&request_id).unwrap().status,
+        IssuanceRequestStatus::Rejected
+    );
+
+    let result = client.try_approve_issuance(&approver, &request_id);
+    assert_eq!(result, Err(Ok(CertificateError::RequestNotPending)));
+}
+
+#[test]
+fn test_direct_minting_gated_when_issuance_workflow_enabled() {
+    let (env, client, admin) = setup_env();
+    let approver = enable_issuance_workflow(&env, &client, &admin);
+    let student = Address::generate(&env);
+    let params = make_cert_params(&env, "HIGH_VALUE", &student);
+    let mut list: Vec<MintCertificateParams> = Vec::new(&env);
+    list.push_back(params.clone());
+
+    let result = client.try_batch_issue_certificates(&admin, &list);
+    assert_eq!(result, Err(Ok(CertificateError::IssuanceApprovalRequired)));
+    assert!(client.get_certificate(&params.certificate_id).is_none());
+
+    // Approval needs the distinct approver permission, not just admin rights
+    let request_id = client.request_certificate_issuance(&admin, &params);
+    let result = client.try_approve_issuance(&Address::generate(&env), &request_id);
+    assert_eq!(result, Err(Ok(CertificateError::ApproverNotAuthorized)));
+
+    let mut approvers: Vec<Address> = Vec::new(&env);
+    approvers.push_back(approver);
+    client
+        .configure_issuance_workflow(&admin, &IssuanceWorkflowConfig { enabled: false, approvers });
+    assert_eq!(client.batch_issue_certificates(&admin, &list).succeeded, 1);
+}
+
+// ─────────────────────────────────────────────────────────────
+// 10. Archival Mode
+// ─────────────────────────────────────────────────────────────
+#[test]
+fn test_archival_mode_rejects_mutations_and_keeps_reads() {
+    let (env, client, admin) = setup_env();
+    let owner = Address::generate(&env);
+    let cert_id = issue_transferable_cert(&env, &client, &admin, &owner);
+
+    client.set_archival_mode(&admin, &true);
+    assert!(client.is_archived());
+
+    let student = Address::generate(&env);
+    let mut params = make_cert_params(&env, "ARCHIVED_COURSE", &student);
+    params.certificate_id = BytesN::from_array(&env, &[2u8; 32]);
+    let mut list: Vec<MintCertificateParams> = Vec::new(&env);
+    list.push_back(params.clone());
+    let archived = Some(Ok(CertificateError::Archived));
+    assert_eq!(client.try_batch_issue_certificates(&admin, &list).err(), archived);
+    assert_eq!(client.try_request_certificate_issuance(&admin, &params).err(), archived);
+    assert_eq!(
+        client.try_revoke_certificate(&admin, &cert_id, &String::from_str(&env, "Migrated")).err(),
+        archived
+    );
+    assert_eq!(client.try_initiate_transfer(&owner, &student, &cert_id).err(), archived);
+    assert_eq!(
+        client
+            .try_configure_multisig(&admin, &make_multisig_config(&env, "ARCHIVED", &[], 1))
+            .err(),
+        archived
+    );
+
+    // Reads are unaffected
+    assert_eq!(client.get_certificate(&cert_id).unwrap().status, CertificateStatus::Active);
+    assert!(client.verify_certificate(&cert_id));
+    assert_eq!(client.get_student_certificates(&owner).len(), 1);
+    assert_eq!(client.get_analytics().total_issued, 1);
+}
+
+#[test]
+fn test_mutations_resume_when_archival_mode_disabled() {
+    let (env, client, admin) = setup_env();
+    let owner = Address::generate(&env);
+    let cert_id = issue_transferable_cert(&env, &client, &admin, &owner);
+
+    let result = client.try_set_archival_mode(&owner, &true);
+    assert_eq!(result, Err(Ok(CertificateError::Unauthorized)));
+
+    client.set_archival_mode(&admin, &true);
+    client.set_archival_mode(&admin, &false);
+    assert!(!client.is_archived());
+
+    client.revoke_certificate(&admin, &cert_id, &String::from_str(&env, "Resumed"));
+    assert_eq!(client.get_certificate(&cert_id).unwrap().status, CertificateStatus::Revoked);
+}
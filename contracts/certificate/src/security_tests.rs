@@ -32,6 +32,8 @@ mod security_tests {
     // Test Helpers
     // ─────────────────────────────────────────────────────────────
 
+    const MAX_BATCH_SIZE: u32 = 25;
+
     fn setup_env() -> (Env, CertificateContractClient<'static>, Address) {
         let env = Env::default();
         env.mock_all_auths();
@@ -40,7 +42,7 @@ mod security_tests {
         let client = CertificateContractClient::new(&env, &contract_id);
         let admin = Address::generate(&env);
 
-        client.initialize(&admin);
+        client.initialize(&admin, &MAX_BATCH_SIZE);
         (env, client, admin)
     }
 
@@ -100,12 +102,12 @@ mod security_tests {
 
         // Attempt to initialize as unauthorized caller
         // This should fail because require_auth() is called
-        let result = client.try_initialize(&unauthorized);
+        let result = client.try_initialize(&unauthorized, &MAX_BATCH_SIZE);
         
         // Vacuousness check: Verify that initialization with the correct admin succeeds
         // This confirms that auth is actually being checked
         env.mock_all_auths();
-        let result_admin = client.try_initialize(&admin);
+        let result_admin = client.try_initialize(&admin, &MAX_BATCH_SIZE);
         assert!(result_admin.is_ok());
     }
 
@@ -141,7 +143,7 @@ mod security_tests {
         
         // First initialization already done in setup_env()
         // Attempt second initialization
-        let result = client.try_initialize(&admin);
+        let result = client.try_initialize(&admin, &MAX_BATCH_SIZE);
         assert!(result.is_err());
 
         // Vacuousness check: Verify that single initialization succeeds
@@ -1,9 +1,11 @@
 use soroban_sdk::{Address, BytesN, Env, IntoVal, Map, String, Val, Vec};
 
 use crate::types::{
-    CertDataKey, Certificate, CertificateAnalytics, CertificateBackup, CertificateTemplate,
-    ComplianceRecord, MultiSigAuditEntry, MultiSigCertificateRequest, MultiSigConfig,
-    RecoveryRequest, RevocationRecord, ShareRecord, TemplateVersion,
+    CertDataKey, Certificate, CertificateAnalytics, CertificateBackup, CertificateLocalization,
+    CertificateTemplate, ComplianceRecord, IssuanceRequest, IssuanceWorkflowConfig,
+    MultiSigAuditEntry, MultiSigCertificateRequest, MultiSigConfig, PendingTransfer,
+    RecoveryRequest, RevocationRecord, ShareRecord, TemplateVersion, TokenRewardConfig,
+    VerificationRecord,
 };
 
 // ─────────────────────────────────────────────────────────────
@@ -42,6 +44,26 @@ pub fn set_initialized(env: &Env) {
     env.storage().instance().set(&CertDataKey::Initialized, &true);
 }
 
+pub fn set_archived(env: &Env, archived: bool) {
+    env.storage().instance().set(&CertDataKey::Archived, &archived);
+}
+
+pub fn is_archived(env: &Env) -> bool {
+    env.storage().instance().get(&CertDataKey::Archived).unwrap_or(false)
+}
+
+pub fn set_max_batch_size(env: &Env, max_batch_size: u32) {
+    env.storage().instance().set(&CertDataKey::MaxBatchSize, &max_batch_size);
+}
+
+/// Batch cap for deployments initialized before `max_batch_size` was
+/// configurable. Those contracts never capped batches, so they stay uncapped.
+pub const DEFAULT_MAX_BATCH_SIZE: u32 = u32::MAX;
+
+pub fn get_max_batch_size(env: &Env) -> u32 {
+    env.storage().instance().get(&CertDataKey::MaxBatchSize).unwrap_or(DEFAULT_MAX_BATCH_SIZE)
+}
+
 // ─────────────────────────────────────────────────────────────
 // Multi-Sig Configs
 // ─────────────────────────────────────────────────────────────
@@ -191,6 +213,17 @@ pub fn get_student_certificates(env: &Env, student: &Address) -> Vec<BytesN<32>>
         .unwrap_or_else(|| Vec::new(env))
 }
 
+pub fn remove_student_certificate(env: &Env, student: &Address, cert_id: &BytesN<32>) {
+    let certs = get_student_certificates(env, student);
+    let mut remaining: Vec<BytesN<32>> = Vec::new(env);
+    for id in certs.iter() {
+        if id != *cert_id {
+            remaining.push_back(id);
+        }
+    }
+    env.storage().persistent().set(&CertDataKey::StudentCertificates(student.clone()), &remaining);
+}
+
 pub fn has_course_student_certificate(env: &Env, course_id: &String, student: &Address) -> bool {
     env.storage()
         .persistent()
@@ -342,6 +375,55 @@ pub fn get_revocation(env: &Env, cert_id: &BytesN<32>) -> Option<RevocationRecor
     env.storage().persistent().get(&CertDataKey::RevocationRecord(cert_id.clone()))
 }
 
+// ─────────────────────────────────────────────────────────────
+// Transfers
+// ─────────────────────────────────────────────────────────────
+pub fn set_pending_transfer(env: &Env, cert_id: &BytesN<32>, transfer: &PendingTransfer) {
+    let key = CertDataKey::PendingTransfer(cert_id.clone());
+    env.storage().persistent().set(&key, transfer);
+    extend_ttl_persistent(env, &key);
+}
+
+pub fn get_pending_transfer(env: &Env, cert_id: &BytesN<32>) -> Option<PendingTransfer> {
+    env.storage().persistent().get(&CertDataKey::PendingTransfer(cert_id.clone()))
+}
+
+pub fn remove_pending_transfer(env: &Env, cert_id: &BytesN<32>) {
+    env.storage().persistent().remove(&CertDataKey::PendingTransfer(cert_id.clone()));
+}
+
+// ─────────────────────────────────────────────────────────────
+// Issuance Approval
+// ─────────────────────────────────────────────────────────────
+pub fn set_issuance_workflow(env: &Env, config: &IssuanceWorkflowConfig) {
+    env.storage().instance().set(&CertDataKey::IssuanceWorkflow, config);
+}
+
+pub fn get_issuance_workflow(env: &Env) -> Option<IssuanceWorkflowConfig> {
+    env.storage().instance().get(&CertDataKey::IssuanceWorkflow)
+}
+
+pub fn is_issuance_approval_required(env: &Env) -> bool {
+    get_issuance_workflow(env).is_some_and(|config| config.enabled)
+}
+
+pub fn set_issuance_request(env: &Env, request: &IssuanceRequest) {
+    let key = CertDataKey::IssuanceRequest(request.request_id);
+    env.storage().persistent().set(&key, request);
+    extend_ttl_persistent(env, &key);
+}
+
+pub fn get_issuance_request(env: &Env, request_id: u64) -> Option<IssuanceRequest> {
+    env.storage().persistent().get(&CertDataKey::IssuanceRequest(request_id))
+}
+
+pub fn next_issuance_request_id(env: &Env) -> u64 {
+    let c: u64 = env.storage().instance().get(&CertDataKey::IssuanceRequestCounter).unwrap_or(0);
+    let next = c + 1;
+    env.storage().instance().set(&CertDataKey::IssuanceRequestCounter, &next);
+    next
+}
+
 // ─────────────────────────────────────────────────────────────
 // Analytics
 // ─────────────────────────────────────────────────────────────
@@ -396,6 +478,57 @@ pub fn get_share_records(env: &Env, cert_id: &BytesN<32>) -> Vec<ShareRecord> {
         .unwrap_or_else(|| Vec::new(env))
 }
 
+// ─────────────────────────────────────────────────────────────
+// Verification Log
+// ─────────────────────────────────────────────────────────────
+/// Verification records kept per certificate when no limit is configured.
+pub const DEFAULT_VERIFICATION_LOG_LIMIT: u32 = 100;
+
+pub fn set_verification_log_limit(env: &Env, limit: u32) {
+    env.storage().instance().set(&CertDataKey::VerificationLogLimit, &limit);
+}
+
+pub fn get_verification_log_limit(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&CertDataKey::VerificationLogLimit)
+        .unwrap_or(DEFAULT_VERIFICATION_LOG_LIMIT)
+}
+
+pub fn set_verifier(env: &Env, verifier: &Address, registered: bool) {
+    let key = CertDataKey::Verifier(verifier.clone());
+    if registered {
+        env.storage().persistent().set(&key, &true);
+        extend_ttl_persistent(env, &key);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+pub fn is_verifier(env: &Env, verifier: &Address) -> bool {
+    env.storage().persistent().get(&CertDataKey::Verifier(verifier.clone())).unwrap_or(false)
+}
+
+/// Append a record, dropping the oldest ones once the log is over its limit.
+pub fn add_verification_record(env: &Env, cert_id: &BytesN<32>, record: &VerificationRecord) {
+    let key = CertDataKey::VerificationLog(cert_id.clone());
+    let mut records = get_verification_log(env, cert_id);
+    records.push_back(record.clone());
+    let limit = get_verification_log_limit(env);
+    while records.len() > limit {
+        records.pop_front();
+    }
+    env.storage().persistent().set(&key, &records);
+    extend_ttl_persistent(env, &key);
+}
+
+pub fn get_verification_log(env: &Env, cert_id: &BytesN<32>) -> Vec<VerificationRecord> {
+    env.storage()
+        .persistent()
+        .get(&CertDataKey::VerificationLog(cert_id.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
 // ─────────────────────────────────────────────────────────────
 // Audit Trail
 // ─────────────────────────────────────────────────────────────
@@ -522,3 +655,44 @@ pub fn set_all_certificates(env: &Env, ids: &Vec<BytesN<32>>) {
 pub fn remove_certificate(env: &Env, cert_id: &BytesN<32>) {
     env.storage().persistent().remove(&CertDataKey::Certificate(cert_id.clone()));
 }
+
+pub fn set_localization(env: &Env, cert_id: &BytesN<32>, localization: &CertificateLocalization) {
+    let key = CertDataKey::Localization(cert_id.clone());
+    env.storage().persistent().set(&key, localization);
+    extend_ttl_persistent(env, &key);
+}
+
+pub fn get_localization(env: &Env, cert_id: &BytesN<32>) -> Option<CertificateLocalization> {
+    env.storage().persistent().get(&CertDataKey::Localization(cert_id.clone()))
+}
+
+pub fn set_auto_revoke_on_expiry(env: &Env, cert_id: &BytesN<32>, enabled: bool) {
+    let key = CertDataKey::AutoRevokeOnExpiry(cert_id.clone());
+    if enabled {
+        env.storage().persistent().set(&key, &true);
+        extend_ttl_persistent(env, &key);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+pub fn is_auto_revoke_on_expiry(env: &Env, cert_id: &BytesN<32>) -> bool {
+    env.storage()
+        .persistent()
+        .get(&CertDataKey::AutoRevokeOnExpiry(cert_id.clone()))
+        .unwrap_or(false)
+}
+
+// ─────────────────────────────────────────────────────────────
+// Token Reward
+// ─────────────────────────────────────────────────────────────
+pub fn set_token_reward(env: &Env, config: &Option<TokenRewardConfig>) {
+    match config {
+        Some(config) => env.storage().instance().set(&CertDataKey::TokenReward, config),
+        None => env.storage().instance().remove(&CertDataKey::TokenReward),
+    }
+}
+
+pub fn get_token_reward(env: &Env) -> Option<TokenRewardConfig> {
+    env.storage().instance().get(&CertDataKey::TokenReward)
+}
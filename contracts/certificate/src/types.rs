@@ -112,6 +112,31 @@ pub struct MintCertificateParams {
     pub expiry_date: u64,
 }
 
+// ─────────────────────────────────────────────────────────────
+// Certificate Localization
+// ─────────────────────────────────────────────────────────────
+/// Translated certificate text keyed by language code (e.g. `"fr"`, `"es"`).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CertificateLocalization {
+    /// Localized titles keyed by language code.
+    pub titles: Map<String, String>,
+    /// Localized descriptions keyed by language code.
+    pub descriptions: Map<String, String>,
+}
+
+/// A certificate's title and description resolved for one language.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LocalizedCertificateText {
+    /// Language code that was requested.
+    pub language: String,
+    /// Localized title, or the certificate's default title if none exists for the language.
+    pub title: String,
+    /// Localized description, or the certificate's default description if none exists.
+    pub description: String,
+}
+
 // ─────────────────────────────────────────────────────────────
 // Multi-Sig Certificate Request
 // ─────────────────────────────────────────────────────────────
@@ -256,6 +281,23 @@ pub enum FieldType {
     Boolean,
 }
 
+// ─────────────────────────────────────────────────────────────
+// Pending Transfer
+// ─────────────────────────────────────────────────────────────
+/// Ownership transfer awaiting acceptance by the recipient.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingTransfer {
+    /// Identifier of the certificate being transferred.
+    pub certificate_id: BytesN<32>,
+    /// Current owner who initiated the transfer.
+    pub from: Address,
+    /// Recipient who must accept the transfer.
+    pub to: Address,
+    /// Unix timestamp (seconds) when the transfer was initiated.
+    pub initiated_at: u64,
+}
+
 // ─────────────────────────────────────────────────────────────
 // Revocation Record
 // ─────────────────────────────────────────────────────────────
@@ -408,6 +450,81 @@ pub struct ShareRecord {
     pub verification_url: String,
 }
 
+// ─────────────────────────────────────────────────────────────
+// Verification Log
+// ─────────────────────────────────────────────────────────────
+/// Record of a third party checking a certificate, kept for anti-fraud audits.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerificationRecord {
+    /// Identifier of the verified certificate.
+    pub certificate_id: BytesN<32>,
+    /// Address of the party that performed the verification.
+    pub verifier: Address,
+    /// Unix timestamp (seconds) when the verification occurred.
+    pub verified_at: u64,
+    /// Whether the certificate was active and unexpired at that time.
+    pub is_valid: bool,
+}
+
+// ─────────────────────────────────────────────────────────────
+// Token Reward
+// ─────────────────────────────────────────────────────────────
+/// Token payout made to a student whenever one of their certificates is issued.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenRewardConfig {
+    /// Token contract whose `mint(to, amount)` is invoked for the reward.
+    pub token_contract: Address,
+    /// Number of tokens minted to the student per issued certificate.
+    pub amount: u64,
+}
+
+// ─────────────────────────────────────────────────────────────
+// Issuance Approval Workflow
+// ─────────────────────────────────────────────────────────────
+/// Request-then-approve issuance settings for high-value credentials.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IssuanceWorkflowConfig {
+    /// When `true`, certificates can only be minted through an approved request.
+    pub enabled: bool,
+    /// Addresses permitted to approve or reject issuance requests.
+    pub approvers: Vec<Address>,
+}
+
+/// Lifecycle state of an issuance request.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum IssuanceRequestStatus {
+    /// Awaiting a decision from an approver.
+    Pending,
+    /// Approved and the certificate has been minted.
+    Approved,
+    /// Rejected; no certificate was minted.
+    Rejected,
+}
+
+/// A request to mint a certificate once an approver signs off.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IssuanceRequest {
+    /// Sequential identifier for this request.
+    pub request_id: u64,
+    /// Address that submitted the request.
+    pub requester: Address,
+    /// Parameters used to mint the certificate on approval.
+    pub params: MintCertificateParams,
+    /// Current lifecycle status of the request.
+    pub status: IssuanceRequestStatus,
+    /// Unix timestamp (seconds) when the request was created.
+    pub requested_at: u64,
+    /// Approver who approved or rejected the request, if decided.
+    pub decided_by: Option<Address>,
+    /// Unix timestamp (seconds) of the decision; 0 while pending.
+    pub decided_at: u64,
+}
+
 // ─────────────────────────────────────────────────────────────
 // Audit Trail Entry
 // ─────────────────────────────────────────────────────────────
@@ -543,6 +660,12 @@ pub enum CertDataKey {
     Admin,
     /// Flag indicating whether the contract has been initialised.
     Initialized,
+    /// Maximum number of certificates accepted by a single batch issuance call.
+    MaxBatchSize,
+    /// Flag freezing the contract to reads only while migrated away.
+    Archived,
+    /// Address allowed to override compliance outcomes.
+    ComplianceOfficer,
 
     // Multi-sig configs per course
     /// Multi-sig configuration keyed by course identifier.
@@ -575,6 +698,8 @@ pub enum CertDataKey {
     TemplateList,
     TemplateVersionHistory(String),
     LatestTemplateVersion(String),
+    /// Field values a certificate was issued with from its template.
+    TemplateValues(BytesN<32>),
 
     // Revocations
     /// Revocation record for a specific certificate.
@@ -592,6 +717,18 @@ pub enum CertDataKey {
     /// List of share records for a specific certificate.
     ShareRecords(BytesN<32>),
 
+    // Verification log
+    /// Most recent verification records for a specific certificate.
+    VerificationLog(BytesN<32>),
+    /// Maximum number of verification records kept per certificate.
+    VerificationLogLimit,
+    /// Marks an address allowed to append to verification logs.
+    Verifier(Address),
+
+    // Token reward
+    /// Token contract and amount rewarded to students on certificate issuance.
+    TokenReward,
+
     // Audit trail
     /// Audit trail entries for a specific multi-sig request.
     AuditTrail(BytesN<32>),
@@ -621,6 +758,22 @@ pub enum CertDataKey {
 
     /// Global list of all issued certificate IDs (used for expiry cleanup).
     AllCertificates,
+    /// Flag marking a certificate to be revoked, rather than expired, once past its expiry date.
+    AutoRevokeOnExpiry(BytesN<32>),
+    /// Translated titles and descriptions for a certificate.
+    Localization(BytesN<32>),
+
+    // Transfers
+    /// Pending two-step ownership transfer for a certificate.
+    PendingTransfer(BytesN<32>),
+
+    // Issuance approval
+    /// Request-then-approve issuance workflow settings.
+    IssuanceWorkflow,
+    /// A certificate issuance request keyed by its ID.
+    IssuanceRequest(u64),
+    /// Monotonically increasing counter for issuance request IDs.
+    IssuanceRequestCounter,
 }
 
 /// Configurable rate limits for certificate operations.
@@ -1,7 +1,12 @@
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{Address, Env, Vec};
 
+use crate::errors::Error;
+use crate::storage::CommunityStorage;
 use crate::types::*;
 
+/// Maximum number of members whose voting eligibility can be synced in one call.
+const MAX_ELIGIBILITY_SYNC_BATCH: u32 = 50;
+
 pub struct AnalyticsManager;
 
 impl AnalyticsManager {
@@ -65,19 +70,183 @@ impl AnalyticsManager {
         let stats = Self::get_user_stats(env, user);
 
         // Weighted reputation calculation
-        let reputation = stats.posts_created * 10
-            + stats.replies_given * 5
-            + stats.solutions_provided * 50
-            + stats.contributions_made * 100
-            + stats.events_attended * 25
-            + stats.mentorship_sessions * 75
-            + stats.helpful_votes_received * 15;
+        let earned = Self::earned_reputation(env, &stats);
+        let reputation = earned.saturating_sub(Self::apply_penalty_recovery(env, user, earned));
 
         // Update stored reputation
         let mut updated_stats = stats;
         updated_stats.reputation_score = reputation;
         env.storage().persistent().set(&CommunityKey::UserStats(user.clone()), &updated_stats);
 
+        Self::update_voting_eligibility(env, user, reputation);
+
         reputation
     }
+
+    /// Re-check stored reputation against the current voting threshold for a
+    /// batch of members, e.g. after the threshold changes or for members whose
+    /// reputation was last calculated before eligibility was tracked.
+    pub fn sync_voting_eligibility(env: &Env, users: &Vec<Address>) -> Result<(), Error> {
+        if users.len() > MAX_ELIGIBILITY_SYNC_BATCH {
+            return Err(Error::InvalidInput);
+        }
+        for user in users.iter() {
+            let reputation = Self::get_user_stats(env, &user).reputation_score;
+            Self::update_voting_eligibility(env, &user, reputation);
+        }
+        Ok(())
+    }
+
+    /// Keep the eligible-voter count in step as a member's reputation crosses
+    /// the voting threshold in either direction.
+    pub fn update_voting_eligibility(env: &Env, user: &Address, reputation: u32) {
+        let since_key = CommunityExtKey::EligibleSince(user.clone());
+        let counted = env.storage().persistent().has(&since_key);
+        let eligible = reputation >= CommunityStorage::get_config(env).vote_weight_threshold;
+        if counted == eligible {
+            return;
+        }
+
+        let count: u32 =
+            env.storage().persistent().get(&CommunityExtKey::EligibleVoterCount).unwrap_or(0);
+        if eligible {
+            env.storage().persistent().set(&since_key, &env.ledger().timestamp());
+            env.storage().persistent().set(&CommunityExtKey::EligibleVoterCount, &(count + 1));
+        } else {
+            env.storage().persistent().remove(&since_key);
+            env.storage()
+                .persistent()
+                .set(&CommunityExtKey::EligibleVoterCount, &count.saturating_sub(1));
+        }
+    }
+
+    fn earned_reputation(env: &Env, stats: &UserCommunityStats) -> u32 {
+        let weights = Self::get_reputation_weights(env);
+        [
+            (stats.posts_created, weights.post),
+            (stats.replies_given, weights.reply),
+            (stats.solutions_provided, weights.solution),
+            (stats.contributions_made, weights.contribution),
+            (stats.events_attended, weights.event),
+            (stats.mentorship_sessions, weights.mentorship),
+            (stats.helpful_votes_received, weights.helpful_vote),
+        ]
+        .iter()
+        .fold(0u32, |total, (count, weight)| total.saturating_add(count.saturating_mul(*weight)))
+    }
+
+    pub fn set_reputation_weights(env: &Env, weights: ReputationWeights) -> Result<(), Error> {
+        let any_weight = weights.post
+            | weights.reply
+            | weights.solution
+            | weights.contribution
+            | weights.event
+            | weights.mentorship
+            | weights.helpful_vote;
+        if any_weight == 0 {
+            return Err(Error::InvalidInput);
+        }
+        env.storage().persistent().set(&CommunityExtKey::ReputationWeights, &weights);
+        Ok(())
+    }
+
+    /// Configured reputation weights, or the original fixed weights if unset.
+    pub fn get_reputation_weights(env: &Env) -> ReputationWeights {
+        env.storage().persistent().get(&CommunityExtKey::ReputationWeights).unwrap_or(
+            ReputationWeights {
+                post: 10,
+                reply: 5,
+                solution: 50,
+                contribution: 100,
+                event: 25,
+                mentorship: 75,
+                helpful_vote: 15,
+            },
+        )
+    }
+
+    // ── Reputation penalties ───────────────────────────────────────────────
+
+    pub fn dock_reputation(env: &Env, user: &Address, amount: u32) -> Result<(), Error> {
+        if amount == 0 {
+            return Err(Error::InvalidInput);
+        }
+        let earned = Self::earned_reputation(env, &Self::get_user_stats(env, user));
+        let outstanding = Self::apply_penalty_recovery(env, user, earned);
+        let previous = Self::get_reputation_penalty(env, user);
+
+        let penalty = ReputationPenalty {
+            outstanding: outstanding + amount,
+            docked: previous.map_or(0, |p| p.docked) + amount,
+            earned_baseline: earned,
+            last_recovery_at: env.ledger().timestamp(),
+        };
+        env.storage().persistent().set(&CommunityExtKey::ReputationPenalty(user.clone()), &penalty);
+
+        Self::calculate_reputation(env, user);
+        Ok(())
+    }
+
+    pub fn get_reputation_penalty(env: &Env, user: &Address) -> Option<ReputationPenalty> {
+        env.storage().persistent().get(&CommunityExtKey::ReputationPenalty(user.clone()))
+    }
+
+    pub fn set_recovery_policy(
+        env: &Env,
+        policy: Option<ReputationRecoveryPolicy>,
+    ) -> Result<(), Error> {
+        match policy {
+            Some(p) => {
+                if p.recovery_period == 0 || p.recovery_percent == 0 || p.recovery_percent > 100 {
+                    return Err(Error::InvalidInput);
+                }
+                env.storage().persistent().set(&CommunityExtKey::ReputationRecoveryPolicy, &p);
+            }
+            None => env.storage().persistent().remove(&CommunityExtKey::ReputationRecoveryPolicy),
+        }
+        Ok(())
+    }
+
+    pub fn get_recovery_policy(env: &Env) -> Option<ReputationRecoveryPolicy> {
+        env.storage().persistent().get(&CommunityExtKey::ReputationRecoveryPolicy)
+    }
+
+    /// Lazily recover part of the user's penalty for every full recovery period
+    /// elapsed since the last one applied, then return the outstanding penalty.
+    ///
+    /// Each period can restore `recovery_percent` of the docked amount, but the
+    /// total restored is capped by the reputation earned since the penalty, so
+    /// an inactive user never recovers and nobody exceeds what they earned.
+    fn apply_penalty_recovery(env: &Env, user: &Address, earned: u32) -> u32 {
+        let key = CommunityExtKey::ReputationPenalty(user.clone());
+        let mut penalty: ReputationPenalty = match env.storage().persistent().get(&key) {
+            Some(p) => p,
+            None => return 0,
+        };
+        let policy = match Self::get_recovery_policy(env) {
+            Some(p) => p,
+            None => return penalty.outstanding,
+        };
+
+        let now = env.ledger().timestamp();
+        let periods = now.saturating_sub(penalty.last_recovery_at) / policy.recovery_period;
+        if periods == 0 {
+            return penalty.outstanding;
+        }
+
+        let step = (penalty.docked as u64 * policy.recovery_percent as u64 / 100).max(1);
+        let by_time = step.saturating_mul(periods).min(penalty.outstanding as u64) as u32;
+        let recovered = by_time.min(earned.saturating_sub(penalty.earned_baseline));
+
+        penalty.outstanding -= recovered;
+        penalty.earned_baseline += recovered;
+        penalty.last_recovery_at += periods * policy.recovery_period;
+
+        if penalty.outstanding == 0 {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &penalty);
+        }
+        penalty.outstanding
+    }
 }
@@ -6,6 +6,9 @@ use crate::storage::CommunityStorage;
 use crate::types::*;
 use shared::validation::{CoreValidator, ValidationConfig};
 
+/// Upper bound on occurrences generated for one recurring event (a year of weekly sessions).
+const MAX_RECURRING_OCCURRENCES: u32 = 52;
+
 pub struct EventManager;
 
 impl EventManager {
@@ -72,6 +75,89 @@ impl EventManager {
         Ok(event_id)
     }
 
+    /// Create every occurrence of a recurring event up front.
+    ///
+    /// Each occurrence is a regular event with its own registrations and
+    /// attendance; the first occurrence's ID identifies the series.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_recurring_event(
+        env: &Env,
+        organizer: &Address,
+        event_type: EventType,
+        title: String,
+        description: String,
+        start_time: u64,
+        end_time: u64,
+        max_participants: u32,
+        is_public: bool,
+        xp_reward: u32,
+        recurrence: EventRecurrence,
+    ) -> Result<Vec<u64>, Error> {
+        // Occurrences must not overlap
+        if recurrence.count == 0
+            || recurrence.count > MAX_RECURRING_OCCURRENCES
+            || recurrence.interval_seconds < end_time.saturating_sub(start_time)
+        {
+            return Err(Error::InvalidInput);
+        }
+
+        let series_id = Self::create_event(
+            env,
+            organizer,
+            event_type,
+            title,
+            description,
+            start_time,
+            end_time,
+            max_participants,
+            is_public,
+            xp_reward,
+        )?;
+        let template: CommunityEvent =
+            env.storage().persistent().get(&CommunityKey::Event(series_id)).unwrap();
+        env.storage().persistent().set(&CommunityExtKey::EventSeriesOf(series_id), &series_id);
+
+        let mut occurrence_ids = Vec::new(env);
+        occurrence_ids.push_back(series_id);
+        for i in 1..recurrence.count {
+            let offset = recurrence.interval_seconds.saturating_mul(i as u64);
+            let mut occurrence = template.clone();
+            occurrence.id = CommunityStorage::increment_counter(env, CommunityKey::EventCounter);
+            occurrence.start_time = start_time.saturating_add(offset);
+            occurrence.end_time = end_time.saturating_add(offset);
+            env.storage().persistent().set(&CommunityKey::Event(occurrence.id), &occurrence);
+            env.storage()
+                .persistent()
+                .set(&CommunityExtKey::EventSeriesOf(occurrence.id), &series_id);
+            CommunityEvents::emit_event_created(env, organizer, occurrence.id);
+            occurrence_ids.push_back(occurrence.id);
+        }
+
+        env.storage().persistent().set(&CommunityExtKey::EventSeries(series_id), &occurrence_ids);
+        Ok(occurrence_ids)
+    }
+
+    /// Return the first occurrence of a series that has not started yet.
+    pub fn get_next_occurrence(env: &Env, series_id: u64) -> Option<CommunityEvent> {
+        let now = env.ledger().timestamp();
+        Self::get_event_series(env, series_id)
+            .iter()
+            .filter_map(|id| Self::get_event(env, id))
+            .find(|event| event.start_time >= now)
+    }
+
+    pub fn get_event_series(env: &Env, series_id: u64) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&CommunityExtKey::EventSeries(series_id))
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// Return the series an event belongs to, or `None` for one-off events.
+    pub fn get_event_series_of(env: &Env, event_id: u64) -> Option<u64> {
+        env.storage().persistent().get(&CommunityExtKey::EventSeriesOf(event_id))
+    }
+
     pub fn register_for_event(env: &Env, user: &Address, event_id: u64) -> Result<(), Error> {
         let mut event: CommunityEvent = env
             .storage()
@@ -145,6 +231,10 @@ impl EventManager {
         let mut participant: EventParticipant =
             env.storage().persistent().get(&participant_key).ok_or(Error::NotFound)?;
 
+        // Duplicate marks are a no-op so XP is only awarded once per event
+        if participant.attended {
+            return Ok(());
+        }
         participant.attended = true;
         env.storage().persistent().set(&participant_key, &participant);
 
@@ -46,6 +46,12 @@ pub enum CommunityError {
     InvalidContributionStatus = 31,
     /// The user does not have enough reputation to perform this action.
     InsufficientReputation = 32,
+    /// The contribution is flagged for suspected plagiarism.
+    ContributionFlagged = 33,
+    /// The contribution has no plagiarism flag to clear.
+    ContributionNotFlagged = 34,
+    /// The contribution's rewards have already been claimed.
+    RewardAlreadyClaimed = 35,
 
     // Event errors
     /// The specified community event does not exist.
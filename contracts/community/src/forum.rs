@@ -2,6 +2,7 @@ use soroban_sdk::{Address, Env, String, Vec};
 
 use crate::errors::Error;
 use crate::events::CommunityEvents;
+use crate::moderation::{ModerationManager, CONTENT_TYPE_POST, CONTENT_TYPE_REPLY};
 use crate::storage::CommunityStorage;
 use crate::types::*;
 use shared::validation::{CoreValidator, ValidationConfig};
@@ -36,6 +37,20 @@ impl ForumManager {
         CoreValidator::validate_vec_size(tags.len(), "tags", ValidationConfig::MAX_TAGS)
             .map_err(|_| Error::InvalidInput)?;
 
+        // Gated categories require a minimum reputation
+        let min_reputation = Self::get_category_min_reputation(env, &category);
+        if min_reputation > 0 {
+            let reputation = env
+                .storage()
+                .persistent()
+                .get::<_, UserCommunityStats>(&CommunityKey::UserStats(author.clone()))
+                .map(|stats| stats.reputation_score)
+                .unwrap_or(0);
+            if reputation < min_reputation {
+                return Err(Error::InsufficientReputation);
+            }
+        }
+
         let post_id = CommunityStorage::increment_counter(env, CommunityKey::PostCounter);
         let now = env.ledger().timestamp();
 
@@ -90,6 +105,22 @@ impl ForumManager {
         Ok(post_id)
     }
 
+    pub fn set_category_min_reputation(env: &Env, category: ForumCategory, min_reputation: u32) {
+        let key = CommunityExtKey::CategoryMinReputation(category);
+        if min_reputation == 0 {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &min_reputation);
+        }
+    }
+
+    pub fn get_category_min_reputation(env: &Env, category: &ForumCategory) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&CommunityExtKey::CategoryMinReputation(category.clone()))
+            .unwrap_or(0)
+    }
+
     pub fn create_reply(
         env: &Env,
         author: &Address,
@@ -216,13 +247,18 @@ impl ForumManager {
             .get(&CommunityKey::Post(post_id))
             .ok_or(Error::PostNotFound)?;
 
+        let weight = i64::from(Self::vote_weight(env, voter));
+        let mut score = Self::get_post_score(env, post_id);
         if upvote {
             post.upvotes += 1;
+            score += weight;
         } else {
             post.downvotes += 1;
+            score -= weight;
         }
 
         env.storage().persistent().set(&CommunityKey::Post(post_id), &post);
+        env.storage().persistent().set(&CommunityExtKey::PostScore(post_id), &score);
         env.storage().persistent().set(&vote_key, &upvote);
 
         // Update author's helpful votes
@@ -235,7 +271,39 @@ impl ForumManager {
         Ok(())
     }
 
+    /// Weight of a forum vote: one unit per `reputation_step` of the voter's
+    /// reputation, at least 1 and at most `max_weight`.
+    fn vote_weight(env: &Env, voter: &Address) -> u32 {
+        let weighting = Self::get_vote_weighting(env);
+        if weighting.reputation_step == 0 {
+            return 1;
+        }
+        let reputation = Self::get_user_stats(env, voter).reputation_score;
+        (reputation / weighting.reputation_step).clamp(1, weighting.max_weight.max(1))
+    }
+
+    /// Reputation-weighted net score of a post (0 before any weighted vote).
+    pub fn get_post_score(env: &Env, post_id: u64) -> i64 {
+        env.storage().persistent().get(&CommunityExtKey::PostScore(post_id)).unwrap_or(0)
+    }
+
+    pub fn set_vote_weighting(env: &Env, weighting: ForumVoteWeighting) {
+        env.storage().persistent().set(&CommunityExtKey::ForumVoteWeighting, &weighting);
+    }
+
+    /// Configured vote weighting, or one unit per 100 reputation capped at 5 if unset.
+    pub fn get_vote_weighting(env: &Env) -> ForumVoteWeighting {
+        env.storage()
+            .persistent()
+            .get(&CommunityExtKey::ForumVoteWeighting)
+            .unwrap_or(ForumVoteWeighting { reputation_step: 100, max_weight: 5 })
+    }
+
     pub fn get_post(env: &Env, post_id: u64) -> Option<ForumPost> {
+        if ModerationManager::is_hidden(env, CONTENT_TYPE_POST, post_id) {
+            return None;
+        }
+
         let mut post: Option<ForumPost> =
             env.storage().persistent().get(&CommunityKey::Post(post_id));
 
@@ -256,6 +324,9 @@ impl ForumManager {
 
         let mut replies = Vec::new(env);
         for id in reply_ids.iter() {
+            if ModerationManager::is_hidden(env, CONTENT_TYPE_REPLY, id) {
+                continue;
+            }
             if let Some(reply) = env.storage().persistent().get(&CommunityKey::Reply(id)) {
                 replies.push_back(reply);
             }
@@ -274,6 +345,9 @@ impl ForumManager {
         let max = limit.min(post_ids.len());
         for i in 0..max {
             if let Some(id) = post_ids.get(i) {
+                if ModerationManager::is_hidden(env, CONTENT_TYPE_POST, id) {
+                    continue;
+                }
                 if let Some(post) = env.storage().persistent().get(&CommunityKey::Post(id)) {
                     posts.push_back(post);
                 }
@@ -1,5 +1,6 @@
 use soroban_sdk::{Address, Env, String, Vec};
 
+use crate::analytics::AnalyticsManager;
 use crate::errors::Error;
 use crate::events::CommunityEvents;
 use crate::storage::CommunityStorage;
@@ -53,6 +54,8 @@ impl GovernanceManager {
         if stats.reputation_score < config.vote_weight_threshold {
             return Err(Error::InsufficientVotingPower);
         }
+        // Eligibility follows the same stored reputation the threshold check uses
+        AnalyticsManager::update_voting_eligibility(env, proposer, stats.reputation_score);
 
         let proposal_id = CommunityStorage::increment_counter(env, CommunityKey::ProposalCounter);
         let now = env.ledger().timestamp();
@@ -70,8 +73,17 @@ impl GovernanceManager {
             voting_ends_at: now + voting_duration,
             min_votes_required,
         };
+        let turnout = ProposalTurnout {
+            eligible_voters: env
+                .storage()
+                .persistent()
+                .get(&CommunityExtKey::EligibleVoterCount)
+                .unwrap_or(0),
+            voter_count: 0,
+        };
 
         env.storage().persistent().set(&CommunityKey::Proposal(proposal_id), &proposal);
+        env.storage().persistent().set(&CommunityExtKey::ProposalTurnout(proposal_id), &turnout);
 
         // Add to active proposals
         let mut active: Vec<u64> = env
@@ -134,6 +146,7 @@ impl GovernanceManager {
         if stats.reputation_score < config.vote_weight_threshold {
             return Err(Error::InsufficientVotingPower);
         }
+        AnalyticsManager::update_voting_eligibility(env, voter, stats.reputation_score);
 
         // Weight votes by reputation (simplified: 1 vote per 100 reputation)
         let vote_weight = (stats.reputation_score / 100).max(1);
@@ -143,8 +156,19 @@ impl GovernanceManager {
         } else {
             proposal.votes_against += vote_weight;
         }
-
         env.storage().persistent().set(&CommunityKey::Proposal(proposal_id), &proposal);
+
+        // Only members already in the creation snapshot count towards turnout
+        let eligible_since: Option<u64> =
+            env.storage().persistent().get(&CommunityExtKey::EligibleSince(voter.clone()));
+        if let Some(mut turnout) = Self::get_proposal_turnout(env, proposal_id) {
+            if eligible_since.is_some_and(|since| since <= proposal.created_at) {
+                turnout.voter_count += 1;
+                env.storage()
+                    .persistent()
+                    .set(&CommunityExtKey::ProposalTurnout(proposal_id), &turnout);
+            }
+        }
         env.storage().persistent().set(&vote_key, &vote_for);
 
         CommunityEvents::emit_vote_cast(env, voter, proposal_id, vote_for);
@@ -168,8 +192,17 @@ impl GovernanceManager {
         }
 
         let total_votes = proposal.votes_for + proposal.votes_against;
+        // Proposals created before turnout was tracked have no snapshot to check
+        let min_quorum_percent = Self::get_min_quorum_percent(env);
+        let quorum_met = match Self::get_proposal_turnout(env, proposal_id) {
+            Some(turnout) if min_quorum_percent > 0 => {
+                turnout.voter_count as u64 * 100
+                    >= turnout.eligible_voters as u64 * min_quorum_percent as u64
+            }
+            _ => true,
+        };
 
-        if total_votes < proposal.min_votes_required {
+        if total_votes < proposal.min_votes_required || !quorum_met {
             proposal.status = ProposalStatus::Rejected;
         } else if proposal.votes_for > proposal.votes_against {
             proposal.status = ProposalStatus::Passed;
@@ -197,10 +230,27 @@ impl GovernanceManager {
         Ok(proposal.status)
     }
 
+    pub fn set_min_quorum_percent(env: &Env, percent: u32) -> Result<(), Error> {
+        if percent > 100 {
+            return Err(Error::InvalidInput);
+        }
+        env.storage().persistent().set(&CommunityExtKey::MinQuorumPercent, &percent);
+        Ok(())
+    }
+
+    /// Configured minimum turnout percentage, or 0 (no quorum check) if unset.
+    pub fn get_min_quorum_percent(env: &Env) -> u32 {
+        env.storage().persistent().get(&CommunityExtKey::MinQuorumPercent).unwrap_or(0)
+    }
+
     pub fn get_proposal(env: &Env, proposal_id: u64) -> Option<CommunityProposal> {
         env.storage().persistent().get(&CommunityKey::Proposal(proposal_id))
     }
 
+    pub fn get_proposal_turnout(env: &Env, proposal_id: u64) -> Option<ProposalTurnout> {
+        env.storage().persistent().get(&CommunityExtKey::ProposalTurnout(proposal_id))
+    }
+
     pub fn get_active_proposals(env: &Env) -> Vec<CommunityProposal> {
         let proposal_ids: Vec<u64> = env
             .storage()
@@ -2,6 +2,7 @@ use soroban_sdk::{Address, Env, String, Vec};
 
 use crate::errors::Error;
 use crate::events::CommunityEvents;
+use crate::moderation::{ModerationManager, CONTENT_TYPE_CONTRIBUTION};
 use crate::storage::CommunityStorage;
 use crate::types::*;
 
@@ -101,9 +102,11 @@ impl KnowledgeManager {
             // Update user stats
             Self::update_contributor_stats(env, &contribution.contributor);
 
-            // Award rewards
-            Self::award_xp(env, &contribution.contributor, xp);
-            Self::award_tokens(env, &contribution.contributor, tokens);
+            // Rewards are paid on claim; approvals from before the claim flow
+            // were paid at approval and never get this marker
+            env.storage()
+                .persistent()
+                .set(&CommunityExtKey::ContributionRewardPending(contribution_id), &true);
 
             CommunityEvents::emit_contribution_approved(env, contribution_id);
         } else {
@@ -152,7 +155,94 @@ impl KnowledgeManager {
         Ok(())
     }
 
+    /// Award an approved contribution's XP and tokens to its contributor, once.
+    ///
+    /// Contributions approved before rewards moved to this claim step were
+    /// already paid at approval and count as claimed.
+    ///
+    /// Flagged contributions cannot be claimed until a moderator clears the flag.
+    pub fn claim_contribution_reward(
+        env: &Env,
+        contributor: &Address,
+        contribution_id: u64,
+    ) -> Result<(u32, i128), Error> {
+        let contribution: KnowledgeContribution = env
+            .storage()
+            .persistent()
+            .get(&CommunityKey::Contribution(contribution_id))
+            .ok_or(Error::ContributionNotFound)?;
+
+        if contribution.contributor != *contributor {
+            return Err(Error::Unauthorized);
+        }
+        if contribution.status != ContributionStatus::Approved
+            && contribution.status != ContributionStatus::Published
+        {
+            return Err(Error::InvalidContributionStatus);
+        }
+        if env.storage().persistent().has(&CommunityExtKey::ContributionFlag(contribution_id)) {
+            return Err(Error::ContributionFlagged);
+        }
+        let pending_key = CommunityExtKey::ContributionRewardPending(contribution_id);
+        if !env.storage().persistent().has(&pending_key) {
+            return Err(Error::RewardAlreadyClaimed);
+        }
+
+        env.storage().persistent().remove(&pending_key);
+        Self::award_xp(env, contributor, contribution.xp_reward);
+        Self::award_tokens(env, contributor, contribution.token_reward);
+
+        Ok((contribution.xp_reward, contribution.token_reward))
+    }
+
+    /// Flag a contribution as suspected plagiarism, withholding its rewards.
+    pub fn flag_contribution(
+        env: &Env,
+        moderator: &Address,
+        contribution_id: u64,
+        reason: String,
+    ) -> Result<(), Error> {
+        CommunityStorage::require_moderator(env, moderator)?;
+
+        if !env.storage().persistent().has(&CommunityKey::Contribution(contribution_id)) {
+            return Err(Error::ContributionNotFound);
+        }
+        let flag_key = CommunityExtKey::ContributionFlag(contribution_id);
+        if env.storage().persistent().has(&flag_key) {
+            return Err(Error::ContributionFlagged);
+        }
+
+        let flag = ContributionFlag {
+            contribution_id,
+            moderator: moderator.clone(),
+            reason,
+            flagged_at: env.ledger().timestamp(),
+        };
+        env.storage().persistent().set(&flag_key, &flag);
+        Ok(())
+    }
+
+    /// Remove a contribution's plagiarism flag, making its rewards claimable again.
+    pub fn clear_flag(env: &Env, moderator: &Address, contribution_id: u64) -> Result<(), Error> {
+        CommunityStorage::require_moderator(env, moderator)?;
+
+        let flag_key = CommunityExtKey::ContributionFlag(contribution_id);
+        if !env.storage().persistent().has(&flag_key) {
+            return Err(Error::ContributionNotFlagged);
+        }
+        env.storage().persistent().remove(&flag_key);
+        Ok(())
+    }
+
+    pub fn get_contribution_flag(env: &Env, contribution_id: u64) -> Option<ContributionFlag> {
+        env.storage().persistent().get(&CommunityExtKey::ContributionFlag(contribution_id))
+    }
+
     pub fn get_contribution(env: &Env, contribution_id: u64) -> Option<KnowledgeContribution> {
+        if ModerationManager::is_hidden(env, CONTENT_TYPE_CONTRIBUTION, contribution_id) {
+            return None;
+        }
+
         let mut contribution: Option<KnowledgeContribution> =
             env.storage().persistent().get(&CommunityKey::Contribution(contribution_id));
 
@@ -179,6 +269,9 @@ impl KnowledgeManager {
         let max = limit.min(contrib_ids.len());
         for i in 0..max {
             if let Some(id) = contrib_ids.get(i) {
+                if ModerationManager::is_hidden(env, CONTENT_TYPE_CONTRIBUTION, id) {
+                    continue;
+                }
                 if let Some(contrib) =
                     env.storage().persistent().get(&CommunityKey::Contribution(id))
                 {
@@ -198,6 +291,9 @@ impl KnowledgeManager {
 
         let mut contributions = Vec::new(env);
         for id in contrib_ids.iter() {
+            if ModerationManager::is_hidden(env, CONTENT_TYPE_CONTRIBUTION, id) {
+                continue;
+            }
             if let Some(contrib) = env.storage().persistent().get(&CommunityKey::Contribution(id)) {
                 contributions.push_back(contrib);
             }
@@ -156,6 +156,7 @@ impl Community {
     ///
     /// # Errors
     /// Returns [`CommunityError::InvalidInput`] if required fields are empty.
+    /// Returns [`CommunityError::InsufficientReputation`] if the category requires more reputation than the author has.
     ///
     /// # Example
     /// ```ignore
@@ -185,6 +186,98 @@ impl Community {
         ForumManager::create_post(&env, &author, category, title, content, tags, course_id)
     }
 
+    /// Admin: set the minimum reputation required to post in a forum category.
+    ///
+    /// A value of `0` removes the requirement and reopens the category to everyone.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment.
+    /// * `admin` - Admin address (must match stored admin).
+    /// * `category` - Forum category to gate.
+    /// * `min_reputation` - Minimum `reputation_score` an author needs.
+    ///
+    /// # Errors
+    /// Returns [`CommunityError::Unauthorized`] if the caller is not the contract admin.
+    ///
+    /// # Example
+    /// ```ignore
+    /// client.set_category_min_reputation(&admin, &ForumCategory::ProjectShowcase, &50);
+    /// ```
+    pub fn set_category_min_reputation(
+        env: Env,
+        admin: Address,
+        category: ForumCategory,
+        min_reputation: u32,
+    ) -> Result<(), CommunityError> {
+        admin.require_auth();
+        CommunityStorage::require_admin(&env, &admin)?;
+        ForumManager::set_category_min_reputation(&env, category, min_reputation);
+        Ok(())
+    }
+
+    /// Return the minimum reputation required to post in a forum category (`0` if open).
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment.
+    /// * `category` - Forum category to query.
+    ///
+    /// # Example
+    /// ```ignore
+    /// client.get_category_min_reputation(&ForumCategory::ProjectShowcase);
+    /// ```
+    pub fn get_category_min_reputation(env: Env, category: ForumCategory) -> u32 {
+        ForumManager::get_category_min_reputation(&env, &category)
+    }
+
+    /// Return a post's net vote score, with each vote weighted by the voter's reputation.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment.
+    /// * `post_id` - ID of the post to query.
+    ///
+    /// # Example
+    /// ```ignore
+    /// client.get_post_score(&post_id);
+    /// ```
+    pub fn get_post_score(env: Env, post_id: u64) -> i64 {
+        ForumManager::get_post_score(&env, post_id)
+    }
+
+    /// Admin: set how voter reputation translates into forum vote weight.
+    ///
+    /// Each vote counts one unit per `reputation_step` of the voter's reputation,
+    /// at least 1 and at most `max_weight`; a `reputation_step` of `0` counts every
+    /// vote once.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment.
+    /// * `admin` - Admin address (must match stored admin).
+    /// * `weighting` - Reputation step and maximum vote weight.
+    ///
+    /// # Errors
+    /// Returns [`CommunityError::Unauthorized`] if the caller is not the contract admin.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let weighting = ForumVoteWeighting { reputation_step: 100, max_weight: 10 };
+    /// client.set_forum_vote_weighting(&admin, &weighting);
+    /// ```
+    pub fn set_forum_vote_weighting(
+        env: Env,
+        admin: Address,
+        weighting: ForumVoteWeighting,
+    ) -> Result<(), CommunityError> {
+        admin.require_auth();
+        CommunityStorage::require_admin(&env, &admin)?;
+        ForumManager::set_vote_weighting(&env, weighting);
+        Ok(())
+    }
+
+    /// Return the forum vote weighting in effect (the defaults if none is configured).
+    pub fn get_forum_vote_weighting(env: Env) -> ForumVoteWeighting {
+        ForumManager::get_vote_weighting(&env)
+    }
+
     /// Add a reply to an existing forum post, supporting nested threads via `parent_reply_id`.
     ///
     /// # Arguments
@@ -253,7 +346,9 @@ impl Community {
 
     /// Cast an upvote or downvote on a forum post.
     ///
-    /// Each user may only vote once per post.
+    /// Each user may only vote once per post. Besides the raw up/down counts,
+    /// the vote moves the post's score (see [`Community::get_post_score`]) by
+    /// the voter's reputation-based weight (see [`Community::set_forum_vote_weighting`]).
     ///
     /// # Arguments
     /// * `env` - The Soroban environment.
@@ -496,6 +591,61 @@ impl Community {
         MentorshipManager::get_mentor_profile(&env, &mentor)
     }
 
+    /// Recommend up to `limit` mentors for `mentee`, best fit first.
+    ///
+    /// Mentors whose `expertise_areas` include `topic` rank ahead of the
+    /// rest, then by rating and spare capacity. Mentors at `max_mentees` or
+    /// not accepting mentees are excluded.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment.
+    /// * `mentee` - Address of the user looking for a mentor.
+    /// * `topic` - Subject the mentee wants help with.
+    /// * `limit` - Maximum number of mentors to return (capped at 20).
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mentors = client.recommend_mentors(&mentee, &topic, &5);
+    /// ```
+    pub fn recommend_mentors(
+        env: Env,
+        mentee: Address,
+        topic: String,
+        limit: u32,
+    ) -> Vec<MentorProfile> {
+        MentorshipManager::recommend_mentors(&env, &mentee, &topic, limit)
+    }
+
+    /// Admin: add registered mentors that are missing from the mentor index
+    /// used by [`Community::recommend_mentors`], such as mentors who
+    /// registered before the index existed.
+    ///
+    /// Addresses without a mentor profile or already indexed are skipped.
+    /// Returns the number of mentors added.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment.
+    /// * `admin` - Admin address (must match stored admin).
+    /// * `mentors` - Mentor addresses to index (at most 50).
+    ///
+    /// # Errors
+    /// Returns [`CommunityError::Unauthorized`] if the caller is not the contract admin.
+    /// Returns [`CommunityError::InvalidInput`] if more than 50 addresses are given.
+    ///
+    /// # Example
+    /// ```ignore
+    /// client.index_mentors(&admin, &mentors);
+    /// ```
+    pub fn index_mentors(
+        env: Env,
+        admin: Address,
+        mentors: Vec<Address>,
+    ) -> Result<u32, CommunityError> {
+        admin.require_auth();
+        CommunityStorage::require_admin(&env, &admin)?;
+        MentorshipManager::index_mentors(&env, &mentors)
+    }
+
     // ══════════════════════════════════════════════════════════════════════
     //  Knowledge Base Functions
     // ══════════════════════════════════════════════════════════════════════
@@ -551,7 +701,9 @@ impl Community {
 
     /// Approve or reject a pending knowledge-base contribution.
     ///
-    /// Caller must hold a moderator role.
+    /// Caller must hold a moderator role. Approval sets the contribution's XP
+    /// and token rewards, which the contributor collects with
+    /// `claim_contribution_reward`.
     ///
     /// # Arguments
     /// * `env` - The Soroban environment.
@@ -577,6 +729,103 @@ impl Community {
         KnowledgeManager::review_contribution(&env, &moderator, contribution_id, approve)
     }
 
+    /// Claim the XP and tokens earned by an approved contribution.
+    ///
+    /// Rewards can be claimed once, by the contributor, and not while the
+    /// contribution carries a plagiarism flag.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment.
+    /// * `contributor` - Address of the contribution's author.
+    /// * `contribution_id` - ID of the approved contribution.
+    ///
+    /// # Errors
+    /// Returns [`CommunityError::ContributionNotFound`] if `contribution_id` does not exist.
+    /// Returns [`CommunityError::Unauthorized`] if `contributor` did not submit the contribution.
+    /// Returns [`CommunityError::InvalidContributionStatus`] if the contribution is not approved.
+    /// Returns [`CommunityError::ContributionFlagged`] if the contribution is flagged.
+    /// Returns [`CommunityError::RewardAlreadyClaimed`] if the rewards were already claimed,
+    /// including contributions approved (and paid) before the claim step existed.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let (xp, tokens) = client.claim_contribution_reward(&contributor, &contribution_id);
+    /// ```
+    pub fn claim_contribution_reward(
+        env: Env,
+        contributor: Address,
+        contribution_id: u64,
+    ) -> Result<(u32, i128), CommunityError> {
+        contributor.require_auth();
+        KnowledgeManager::claim_contribution_reward(&env, &contributor, contribution_id)
+    }
+
+    /// Flag a contribution as suspected plagiarism, excluding it from rewards
+    /// until the flag is cleared.
+    ///
+    /// Caller must hold a moderator role.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment.
+    /// * `moderator` - Address of the moderator raising the flag.
+    /// * `contribution_id` - ID of the contribution to flag.
+    /// * `reason` - Why the contribution is suspected of plagiarism.
+    ///
+    /// # Errors
+    /// Returns [`CommunityError::NotModerator`] if the caller lacks moderator privileges.
+    /// Returns [`CommunityError::ContributionNotFound`] if `contribution_id` does not exist.
+    /// Returns [`CommunityError::ContributionFlagged`] if the contribution is already flagged.
+    ///
+    /// # Example
+    /// ```ignore
+    /// client.flag_contribution(&moderator, &contribution_id, &reason);
+    /// ```
+    pub fn flag_contribution(
+        env: Env,
+        moderator: Address,
+        contribution_id: u64,
+        reason: String,
+    ) -> Result<(), CommunityError> {
+        moderator.require_auth();
+        KnowledgeManager::flag_contribution(&env, &moderator, contribution_id, reason)
+    }
+
+    /// Clear a contribution's plagiarism flag, restoring its reward claim.
+    ///
+    /// Caller must hold a moderator role.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment.
+    /// * `moderator` - Address of the moderator clearing the flag.
+    /// * `contribution_id` - ID of the flagged contribution.
+    ///
+    /// # Errors
+    /// Returns [`CommunityError::NotModerator`] if the caller lacks moderator privileges.
+    /// Returns [`CommunityError::ContributionNotFlagged`] if the contribution is not flagged.
+    ///
+    /// # Example
+    /// ```ignore
+    /// client.clear_flag(&moderator, &contribution_id);
+    /// ```
+    pub fn clear_flag(
+        env: Env,
+        moderator: Address,
+        contribution_id: u64,
+    ) -> Result<(), CommunityError> {
+        moderator.require_auth();
+        KnowledgeManager::clear_flag(&env, &moderator, contribution_id)
+    }
+
+    /// Return the plagiarism flag on a contribution, or `None` if it is not flagged.
+    ///
+    /// # Example
+    /// ```ignore
+    /// client.get_contribution_flag(&contribution_id);
+    /// ```
+    pub fn get_contribution_flag(env: Env, contribution_id: u64) -> Option<ContributionFlag> {
+        KnowledgeManager::get_contribution_flag(&env, contribution_id)
+    }
+
     /// Cast an upvote or downvote on a published knowledge contribution.
     ///
     /// # Arguments
@@ -683,6 +932,96 @@ impl Community {
         )
     }
 
+    /// Create a recurring community event, such as weekly office hours.
+    ///
+    /// All occurrences are created immediately, each starting
+    /// `recurrence.interval_seconds` after the previous one. Every occurrence is
+    /// a separate event, so registration and attendance are tracked per
+    /// occurrence. The first occurrence's ID identifies the series.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment.
+    /// * `organizer` - Address of the user organizing the event.
+    /// * `event_type` - Classification of the event (workshop, AMA, etc.).
+    /// * `title` - Display title shared by every occurrence.
+    /// * `description` - Full description visible to potential attendees.
+    /// * `start_time` - Unix timestamp when the first occurrence begins.
+    /// * `end_time` - Unix timestamp when the first occurrence ends.
+    /// * `max_participants` - Maximum number of registrants per occurrence.
+    /// * `is_public` - Whether the event is open to all users.
+    /// * `xp_reward` - XP awarded to each confirmed attendee of an occurrence.
+    /// * `recurrence` - Interval between occurrences and how many to create.
+    ///
+    /// # Errors
+    /// Returns [`CommunityError::InvalidInput`] if the event fields are invalid, `recurrence.count`
+    /// is 0 or above 52, or the interval is shorter than one occurrence.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let occurrence_ids = client.create_recurring_event(&organizer, &event_type, &title, &description, &start_time, &end_time, &max_participants, &is_public, &xp_reward, &recurrence);
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_recurring_event(
+        env: Env,
+        organizer: Address,
+        event_type: EventType,
+        title: String,
+        description: String,
+        start_time: u64,
+        end_time: u64,
+        max_participants: u32,
+        is_public: bool,
+        xp_reward: u32,
+        recurrence: EventRecurrence,
+    ) -> Result<Vec<u64>, CommunityError> {
+        organizer.require_auth();
+        EventManager::create_recurring_event(
+            &env,
+            &organizer,
+            event_type,
+            title,
+            description,
+            start_time,
+            end_time,
+            max_participants,
+            is_public,
+            xp_reward,
+            recurrence,
+        )
+    }
+
+    /// Return the next occurrence of a recurring event that has not started yet,
+    /// or `None` once every occurrence has begun.
+    ///
+    /// # Example
+    /// ```ignore
+    /// client.get_next_occurrence(&series_id);
+    /// ```
+    pub fn get_next_occurrence(env: Env, series_id: u64) -> Option<CommunityEvent> {
+        EventManager::get_next_occurrence(&env, series_id)
+    }
+
+    /// Return the event IDs of every occurrence in a recurring series, in order.
+    ///
+    /// # Example
+    /// ```ignore
+    /// client.get_event_series(&series_id);
+    /// ```
+    pub fn get_event_series(env: Env, series_id: u64) -> Vec<u64> {
+        EventManager::get_event_series(&env, series_id)
+    }
+
+    /// Return the ID of the recurring series `event_id` belongs to, or `None`
+    /// for a one-off event.
+    ///
+    /// # Example
+    /// ```ignore
+    /// client.get_event_series_of(&event_id);
+    /// ```
+    pub fn get_event_series_of(env: Env, event_id: u64) -> Option<u64> {
+        EventManager::get_event_series_of(&env, event_id)
+    }
+
     /// Register `user` to attend a community event.
     ///
     /// # Arguments
@@ -710,7 +1049,8 @@ impl Community {
 
     /// Confirm that `user` attended an event, making them eligible for the XP reward.
     ///
-    /// Only the event organizer may mark attendance.
+    /// Only the event organizer may mark attendance. Marking the same user again
+    /// is a no-op, so attendance stats and XP are only awarded once per event.
     ///
     /// # Arguments
     /// * `env` - The Soroban environment.
@@ -834,6 +1174,10 @@ impl Community {
 
     /// File a moderation report against a piece of content.
     ///
+    /// Once the number of distinct members with unresolved reports against the content
+    /// reaches the auto-hide threshold, it is hidden from retrieval until a moderator
+    /// resolves their reports. Repeat reports from one member count once.
+    ///
     /// # Arguments
     /// * `env` - The Soroban environment.
     /// * `reporter` - Address of the user submitting the report.
@@ -920,6 +1264,102 @@ impl Community {
         ModerationManager::get_pending_reports(&env)
     }
 
+    /// Check whether a content item is currently auto-hidden pending moderator review.
+    ///
+    /// Content is hidden once the number of distinct members with unresolved reports
+    /// against it reaches the auto-hide threshold, and restored when all its reports are
+    /// resolved.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment.
+    /// * `content_type` - String identifier for the content type (e.g. `"post"`, `"reply"`).
+    /// * `content_id` - ID of the content item.
+    ///
+    /// # Example
+    /// ```ignore
+    /// client.is_content_hidden(&content_type, &content_id);
+    /// ```
+    pub fn is_content_hidden(env: Env, content_type: String, content_id: u64) -> bool {
+        ModerationManager::is_content_hidden(&env, &content_type, content_id)
+    }
+
+    /// Admin: set how many distinct members must have open reports against content
+    /// before it is auto-hidden.
+    ///
+    /// A value of `0` disables auto-hiding.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment.
+    /// * `admin` - Admin address (must match stored admin).
+    /// * `threshold` - Number of distinct reporters that triggers auto-hide.
+    ///
+    /// # Errors
+    /// Returns [`CommunityError::Unauthorized`] if the caller is not the contract admin.
+    ///
+    /// # Example
+    /// ```ignore
+    /// client.set_auto_hide_report_threshold(&admin, &3);
+    /// ```
+    pub fn set_auto_hide_report_threshold(
+        env: Env,
+        admin: Address,
+        threshold: u32,
+    ) -> Result<(), CommunityError> {
+        admin.require_auth();
+        CommunityStorage::require_admin(&env, &admin)?;
+        ModerationManager::set_auto_hide_report_threshold(&env, threshold);
+        Ok(())
+    }
+
+    /// Return the auto-hide report threshold (`5` unless configured, `0` if disabled).
+    pub fn get_auto_hide_report_threshold(env: Env) -> u32 {
+        ModerationManager::get_auto_hide_report_threshold(&env)
+    }
+
+    /// Dock reputation from `user` as a moderation penalty.
+    ///
+    /// The penalty is subtracted from the user's earned reputation and, if a
+    /// recovery policy is set, recovers gradually as the user stays active.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment.
+    /// * `moderator` - Address of the moderator applying the penalty.
+    /// * `user` - Address of the member being penalized.
+    /// * `amount` - Reputation points to dock.
+    ///
+    /// # Errors
+    /// Returns [`CommunityError::NotModerator`] if the caller lacks moderator privileges.
+    /// Returns [`CommunityError::InvalidInput`] if `amount` is zero.
+    ///
+    /// # Example
+    /// ```ignore
+    /// client.dock_reputation(&moderator, &user, &100);
+    /// ```
+    pub fn dock_reputation(
+        env: Env,
+        moderator: Address,
+        user: Address,
+        amount: u32,
+    ) -> Result<(), CommunityError> {
+        moderator.require_auth();
+        CommunityStorage::require_moderator(&env, &moderator)?;
+        AnalyticsManager::dock_reputation(&env, &user, amount)
+    }
+
+    /// Return the outstanding reputation penalty for `user`, if any.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment.
+    /// * `user` - Address of the user to query.
+    ///
+    /// # Example
+    /// ```ignore
+    /// client.get_reputation_penalty(&user);
+    /// ```
+    pub fn get_reputation_penalty(env: Env, user: Address) -> Option<ReputationPenalty> {
+        AnalyticsManager::get_reputation_penalty(&env, &user)
+    }
+
     // ══════════════════════════════════════════════════════════════════════
     //  Governance Functions
     // ══════════════════════════════════════════════════════════════════════
@@ -1004,6 +1444,8 @@ impl Community {
     /// Finalize a proposal after its voting window closes and compute the outcome.
     ///
     /// This function is permissionless and can be called by anyone once the window has passed.
+    /// When a minimum quorum is set, the proposal is rejected unless the number of distinct
+    /// voters reaches that percentage of the eligible voters snapshotted at creation.
     ///
     /// # Arguments
     /// * `env` - The Soroban environment.
@@ -1035,6 +1477,21 @@ impl Community {
         GovernanceManager::get_proposal(&env, proposal_id)
     }
 
+    /// Return the eligible-voter snapshot and turnout of a proposal, or `None`
+    /// for proposals created before turnout was tracked.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment.
+    /// * `proposal_id` - ID of the proposal to fetch.
+    ///
+    /// # Example
+    /// ```ignore
+    /// client.get_proposal_turnout(&proposal_id);
+    /// ```
+    pub fn get_proposal_turnout(env: Env, proposal_id: u64) -> Option<ProposalTurnout> {
+        GovernanceManager::get_proposal_turnout(&env, proposal_id)
+    }
+
     /// Return all proposals whose voting window is still open.
     ///
     /// # Arguments
@@ -1048,6 +1505,39 @@ impl Community {
         GovernanceManager::get_active_proposals(&env)
     }
 
+    /// Admin: set the minimum turnout a proposal needs to pass.
+    ///
+    /// Turnout is the share of members in the eligible-voter snapshot taken at
+    /// proposal creation who voted. A value of `0` disables the check.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment.
+    /// * `admin` - Admin address (must match stored admin).
+    /// * `percent` - Minimum turnout percentage (0–100).
+    ///
+    /// # Errors
+    /// Returns [`CommunityError::Unauthorized`] if the caller is not the contract admin.
+    /// Returns [`CommunityError::InvalidInput`] if `percent` is above 100.
+    ///
+    /// # Example
+    /// ```ignore
+    /// client.set_min_quorum_percent(&admin, &25);
+    /// ```
+    pub fn set_min_quorum_percent(
+        env: Env,
+        admin: Address,
+        percent: u32,
+    ) -> Result<(), CommunityError> {
+        admin.require_auth();
+        CommunityStorage::require_admin(&env, &admin)?;
+        GovernanceManager::set_min_quorum_percent(&env, percent)
+    }
+
+    /// Return the minimum proposal turnout percentage (`0` if disabled).
+    pub fn get_min_quorum_percent(env: Env) -> u32 {
+        GovernanceManager::get_min_quorum_percent(&env)
+    }
+
     // ══════════════════════════════════════════════════════════════════════
     //  Analytics Functions
     // ══════════════════════════════════════════════════════════════════════
@@ -1093,6 +1583,35 @@ impl Community {
         AnalyticsManager::calculate_reputation(&env, &user)
     }
 
+    /// Admin: re-check a batch of members' stored reputation against the
+    /// current voting threshold and update the eligible-voter count.
+    ///
+    /// Use after changing `vote_weight_threshold`, or to backfill members whose
+    /// reputation was calculated before eligibility was tracked.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment.
+    /// * `admin` - Admin address (must match stored admin).
+    /// * `users` - Members to re-check (at most 50).
+    ///
+    /// # Errors
+    /// Returns [`CommunityError::Unauthorized`] if the caller is not the contract admin.
+    /// Returns [`CommunityError::InvalidInput`] if more than 50 members are given.
+    ///
+    /// # Example
+    /// ```ignore
+    /// client.sync_voting_eligibility(&admin, &users);
+    /// ```
+    pub fn sync_voting_eligibility(
+        env: Env,
+        admin: Address,
+        users: Vec<Address>,
+    ) -> Result<(), CommunityError> {
+        admin.require_auth();
+        CommunityStorage::require_admin(&env, &admin)?;
+        AnalyticsManager::sync_voting_eligibility(&env, &users)
+    }
+
     // ══════════════════════════════════════════════════════════════════════
     //  Admin Functions
     // ══════════════════════════════════════════════════════════════════════
@@ -1135,6 +1654,77 @@ impl Community {
         CommunityStorage::get_config(&env)
     }
 
+    /// Admin: set or clear the policy for recovering docked reputation.
+    ///
+    /// Recovery is applied lazily in `calculate_reputation`: each elapsed
+    /// `recovery_period` restores up to `recovery_percent` of the docked
+    /// amount, capped by the reputation the user has earned since. Pass `None`
+    /// to stop recovery.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment.
+    /// * `admin` - Admin address (must match stored admin).
+    /// * `policy` - The recovery policy, or `None` to disable recovery.
+    ///
+    /// # Errors
+    /// Returns [`CommunityError::Unauthorized`] if the caller is not the contract admin.
+    /// Returns [`CommunityError::InvalidInput`] if the period is zero or the percentage is outside 1–100.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let policy = ReputationRecoveryPolicy { recovery_period: 604_800, recovery_percent: 10 };
+    /// client.set_reputation_recovery_policy(&admin, &Some(policy));
+    /// ```
+    pub fn set_reputation_recovery_policy(
+        env: Env,
+        admin: Address,
+        policy: Option<ReputationRecoveryPolicy>,
+    ) -> Result<(), CommunityError> {
+        admin.require_auth();
+        CommunityStorage::require_admin(&env, &admin)?;
+        AnalyticsManager::set_recovery_policy(&env, policy)
+    }
+
+    /// Return the reputation recovery policy, if one is set.
+    pub fn get_reputation_recovery_policy(env: Env) -> Option<ReputationRecoveryPolicy> {
+        AnalyticsManager::get_recovery_policy(&env)
+    }
+
+    /// Admin: set the points awarded per activity when computing reputation.
+    ///
+    /// Takes effect the next time a user's reputation is calculated; stored
+    /// scores are not recomputed eagerly.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment.
+    /// * `admin` - Admin address (must match stored admin).
+    /// * `weights` - Points per post, reply, solution, contribution, event, mentorship session and helpful vote.
+    ///
+    /// # Errors
+    /// Returns [`CommunityError::Unauthorized`] if the caller is not the contract admin.
+    /// Returns [`CommunityError::InvalidInput`] if every weight is zero.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut weights = client.get_reputation_weights();
+    /// weights.mentorship = 200;
+    /// client.set_reputation_weights(&admin, &weights);
+    /// ```
+    pub fn set_reputation_weights(
+        env: Env,
+        admin: Address,
+        weights: ReputationWeights,
+    ) -> Result<(), CommunityError> {
+        admin.require_auth();
+        CommunityStorage::require_admin(&env, &admin)?;
+        AnalyticsManager::set_reputation_weights(&env, weights)
+    }
+
+    /// Return the reputation weights in effect (the defaults if none are configured).
+    pub fn get_reputation_weights(env: Env) -> ReputationWeights {
+        AnalyticsManager::get_reputation_weights(&env)
+    }
+
     pub fn export_user_data(env: Env, user: Address) -> CommunityExport {
         let stats = AnalyticsManager::get_user_stats(&env, &user);
         CommunityExport {
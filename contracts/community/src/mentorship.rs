@@ -6,6 +6,11 @@ use crate::storage::CommunityStorage;
 use crate::types::*;
 use shared::validation::{CoreValidator, ValidationConfig};
 
+/// Upper bound on mentors returned by one recommendation call.
+const MAX_MENTOR_RECOMMENDATIONS: u32 = 20;
+/// Maximum number of mentors that can be added to the index in one call.
+const MAX_MENTOR_INDEX_BATCH: u32 = 50;
+
 pub struct MentorshipManager;
 
 impl MentorshipManager {
@@ -53,6 +58,14 @@ impl MentorshipManager {
 
         env.storage().persistent().set(&CommunityKey::MentorProfile(mentor.clone()), &profile);
 
+        let mut mentors: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&CommunityExtKey::Mentors)
+            .unwrap_or_else(|| Vec::new(env));
+        mentors.push_back(mentor.clone());
+        env.storage().persistent().set(&CommunityExtKey::Mentors, &mentors);
+
         CommunityEvents::emit_mentor_registered(env, mentor);
         Ok(())
     }
@@ -245,12 +258,95 @@ impl MentorshipManager {
     }
 
     pub fn get_available_mentors(env: &Env) -> Vec<MentorProfile> {
-        // In production, this would use an index
-        // For now, returning empty vec as placeholder
-        Vec::new(env)
+        let mentors: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&CommunityExtKey::Mentors)
+            .unwrap_or_else(|| Vec::new(env));
+
+        let mut available = Vec::new(env);
+        for mentor in mentors.iter() {
+            if let Some(profile) = Self::get_mentor_profile(env, &mentor) {
+                if profile.is_available && profile.current_mentees < profile.max_mentees {
+                    available.push_back(profile);
+                }
+            }
+        }
+        available
+    }
+
+    /// Rank available mentors for `mentee` by fit with `topic`.
+    ///
+    /// Mentors covering the topic come first, then higher ratings, then more
+    /// spare capacity. Mentors at capacity or not accepting mentees are left
+    /// out, as is the mentee's own profile.
+    pub fn recommend_mentors(
+        env: &Env,
+        mentee: &Address,
+        topic: &String,
+        limit: u32,
+    ) -> Vec<MentorProfile> {
+        // Keep only the best `limit` so each insertion costs at most `limit` comparisons
+        let limit = limit.min(MAX_MENTOR_RECOMMENDATIONS);
+        let mut ranked: Vec<MentorProfile> = Vec::new(env);
+        if limit == 0 {
+            return ranked;
+        }
+        for profile in Self::get_available_mentors(env).iter() {
+            if profile.mentor == *mentee {
+                continue;
+            }
+            let fit = Self::mentor_fit(&profile, topic);
+            let mut index = ranked.len();
+            while index > 0 && Self::mentor_fit(&ranked.get(index - 1).unwrap(), topic) < fit {
+                index -= 1;
+            }
+            if index >= limit {
+                continue;
+            }
+            ranked.insert(index, profile);
+            if ranked.len() > limit {
+                ranked.pop_back();
+            }
+        }
+        ranked
+    }
+
+    /// Add registered mentors missing from the mentor index, e.g. mentors who
+    /// registered before the index existed. Returns how many were added.
+    pub fn index_mentors(env: &Env, mentors: &Vec<Address>) -> Result<u32, Error> {
+        if mentors.len() > MAX_MENTOR_INDEX_BATCH {
+            return Err(Error::InvalidInput);
+        }
+        let mut index: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&CommunityExtKey::Mentors)
+            .unwrap_or_else(|| Vec::new(env));
+        let mut added = 0;
+        for mentor in mentors.iter() {
+            if index.contains(&mentor)
+                || !env.storage().persistent().has(&CommunityKey::MentorProfile(mentor.clone()))
+            {
+                continue;
+            }
+            index.push_back(mentor);
+            added += 1;
+        }
+        env.storage().persistent().set(&CommunityExtKey::Mentors, &index);
+        Ok(added)
     }
 
     // Helper functions
+    /// Recommendation sort key: (covers topic, rating, spare capacity)
+    fn mentor_fit(profile: &MentorProfile, topic: &String) -> (bool, u32, u32) {
+        (
+            profile.expertise_areas.contains(topic),
+            profile.rating,
+            profile.max_mentees.saturating_sub(profile.current_mentees),
+        )
+    }
+
     fn update_mentor_rating(env: &Env, mentor: &Address) {
         // Calculate average rating from all sessions
         // Simplified implementation
@@ -6,6 +6,11 @@ use crate::storage::CommunityStorage;
 use crate::types::*;
 use shared::validation::{CoreValidator, ValidationConfig};
 
+/// Content type identifiers used when reporting and hiding content.
+pub const CONTENT_TYPE_POST: &str = "post";
+pub const CONTENT_TYPE_REPLY: &str = "reply";
+pub const CONTENT_TYPE_CONTRIBUTION: &str = "contribution";
+
 pub struct ModerationManager;
 
 impl ModerationManager {
@@ -42,7 +47,6 @@ impl ModerationManager {
         // Check daily report limit
         let now = env.ledger().timestamp();
         let _day_bucket = now / 86_400;
-        let _config = CommunityStorage::get_config(env);
 
         // Rate limiting would be implemented here
 
@@ -51,14 +55,14 @@ impl ModerationManager {
         let report = ContentReport {
             id: report_id,
             reporter: reporter.clone(),
-            content_type,
+            content_type: content_type.clone(),
             content_id,
             reason,
             description,
             status: ReportStatus::Pending,
             created_at: now,
             resolved_at: 0,
-            resolved_by: Address::from_string(&String::from_str(env, "")),
+            resolved_by: env.current_contract_address(),
         };
 
         env.storage().persistent().set(&CommunityKey::Report(report_id), &report);
@@ -72,6 +76,26 @@ impl ModerationManager {
         pending.push_back(report_id);
         env.storage().persistent().set(&CommunityKey::PendingReports, &pending);
 
+        // Auto-hide content once enough distinct members have unresolved reports against it
+        let reporter_key =
+            CommunityExtKey::ContentReporter(content_type.clone(), content_id, reporter.clone());
+        let reporter_open: u32 = env.storage().persistent().get(&reporter_key).unwrap_or(0);
+        env.storage().persistent().set(&reporter_key, &(reporter_open + 1));
+
+        let count_key = CommunityExtKey::ContentReportCount(content_type.clone(), content_id);
+        let mut open_reporters: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+        if reporter_open == 0 {
+            open_reporters += 1;
+            env.storage().persistent().set(&count_key, &open_reporters);
+        }
+
+        let threshold = Self::get_auto_hide_report_threshold(env);
+        if threshold > 0 && open_reporters >= threshold {
+            env.storage()
+                .persistent()
+                .set(&CommunityExtKey::HiddenContent(content_type, content_id), &true);
+        }
+
         CommunityEvents::emit_content_reported(env, reporter, report_id);
         Ok(report_id)
     }
@@ -116,6 +140,32 @@ impl ModerationManager {
         }
         env.storage().persistent().set(&CommunityKey::PendingReports, &new_pending);
 
+        // A reporter stops counting once all of their reports on the content are resolved
+        let reporter_key = CommunityExtKey::ContentReporter(
+            report.content_type.clone(),
+            report.content_id,
+            report.reporter.clone(),
+        );
+        let reporter_open: u32 = env.storage().persistent().get(&reporter_key).unwrap_or(0);
+        if reporter_open > 1 {
+            env.storage().persistent().set(&reporter_key, &(reporter_open - 1));
+            return Ok(());
+        }
+        env.storage().persistent().remove(&reporter_key);
+
+        // Restore hidden content once all of its reports have been resolved
+        let count_key =
+            CommunityExtKey::ContentReportCount(report.content_type.clone(), report.content_id);
+        let open_reporters: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+        let remaining = open_reporters.saturating_sub(1);
+        env.storage().persistent().set(&count_key, &remaining);
+
+        if remaining == 0 {
+            env.storage()
+                .persistent()
+                .remove(&CommunityExtKey::HiddenContent(report.content_type, report.content_id));
+        }
+
         Ok(())
     }
 
@@ -156,6 +206,25 @@ impl ModerationManager {
         Ok(action_id)
     }
 
+    pub fn set_auto_hide_report_threshold(env: &Env, threshold: u32) {
+        env.storage().persistent().set(&CommunityExtKey::AutoHideReportThreshold, &threshold);
+    }
+
+    /// Configured auto-hide threshold, or 5 if unset.
+    pub fn get_auto_hide_report_threshold(env: &Env) -> u32 {
+        env.storage().persistent().get(&CommunityExtKey::AutoHideReportThreshold).unwrap_or(5)
+    }
+
+    pub fn is_content_hidden(env: &Env, content_type: &String, content_id: u64) -> bool {
+        env.storage()
+            .persistent()
+            .has(&CommunityExtKey::HiddenContent(content_type.clone(), content_id))
+    }
+
+    pub fn is_hidden(env: &Env, content_type: &str, content_id: u64) -> bool {
+        Self::is_content_hidden(env, &String::from_str(env, content_type), content_id)
+    }
+
     pub fn get_pending_reports(env: &Env) -> Vec<ContentReport> {
         let report_ids: Vec<u64> = env
             .storage()
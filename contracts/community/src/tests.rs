@@ -1,7 +1,7 @@
 use soroban_sdk::{testutils::Address as _, testutils::Ledger, Address, Env, String, Vec};
 
 use crate::types::*;
-use crate::{Community, CommunityClient};
+use crate::{Community, CommunityClient, CommunityError};
 
 fn create_test_env() -> (Env, Address, Address, Address, Address) {
     let env = Env::default();
@@ -139,6 +139,68 @@ fn test_vote_post() {
     assert_eq!(post.upvotes, 1);
 }
 
+fn create_vote_target(env: &Env, client: &CommunityClient, author: &Address) -> u64 {
+    client.create_post(
+        author,
+        &ForumCategory::General,
+        &String::from_str(env, "Vote Target"),
+        &String::from_str(env, "Content for weighted voting"),
+        &Vec::new(env),
+        &String::from_str(env, ""),
+    )
+}
+
+#[test]
+fn test_high_reputation_vote_moves_score_more() {
+    let (env, admin, author, respected, newcomer) = create_test_env();
+    let client = setup_community(&env, &admin);
+
+    // One post is worth 300 reputation, i.e. a vote weight of 3
+    let mut weights = client.get_reputation_weights();
+    weights.post = 300;
+    client.set_reputation_weights(&admin, &weights);
+    build_voter(&env, &client, &respected);
+
+    let post_a = create_vote_target(&env, &client, &author);
+    let post_b = create_vote_target(&env, &client, &author);
+    client.vote_post(&respected, &post_a, &true);
+    client.vote_post(&newcomer, &post_b, &true);
+
+    assert_eq!(client.get_post(&post_a).unwrap().upvotes, 1);
+    assert_eq!(client.get_post_score(&post_a), 3);
+    assert_eq!(client.get_post(&post_b).unwrap().upvotes, 1);
+    assert_eq!(client.get_post_score(&post_b), 1);
+
+    client.vote_post(&newcomer, &post_a, &false);
+    assert_eq!(client.get_post_score(&post_a), 2);
+    assert_eq!(
+        client.try_vote_post(&respected, &post_a, &true),
+        Err(Ok(CommunityError::AlreadyVoted))
+    );
+}
+
+#[test]
+fn test_vote_weight_capped() {
+    let (env, admin, author, respected, _) = create_test_env();
+    let client = setup_community(&env, &admin);
+
+    let mut weights = client.get_reputation_weights();
+    weights.post = 5_000;
+    client.set_reputation_weights(&admin, &weights);
+    build_voter(&env, &client, &respected);
+
+    let post_id = create_vote_target(&env, &client, &author);
+    client.vote_post(&respected, &post_id, &true);
+    assert_eq!(client.get_post_score(&post_id), 5);
+
+    let weighting = ForumVoteWeighting { reputation_step: 100, max_weight: 20 };
+    client.set_forum_vote_weighting(&admin, &weighting);
+    assert_eq!(client.get_forum_vote_weighting(), weighting);
+    let post_id = create_vote_target(&env, &client, &author);
+    client.vote_post(&respected, &post_id, &true);
+    assert_eq!(client.get_post_score(&post_id), 20);
+}
+
 // ══════════════════════════════════════════════════════════════════════
 //  Mentorship Tests
 // ══════════════════════════════════════════════════════════════════════
@@ -254,6 +316,75 @@ fn test_review_contribution() {
     assert!(contrib.xp_reward > 0);
 }
 
+fn submit_approved_contribution(
+    env: &Env,
+    client: &CommunityClient,
+    admin: &Address,
+    contributor: &Address,
+) -> u64 {
+    let contrib_id = client.submit_contribution(
+        contributor,
+        &ContributionType::Article,
+        &String::from_str(env, "Article Title"),
+        &String::from_str(env, "This is the article content for review"),
+        &ForumCategory::General,
+        &Vec::new(env),
+    );
+    client.review_contribution(admin, &contrib_id, &true);
+    contrib_id
+}
+
+#[test]
+fn test_flagged_contribution_reward_blocked_until_cleared() {
+    let (env, admin, user1, _, _) = create_test_env();
+    let client = setup_community(&env, &admin);
+    let contrib_id = submit_approved_contribution(&env, &client, &admin, &user1);
+
+    client.flag_contribution(&admin, &contrib_id, &String::from_str(&env, "Copied from blog"));
+    assert_eq!(client.get_contribution_flag(&contrib_id).unwrap().moderator, admin);
+    let result = client.try_claim_contribution_reward(&user1, &contrib_id);
+    assert_eq!(result, Err(Ok(CommunityError::ContributionFlagged)));
+
+    client.clear_flag(&admin, &contrib_id);
+    assert!(client.get_contribution_flag(&contrib_id).is_none());
+    let (xp, tokens) = client.claim_contribution_reward(&user1, &contrib_id);
+    let contrib = client.get_contribution(&contrib_id).unwrap();
+    assert_eq!((xp, tokens), (contrib.xp_reward, contrib.token_reward));
+
+    let result = client.try_claim_contribution_reward(&user1, &contrib_id);
+    assert_eq!(result, Err(Ok(CommunityError::RewardAlreadyClaimed)));
+}
+
+#[test]
+fn test_unflagged_contribution_reward_claimable() {
+    let (env, admin, user1, user2, _) = create_test_env();
+    let client = setup_community(&env, &admin);
+    let flagged_id = submit_approved_contribution(&env, &client, &admin, &user1);
+    let clean_id = submit_approved_contribution(&env, &client, &admin, &user2);
+
+    client.flag_contribution(&admin, &flagged_id, &String::from_str(&env, "Copied from blog"));
+    let result = client.try_clear_flag(&admin, &clean_id);
+    assert_eq!(result, Err(Ok(CommunityError::ContributionNotFlagged)));
+
+    let (xp, _) = client.claim_contribution_reward(&user2, &clean_id);
+    assert!(xp > 0);
+}
+
+#[test]
+fn test_contribution_approved_before_claim_flow_counts_as_claimed() {
+    let (env, admin, user1, _, _) = create_test_env();
+    let client = setup_community(&env, &admin);
+    let contrib_id = submit_approved_contribution(&env, &client, &admin, &user1);
+
+    // Approvals from before the upgrade were paid immediately and have no pending marker
+    env.as_contract(&client.address, || {
+        env.storage().persistent().remove(&CommunityExtKey::ContributionRewardPending(contrib_id));
+    });
+
+    let result = client.try_claim_contribution_reward(&user1, &contrib_id);
+    assert_eq!(result, Err(Ok(CommunityError::RewardAlreadyClaimed)));
+}
+
 // ══════════════════════════════════════════════════════════════════════
 //  Event Tests
 // ══════════════════════════════════════════════════════════════════════
@@ -280,6 +411,7 @@ fn test_create_event() {
     let event = client.get_event(&event_id).unwrap();
     assert_eq!(event.organizer, user1);
     assert_eq!(event.max_participants, 50);
+    assert_eq!(client.get_event_series_of(&event_id), None);
 }
 
 #[test]
@@ -305,6 +437,207 @@ fn test_event_registration() {
     assert_eq!(event.current_participants, 1);
 }
 
+#[test]
+fn test_mark_attendance_awards_once() {
+    let (env, admin, user1, user2, user3) = create_test_env();
+    let client = setup_community(&env, &admin);
+
+    let event_id = client.create_event(
+        &user1,
+        &EventType::Workshop,
+        &String::from_str(&env, "Soroban Workshop"),
+        &String::from_str(&env, "Hands-on smart contract workshop"),
+        &1000,
+        &2000,
+        &10,
+        &true,
+        &20,
+    );
+    client.register_for_event(&user2, &event_id);
+    client.register_for_event(&user3, &event_id);
+
+    client.mark_attendance(&user1, &event_id, &user2);
+    assert_eq!(client.get_user_stats(&user2).events_attended, 1);
+
+    // Marking the same user again must not award a second time
+    client.mark_attendance(&user1, &event_id, &user2);
+    assert_eq!(client.get_user_stats(&user2).events_attended, 1);
+
+    // A different attendee still gets their own award
+    client.mark_attendance(&user1, &event_id, &user3);
+    assert_eq!(client.get_user_stats(&user3).events_attended, 1);
+}
+
+#[test]
+fn test_recurring_event_occurrences_track_attendance_independently() {
+    let (env, admin, user1, user2, _) = create_test_env();
+    let client = setup_community(&env, &admin);
+    let week = 7 * 86_400;
+
+    let occurrences = client.create_recurring_event(
+        &user1,
+        &EventType::StudyGroup,
+        &String::from_str(&env, "Office Hours"),
+        &String::from_str(&env, "Weekly open office hours"),
+        &1000,
+        &4600,
+        &10,
+        &true,
+        &20,
+        &EventRecurrence { interval_seconds: week, count: 3 },
+    );
+    assert_eq!(occurrences.len(), 3);
+    assert_eq!(client.get_event_series(&occurrences.get(0).unwrap()), occurrences);
+    for (i, id) in occurrences.iter().enumerate() {
+        let event = client.get_event(&id).unwrap();
+        assert_eq!(event.start_time, 1000 + week * i as u64);
+        assert_eq!(event.end_time, 4600 + week * i as u64);
+        assert_eq!(client.get_event_series_of(&id), Some(occurrences.get(0).unwrap()));
+    }
+
+    let first = occurrences.get(0).unwrap();
+    let second = occurrences.get(1).unwrap();
+    client.register_for_event(&user2, &first);
+    client.mark_attendance(&user1, &first, &user2);
+    assert_eq!(client.get_event(&second).unwrap().current_participants, 0);
+    client.register_for_event(&user2, &second);
+    client.mark_attendance(&user1, &second, &user2);
+    assert_eq!(client.get_user_stats(&user2).events_attended, 2);
+
+    env.ledger().with_mut(|li| li.timestamp = 1001);
+    assert_eq!(client.get_next_occurrence(&first).unwrap().id, second);
+}
+
+#[test]
+fn test_recurring_event_rejects_overlapping_interval() {
+    let (env, admin, user1, _, _) = create_test_env();
+    let client = setup_community(&env, &admin);
+
+    let result = client.try_create_recurring_event(
+        &user1,
+        &EventType::StudyGroup,
+        &String::from_str(&env, "Office Hours"),
+        &String::from_str(&env, "Weekly open office hours"),
+        &1000,
+        &4600,
+        &10,
+        &true,
+        &20,
+        &EventRecurrence { interval_seconds: 1800, count: 3 },
+    );
+    assert_eq!(result, Err(Ok(CommunityError::InvalidInput)));
+}
+
+// ══════════════════════════════════════════════════════════════════════
+//  Moderation Tests
+// ══════════════════════════════════════════════════════════════════════
+
+fn report_post(env: &Env, client: &CommunityClient, reporter: &Address, post_id: u64) -> u64 {
+    client.report_content(
+        reporter,
+        &String::from_str(env, "post"),
+        &post_id,
+        &ReportReason::Spam,
+        &String::from_str(env, "Repeated promotional content"),
+    )
+}
+
+#[test]
+fn test_content_auto_hidden_at_report_threshold() {
+    let (env, admin, user1, user2, user3) = create_test_env();
+    let client = setup_community(&env, &admin);
+
+    client.set_auto_hide_report_threshold(&admin, &3);
+
+    let post_id = client.create_post(
+        &user1,
+        &ForumCategory::General,
+        &String::from_str(&env, "Buy now"),
+        &String::from_str(&env, "Limited time offer"),
+        &Vec::new(&env),
+        &String::from_str(&env, ""),
+    );
+
+    let user4 = Address::generate(&env);
+    let first = report_post(&env, &client, &user2, post_id);
+    let second = report_post(&env, &client, &user3, post_id);
+
+    // Below the threshold the post is still visible
+    assert!(client.get_post(&post_id).is_some());
+    assert!(!client.is_content_hidden(&String::from_str(&env, "post"), &post_id));
+
+    let third = report_post(&env, &client, &user4, post_id);
+
+    assert!(client.is_content_hidden(&String::from_str(&env, "post"), &post_id));
+    assert!(client.get_post(&post_id).is_none());
+    assert_eq!(client.get_category_posts(&ForumCategory::General, &10).len(), 0);
+
+    // Content stays hidden until every report is resolved
+    client.resolve_report(&admin, &first, &String::from_str(&env, "dismissed"));
+    client.resolve_report(&admin, &second, &String::from_str(&env, "dismissed"));
+    assert!(client.get_post(&post_id).is_none());
+
+    client.resolve_report(&admin, &third, &String::from_str(&env, "dismissed"));
+    assert!(!client.is_content_hidden(&String::from_str(&env, "post"), &post_id));
+    assert!(client.get_post(&post_id).is_some());
+    assert_eq!(client.get_category_posts(&ForumCategory::General, &10).len(), 1);
+}
+
+#[test]
+fn test_repeated_reports_from_one_member_do_not_hide_content() {
+    let (env, admin, user1, user2, user3) = create_test_env();
+    let client = setup_community(&env, &admin);
+
+    client.set_auto_hide_report_threshold(&admin, &2);
+
+    let post_id = client.create_post(
+        &user1,
+        &ForumCategory::General,
+        &String::from_str(&env, "Opinion"),
+        &String::from_str(&env, "An unpopular opinion"),
+        &Vec::new(&env),
+        &String::from_str(&env, ""),
+    );
+
+    let first = report_post(&env, &client, &user2, post_id);
+    for _ in 0..4 {
+        report_post(&env, &client, &user2, post_id);
+    }
+
+    // Five reports from one member count once
+    assert_eq!(client.get_pending_reports().len(), 5);
+    assert!(!client.is_content_hidden(&String::from_str(&env, "post"), &post_id));
+    assert!(client.get_post(&post_id).is_some());
+
+    // Resolving one of them keeps that member counted while the others are open
+    client.resolve_report(&admin, &first, &String::from_str(&env, "dismissed"));
+    report_post(&env, &client, &user3, post_id);
+    assert!(client.is_content_hidden(&String::from_str(&env, "post"), &post_id));
+}
+
+#[test]
+fn test_auto_hide_disabled_with_zero_threshold() {
+    let (env, admin, user1, user2, user3) = create_test_env();
+    let client = setup_community(&env, &admin);
+
+    client.set_auto_hide_report_threshold(&admin, &0);
+
+    let post_id = client.create_post(
+        &user1,
+        &ForumCategory::General,
+        &String::from_str(&env, "Opinion"),
+        &String::from_str(&env, "An unpopular opinion"),
+        &Vec::new(&env),
+        &String::from_str(&env, ""),
+    );
+
+    report_post(&env, &client, &user2, post_id);
+    report_post(&env, &client, &user3, post_id);
+
+    assert!(client.get_post(&post_id).is_some());
+    assert_eq!(client.get_pending_reports().len(), 2);
+}
+
 // ══════════════════════════════════════════════════════════════════════
 //  Governance Tests
 // ══════════════════════════════════════════════════════════════════════
@@ -354,6 +687,215 @@ fn test_create_proposal() {
     assert_eq!(proposal_id, 1);
 }
 
+fn setup_governance<'a>(
+    env: &Env,
+    admin: &Address,
+    min_quorum_percent: u32,
+) -> CommunityClient<'a> {
+    let client = setup_community(env, admin);
+    let mut config = client.get_config();
+    config.vote_weight_threshold = 10;
+    client.update_config(admin, &config);
+    client.set_min_quorum_percent(admin, &min_quorum_percent);
+    client
+}
+
+fn build_voter(env: &Env, client: &CommunityClient, user: &Address) {
+    client.create_post(
+        user,
+        &ForumCategory::General,
+        &String::from_str(env, "Introductions"),
+        &String::from_str(env, "Hello from a new community member."),
+        &Vec::new(env),
+        &String::from_str(env, ""),
+    );
+    client.calculate_reputation(user);
+}
+
+fn post_in(
+    env: &Env,
+    client: &CommunityClient,
+    author: &Address,
+    category: ForumCategory,
+) -> Result<u64, CommunityError> {
+    match client.try_create_post(
+        author,
+        &category,
+        &String::from_str(env, "Showcase entry"),
+        &String::from_str(env, "Sharing a project I built during the course."),
+        &Vec::new(env),
+        &String::from_str(env, ""),
+    ) {
+        Ok(Ok(id)) => Ok(id),
+        Err(Ok(e)) => Err(e),
+        _ => panic!("unexpected create_post result"),
+    }
+}
+
+#[test]
+fn test_gated_category_rejects_low_reputation_author() {
+    let (env, admin, user1, _, _) = create_test_env();
+    let client = setup_community(&env, &admin);
+
+    client.set_category_min_reputation(&admin, &ForumCategory::ProjectShowcase, &10);
+    assert_eq!(client.get_category_min_reputation(&ForumCategory::ProjectShowcase), 10);
+
+    let result = post_in(&env, &client, &user1, ForumCategory::ProjectShowcase);
+    assert_eq!(result, Err(CommunityError::InsufficientReputation));
+
+    // Categories without a requirement stay open
+    assert!(post_in(&env, &client, &user1, ForumCategory::General).is_ok());
+}
+
+#[test]
+fn test_gated_category_accepts_qualified_author() {
+    let (env, admin, user1, _, _) = create_test_env();
+    let client = setup_community(&env, &admin);
+    client.set_category_min_reputation(&admin, &ForumCategory::ProjectShowcase, &10);
+
+    // One general post earns 10 reputation
+    build_voter(&env, &client, &user1);
+    assert!(post_in(&env, &client, &user1, ForumCategory::ProjectShowcase).is_ok());
+}
+
+fn propose(env: &Env, client: &CommunityClient, proposer: &Address) -> u64 {
+    client.create_proposal(
+        proposer,
+        &ProposalType::FeatureRequest,
+        &String::from_str(env, "New Feature"),
+        &String::from_str(env, "Add new functionality to the platform"),
+        &86400,
+        &1,
+    )
+}
+
+#[test]
+fn test_proposal_below_snapshot_quorum_is_rejected() {
+    let (env, admin, user1, user2, user3) = create_test_env();
+    let client = setup_governance(&env, &admin, 50);
+    for user in [&user1, &user2, &user3] {
+        build_voter(&env, &client, user);
+    }
+
+    let proposal_id = propose(&env, &client, &user1);
+    assert_eq!(client.get_proposal_turnout(&proposal_id).unwrap().eligible_voters, 3);
+
+    // A single favourable vote clears min_votes_required but not 50% turnout
+    client.vote_on_proposal(&user1, &proposal_id, &true);
+    env.ledger().with_mut(|l| l.timestamp += 86_401);
+
+    assert_eq!(client.finalize_proposal(&proposal_id), ProposalStatus::Rejected);
+}
+
+#[test]
+fn test_proposal_meeting_snapshot_quorum_passes() {
+    let (env, admin, user1, user2, user3) = create_test_env();
+    let client = setup_governance(&env, &admin, 50);
+    for user in [&user1, &user2, &user3] {
+        build_voter(&env, &client, user);
+    }
+
+    let proposal_id = propose(&env, &client, &user1);
+
+    // Members who become eligible later do not change the snapshot
+    let late = Address::generate(&env);
+    build_voter(&env, &client, &late);
+
+    client.vote_on_proposal(&user1, &proposal_id, &true);
+    client.vote_on_proposal(&user2, &proposal_id, &true);
+    env.ledger().with_mut(|l| l.timestamp += 86_401);
+
+    assert_eq!(client.get_proposal_turnout(&proposal_id).unwrap().eligible_voters, 3);
+    assert_eq!(client.finalize_proposal(&proposal_id), ProposalStatus::Passed);
+}
+
+#[test]
+fn test_late_eligible_voters_do_not_count_towards_turnout() {
+    let (env, admin, user1, user2, user3) = create_test_env();
+    let client = setup_governance(&env, &admin, 50);
+    for user in [&user1, &user2, &user3] {
+        build_voter(&env, &client, user);
+    }
+
+    let proposal_id = propose(&env, &client, &user1);
+    env.ledger().with_mut(|l| l.timestamp += 60);
+    let late = Address::generate(&env);
+    build_voter(&env, &client, &late);
+
+    // The late member may vote, but only user1 is part of the snapshot
+    client.vote_on_proposal(&user1, &proposal_id, &true);
+    client.vote_on_proposal(&late, &proposal_id, &true);
+    env.ledger().with_mut(|l| l.timestamp += 86_401);
+
+    assert_eq!(client.get_proposal_turnout(&proposal_id).unwrap().voter_count, 1);
+    assert_eq!(client.finalize_proposal(&proposal_id), ProposalStatus::Rejected);
+}
+
+#[test]
+fn test_sync_voting_eligibility_backfills_snapshot_after_threshold_change() {
+    let (env, admin, user1, user2, user3) = create_test_env();
+    let client = setup_community(&env, &admin);
+    client.set_min_quorum_percent(&admin, &50);
+    // Reputation is calculated while the default threshold (100) excludes everyone
+    for user in [&user1, &user2, &user3] {
+        build_voter(&env, &client, user);
+    }
+
+    let mut config = client.get_config();
+    config.vote_weight_threshold = 10;
+    client.update_config(&admin, &config);
+
+    // Only the proposer is picked up at proposal time without a sync
+    let stale = propose(&env, &client, &user1);
+    assert_eq!(client.get_proposal_turnout(&stale).unwrap().eligible_voters, 1);
+
+    let mut users = Vec::new(&env);
+    users.push_back(user2.clone());
+    users.push_back(user3.clone());
+    client.sync_voting_eligibility(&admin, &users);
+
+    let proposal_id = propose(&env, &client, &user1);
+    assert_eq!(client.get_proposal_turnout(&proposal_id).unwrap().eligible_voters, 3);
+}
+
+#[test]
+fn test_sync_voting_eligibility_is_bounded() {
+    let (env, admin, _, _, _) = create_test_env();
+    let client = setup_community(&env, &admin);
+
+    let mut users = Vec::new(&env);
+    for _ in 0..51 {
+        users.push_back(Address::generate(&env));
+    }
+    let result = client.try_sync_voting_eligibility(&admin, &users);
+    assert_eq!(result, Err(Ok(CommunityError::InvalidInput)));
+}
+
+#[test]
+fn test_min_quorum_percent_must_be_a_percentage() {
+    let (env, admin, _, _, _) = create_test_env();
+    let client = setup_governance(&env, &admin, 40);
+    assert_eq!(client.get_min_quorum_percent(), 40);
+
+    let result = client.try_set_min_quorum_percent(&admin, &101);
+    assert_eq!(result, Err(Ok(CommunityError::InvalidInput)));
+}
+
+#[test]
+fn test_proposal_without_quorum_uses_vote_count_only() {
+    let (env, admin, user1, user2, user3) = create_test_env();
+    let client = setup_governance(&env, &admin, 0);
+    for user in [&user1, &user2, &user3] {
+        build_voter(&env, &client, user);
+    }
+
+    let proposal_id = propose(&env, &client, &user1);
+    client.vote_on_proposal(&user1, &proposal_id, &true);
+    env.ledger().with_mut(|l| l.timestamp += 86_401);
+
+    assert_eq!(client.finalize_proposal(&proposal_id), ProposalStatus::Passed);
+}
+
 // ══════════════════════════════════════════════════════════════════════
 //  Rate Limiting Tests
 // ══════════════════════════════════════════════════════════════════════
@@ -691,3 +1233,228 @@ fn test_rate_session_out_of_range() {
 
     client.rate_session(&user2, &session_id, &6); // > MAX_RATING of 5
 }
+
+fn earn_posts(env: &Env, client: &CommunityClient, user: &Address, count: u32) {
+    for _ in 0..count {
+        post_in(env, client, user, ForumCategory::General).unwrap();
+    }
+}
+
+#[test]
+fn test_docked_reputation_recovers_with_activity_up_to_earned() {
+    let (env, admin, user1, _, _) = create_test_env();
+    let client = setup_community(&env, &admin);
+    let policy = ReputationRecoveryPolicy { recovery_period: 86_400, recovery_percent: 25 };
+    client.set_reputation_recovery_policy(&admin, &Some(policy));
+
+    earn_posts(&env, &client, &user1, 5);
+    assert_eq!(client.calculate_reputation(&user1), 50);
+    client.dock_reputation(&admin, &user1, &40);
+    assert_eq!(client.calculate_reputation(&user1), 10);
+
+    // Time alone does not restore reputation
+    env.ledger().with_mut(|l| l.timestamp += 3 * 86_400);
+    assert_eq!(client.calculate_reputation(&user1), 10);
+
+    // Two periods allow 20 back, but only 10 was earned since the penalty
+    env.ledger().with_mut(|l| l.timestamp += 2 * 86_400);
+    earn_posts(&env, &client, &user1, 1);
+    assert_eq!(client.calculate_reputation(&user1), 30);
+    assert_eq!(client.get_reputation_penalty(&user1).unwrap().outstanding, 30);
+
+    // Recovery never goes beyond what the user has legitimately earned
+    env.ledger().with_mut(|l| l.timestamp += 10 * 86_400);
+    earn_posts(&env, &client, &user1, 5);
+    assert_eq!(client.calculate_reputation(&user1), 110);
+    assert!(client.get_reputation_penalty(&user1).is_none());
+}
+
+#[test]
+fn test_docked_reputation_without_policy_does_not_recover() {
+    let (env, admin, user1, user2, _) = create_test_env();
+    let client = setup_community(&env, &admin);
+
+    earn_posts(&env, &client, &user1, 3);
+    client.dock_reputation(&admin, &user1, &100);
+    assert_eq!(client.calculate_reputation(&user1), 0);
+
+    env.ledger().with_mut(|l| l.timestamp += 30 * 86_400);
+    earn_posts(&env, &client, &user1, 2);
+    assert_eq!(client.calculate_reputation(&user1), 0);
+
+    let non_moderator = client.try_dock_reputation(&user2, &user1, &10);
+    assert_eq!(non_moderator, Err(Ok(CommunityError::Unauthorized)));
+    let bad_policy = ReputationRecoveryPolicy { recovery_period: 86_400, recovery_percent: 101 };
+    let result = client.try_set_reputation_recovery_policy(&admin, &Some(bad_policy));
+    assert_eq!(result, Err(Ok(CommunityError::InvalidInput)));
+}
+
+fn mentor_one_session(env: &Env, client: &CommunityClient, mentor: &Address, mentee: &Address) {
+    let mut expertise = Vec::new(env);
+    expertise.push_back(String::from_str(env, "Soroban"));
+    client.register_mentor(
+        mentor,
+        &expertise,
+        &MentorExpertise::Expert,
+        &3,
+        &String::from_str(env, "Soroban mentor"),
+    );
+    let request_id = client.request_mentorship(
+        mentee,
+        mentor,
+        &String::from_str(env, "Soroban"),
+        &String::from_str(env, "Storage design"),
+    );
+    client.accept_mentorship(mentor, &request_id);
+    client.complete_session(mentor, &request_id, &3600, &String::from_str(env, "Covered storage"));
+}
+
+#[test]
+fn test_reputation_uses_default_weights() {
+    let (env, admin, user1, user2, _) = create_test_env();
+    let client = setup_community(&env, &admin);
+
+    earn_posts(&env, &client, &user2, 2);
+    mentor_one_session(&env, &client, &user1, &user2);
+
+    // 2 posts x 10 + 1 mentorship session x 75
+    assert_eq!(client.calculate_reputation(&user2), 95);
+    assert_eq!(client.get_reputation_weights().mentorship, 75);
+}
+
+#[test]
+fn test_custom_weights_emphasizing_mentorship_shift_reputation() {
+    let (env, admin, user1, user2, user3) = create_test_env();
+    let client = setup_community(&env, &admin);
+
+    earn_posts(&env, &client, &user2, 2);
+    mentor_one_session(&env, &client, &user1, &user2);
+    earn_posts(&env, &client, &user3, 4);
+    assert_eq!(client.calculate_reputation(&user2), 95);
+    assert_eq!(client.calculate_reputation(&user3), 40);
+
+    let mut weights = client.get_reputation_weights();
+    weights.post = 5;
+    weights.mentorship = 300;
+    client.set_reputation_weights(&admin, &weights);
+
+    // 2 posts x 5 + 1 mentorship session x 300
+    assert_eq!(client.calculate_reputation(&user2), 310);
+    assert_eq!(client.calculate_reputation(&user3), 20);
+
+    let zero = ReputationWeights {
+        post: 0,
+        reply: 0,
+        solution: 0,
+        contribution: 0,
+        event: 0,
+        mentorship: 0,
+        helpful_vote: 0,
+    };
+    assert_eq!(
+        client.try_set_reputation_weights(&admin, &zero),
+        Err(Ok(CommunityError::InvalidInput))
+    );
+    assert_eq!(
+        client.try_set_reputation_weights(&user1, &weights),
+        Err(Ok(CommunityError::Unauthorized))
+    );
+}
+
+// ══════════════════════════════════════════════════════════════════════
+//  Mentor Recommendation Tests
+// ══════════════════════════════════════════════════════════════════════
+
+fn seed_mentor(
+    env: &Env,
+    client: &CommunityClient,
+    area: &str,
+    rating: u32,
+    max_mentees: u32,
+    current_mentees: u32,
+) -> Address {
+    let mentor = Address::generate(env);
+    let mut expertise = Vec::new(env);
+    expertise.push_back(String::from_str(env, area));
+    client.register_mentor(
+        &mentor,
+        &expertise,
+        &MentorExpertise::Advanced,
+        &max_mentees,
+        &String::from_str(env, "Seeded mentor"),
+    );
+
+    env.as_contract(&client.address, || {
+        let key = CommunityKey::MentorProfile(mentor.clone());
+        let mut profile: MentorProfile = env.storage().persistent().get(&key).unwrap();
+        profile.rating = rating;
+        profile.current_mentees = current_mentees;
+        env.storage().persistent().set(&key, &profile);
+    });
+    mentor
+}
+
+#[test]
+fn test_recommend_mentors_ranks_matching_available_mentors_first() {
+    let (env, admin, mentee, _, _) = create_test_env();
+    let client = setup_community(&env, &admin);
+
+    let rust_good = seed_mentor(&env, &client, "Rust", 80, 3, 1);
+    let rust_best = seed_mentor(&env, &client, "Rust", 95, 3, 0);
+    let go_top = seed_mentor(&env, &client, "Go", 100, 3, 0);
+    seed_mentor(&env, &client, "Rust", 100, 2, 2); // at capacity
+
+    let topic = String::from_str(&env, "Rust");
+    let recommended = client.recommend_mentors(&mentee, &topic, &10);
+    assert_eq!(recommended.len(), 3);
+    assert_eq!(recommended.get(0).unwrap().mentor, rust_best);
+    assert_eq!(recommended.get(1).unwrap().mentor, rust_good);
+    assert_eq!(recommended.get(2).unwrap().mentor, go_top);
+
+    let top = client.recommend_mentors(&mentee, &topic, &1);
+    assert_eq!(top.len(), 1);
+    assert_eq!(top.get(0).unwrap().mentor, rust_best);
+}
+
+#[test]
+fn test_recommend_mentors_breaks_rating_ties_by_spare_capacity() {
+    let (env, admin, mentee, _, _) = create_test_env();
+    let client = setup_community(&env, &admin);
+
+    let busy = seed_mentor(&env, &client, "Rust", 90, 4, 3);
+    let free = seed_mentor(&env, &client, "Rust", 90, 4, 0);
+
+    let recommended = client.recommend_mentors(&mentee, &String::from_str(&env, "Rust"), &5);
+    assert_eq!(recommended.len(), 2);
+    assert_eq!(recommended.get(0).unwrap().mentor, free);
+    assert_eq!(recommended.get(1).unwrap().mentor, busy);
+    // The mentee never appears among their own recommendations
+    let for_free = client.recommend_mentors(&free, &String::from_str(&env, "Rust"), &5);
+    assert_eq!(for_free.len(), 1);
+    assert_eq!(for_free.get(0).unwrap().mentor, busy);
+}
+
+#[test]
+fn test_index_mentors_backfills_unindexed_mentors() {
+    let (env, admin, mentee, _, _) = create_test_env();
+    let client = setup_community(&env, &admin);
+    let topic = String::from_str(&env, "Rust");
+
+    let legacy = seed_mentor(&env, &client, "Rust", 100, 3, 0);
+    // Simulate a mentor who registered before the index existed
+    env.as_contract(&client.address, || {
+        env.storage().persistent().remove(&CommunityExtKey::Mentors);
+    });
+    assert_eq!(client.recommend_mentors(&mentee, &topic, &5).len(), 0);
+
+    let mut mentors = Vec::new(&env);
+    mentors.push_back(legacy.clone());
+    mentors.push_back(Address::generate(&env));
+    assert_eq!(client.index_mentors(&admin, &mentors), 1);
+    // Already-indexed mentors are not duplicated
+    assert_eq!(client.index_mentors(&admin, &mentors), 0);
+
+    let recommended = client.recommend_mentors(&mentee, &topic, &5);
+    assert_eq!(recommended.len(), 1);
+    assert_eq!(recommended.get(0).unwrap().mentor, legacy);
+}
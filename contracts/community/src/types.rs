@@ -102,6 +102,16 @@ pub struct ForumReply {
     pub parent_reply_id: u64,
 }
 
+/// How a voter's reputation translates into forum vote weight.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ForumVoteWeighting {
+    /// Reputation needed per extra unit of vote weight; 0 counts every vote once.
+    pub reputation_step: u32,
+    /// Maximum weight a single vote can carry.
+    pub max_weight: u32,
+}
+
 // ───────────────────────────────────────────────
 //  Mentorship System
 // ───────────────────────────────────────────────
@@ -274,12 +284,26 @@ pub struct KnowledgeContribution {
     pub created_at: u64,
     /// Unix timestamp (seconds) when the contribution was published; 0 if not published.
     pub published_at: u64,
-    /// Amount of XP awarded to the contributor upon publication.
+    /// Amount of XP the contributor can claim once the contribution is approved.
     pub xp_reward: u32,
-    /// Amount of tokens awarded to the contributor upon publication.
+    /// Amount of tokens the contributor can claim once the contribution is approved.
     pub token_reward: i128,
 }
 
+/// Moderator flag marking a contribution as suspected plagiarism.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ContributionFlag {
+    /// ID of the flagged contribution.
+    pub contribution_id: u64,
+    /// Moderator who raised the flag.
+    pub moderator: Address,
+    /// Moderator's explanation, e.g. the suspected original source.
+    pub reason: String,
+    /// Unix timestamp (seconds) when the flag was raised.
+    pub flagged_at: u64,
+}
+
 // ───────────────────────────────────────────────
 //  Community Events
 // ───────────────────────────────────────────────
@@ -350,6 +374,16 @@ pub struct CommunityEvent {
     pub created_at: u64,
 }
 
+/// Repetition schedule for a recurring community event.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct EventRecurrence {
+    /// Seconds between the start of consecutive occurrences.
+    pub interval_seconds: u64,
+    /// Total number of occurrences, including the first.
+    pub count: u32,
+}
+
 /// Registration and attendance record for a single participant at an event.
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[contracttype]
@@ -518,6 +552,50 @@ pub struct UserCommunityStats {
     pub joined_at: u64,
 }
 
+/// Reputation docked from a user by moderators and not yet recovered.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ReputationPenalty {
+    /// Penalty still subtracted from the user's earned reputation.
+    pub outstanding: u32,
+    /// Total reputation docked, used to size each recovery step.
+    pub docked: u32,
+    /// Earned reputation already credited towards recovery; only gains above it count.
+    pub earned_baseline: u32,
+    /// Unix timestamp (seconds) up to which recovery periods have been applied.
+    pub last_recovery_at: u64,
+}
+
+/// Policy for lazily recovering docked reputation as a user stays active.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ReputationRecoveryPolicy {
+    /// Length of one recovery period in seconds.
+    pub recovery_period: u64,
+    /// Percentage of the docked amount recoverable per elapsed period (1–100).
+    pub recovery_percent: u32,
+}
+
+/// Points awarded per unit of each activity in the earned-reputation sum.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ReputationWeights {
+    /// Points per forum post created.
+    pub post: u32,
+    /// Points per forum reply given.
+    pub reply: u32,
+    /// Points per reply marked as a solution.
+    pub solution: u32,
+    /// Points per approved contribution.
+    pub contribution: u32,
+    /// Points per event attended.
+    pub event: u32,
+    /// Points per completed mentorship session.
+    pub mentorship: u32,
+    /// Points per helpful vote received.
+    pub helpful_vote: u32,
+}
+
 // ───────────────────────────────────────────────
 //  Community Governance
 // ───────────────────────────────────────────────
@@ -582,6 +660,16 @@ pub struct CommunityProposal {
     pub min_votes_required: u32,
 }
 
+/// Turnout tracked for a proposal against the eligible-voter snapshot taken at creation.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ProposalTurnout {
+    /// Number of members eligible to vote when the proposal was created.
+    pub eligible_voters: u32,
+    /// Number of distinct members from the eligible-voter snapshot who have voted.
+    pub voter_count: u32,
+}
+
 // ───────────────────────────────────────────────
 //  Configuration
 // ───────────────────────────────────────────────
@@ -723,3 +811,63 @@ pub enum CommunityKey {
     // Rate Limiting
     RateLimit(Address, u64), // (user, operation_id) -> RateLimitState
 }
+
+/// Storage keys for community features added after the original key set.
+///
+/// Kept separate from [`CommunityKey`] because a `#[contracttype]` enum can
+/// hold at most 50 variants.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum CommunityExtKey {
+    // Forum
+    /// Minimum reputation score required to create posts in a forum category.
+    CategoryMinReputation(ForumCategory),
+    /// Admin-configured reputation weighting for forum votes.
+    ForumVoteWeighting,
+    /// Net vote score of a post, with each vote weighted by the voter's reputation.
+    PostScore(u64),
+
+    // Mentorship
+    /// List of all registered mentor addresses, in registration order.
+    Mentors,
+
+    // Knowledge Base
+    /// Plagiarism flag on a contribution; its rewards cannot be claimed while set.
+    ContributionFlag(u64),
+    /// Set when a contribution is approved and removed once its rewards are claimed.
+    ContributionRewardPending(u64),
+
+    // Events
+    /// Occurrence event IDs of a recurring series, keyed by the first occurrence's ID.
+    EventSeries(u64),
+    /// ID of the first occurrence of the recurring series an event belongs to.
+    EventSeriesOf(u64),
+
+    // Moderation
+    /// Number of distinct reporters with open reports after which content is auto-hidden.
+    AutoHideReportThreshold,
+    /// Number of distinct reporters with unresolved reports against a content item (type, id).
+    ContentReportCount(String, u64),
+    /// Number of unresolved reports one reporter has filed against a content item (type, id).
+    ContentReporter(String, u64, Address),
+    /// Marker set while a content item (type, id) is auto-hidden pending review.
+    HiddenContent(String, u64),
+
+    // Analytics
+    /// Number of members whose last calculated reputation met the voting threshold.
+    EligibleVoterCount,
+    /// When a member's reputation last crossed the voting threshold; absent while ineligible.
+    EligibleSince(Address),
+    /// Outstanding moderation penalty against a user's reputation.
+    ReputationPenalty(Address),
+    /// Optional policy for recovering docked reputation over time.
+    ReputationRecoveryPolicy,
+    /// Admin-configured activity weights used to compute earned reputation.
+    ReputationWeights,
+
+    // Governance
+    /// Minimum turnout, as a percentage of the eligible-voter snapshot, for a proposal to pass.
+    MinQuorumPercent,
+    /// Eligible-voter snapshot and turnout of a proposal.
+    ProposalTurnout(u64),
+}
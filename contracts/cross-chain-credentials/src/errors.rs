@@ -10,6 +10,8 @@ pub enum CrossChainError {
     NotInitialized = 2,
     /// Caller does not have the required admin privileges.
     Unauthorized = 10,
+    /// A proof format version of zero was supplied.
+    InvalidFormatVersion = 20,
     /// No credential was found with the given ID.
     CredentialNotFound = 50,
     /// No cross-chain proof has been generated for the given credential.
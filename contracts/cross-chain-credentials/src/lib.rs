@@ -11,15 +11,15 @@ use shared::event_schema::{
 use shared::monitoring::{ContractHealthReport, Monitor};
 use shared::validation::{CoreValidator, ValidationConfig};
 use shared::{emit_access_control_event, emit_crosschain_event};
-use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env, String, Vec};
+use soroban_sdk::{contract, contractimpl, symbol_short, xdr::ToXdr, Address, Env, String, Vec};
 
 mod storage;
 mod types;
 
 use storage::{get_admin, is_oracle, DataKey};
 use types::{
-    BridgeRequest, BridgeStatus, ChainId, Credential, CredentialStatus, CrossChainProof,
-    Transcript, VerificationRequest,
+    BridgeRequest, BridgeStatus, ChainId, ChainProofConfig, Credential, CredentialStatus,
+    CrossChainProof, ProofFormat, Transcript, VerificationRequest,
 };
 
 #[contract]
@@ -261,8 +261,12 @@ impl CrossChainCredentials {
 
     /// Verifies a credential for use on another chain and generates a cross-chain proof.
     ///
-    /// The credential must be in `Active` status. The generated proof is stored on-chain and
-    /// can be retrieved later via [`CrossChainCredentials::get_proof`].
+    /// The credential must be in `Active` status. The format registered for `target_chain` via
+    /// [`CrossChainCredentials::register_chain`] (Soroban XDR v1 if none is registered) is recorded
+    /// alongside the proof and retrievable via [`CrossChainCredentials::get_proof_format`]. Only
+    /// this marker varies per format: `proof_hash` is always SHA-256 over the XDR-encoded proof
+    /// fields, with the format included in the hashed input. The generated proof is stored
+    /// on-chain and can be retrieved later via [`CrossChainCredentials::get_proof`].
     ///
     /// # Arguments
     /// * `credential_id` - ID of the credential to verify.
@@ -291,15 +295,18 @@ impl CrossChainCredentials {
             return Err(CrossChainError::CredentialNotActive);
         }
 
+        let chain_config = storage::get_chain_config(&env, &target_chain);
+        let verified_at = env.ledger().timestamp();
         let proof = CrossChainProof {
             credential_id: credential.id.clone(),
             source_chain: credential.chain_id.clone(),
             target_chain: target_chain.clone(),
-            proof_hash: String::from_str(&env, "proof_hash"),
-            verified_at: env.ledger().timestamp(),
+            proof_hash: proof_hash(&env, &credential, &target_chain, &chain_config, verified_at),
+            verified_at,
         };
 
         env.storage().persistent().set(&DataKey::Proof(credential_id.clone()), &proof);
+        storage::set_proof_format(&env, &credential_id, &chain_config);
 
         emit_crosschain_event!(
             &env,
@@ -313,6 +320,57 @@ impl CrossChainCredentials {
         Ok(proof)
     }
 
+    /// Registers the proof format a target chain expects.
+    ///
+    /// Requires admin authorization. Re-registering a chain replaces its format.
+    ///
+    /// # Arguments
+    /// * `chain_id` - Chain whose proof format is being registered.
+    /// * `target_format` - Encoding proofs for this chain should be produced in.
+    /// * `format_version` - Version of the encoding expected by the chain.
+    ///
+    /// # Errors
+    /// Returns [`CrossChainError::InvalidFormatVersion`] if `format_version` is zero.
+    ///
+    /// # Example
+    /// ```ignore
+    /// client.register_chain(&ChainId::Ethereum, &ProofFormat::EvmAbi, &2);
+    /// ```
+    pub fn register_chain(
+        env: Env,
+        chain_id: ChainId,
+        target_format: ProofFormat,
+        format_version: u32,
+    ) -> Result<(), CrossChainError> {
+        let admin = get_admin(&env);
+        admin.require_auth();
+
+        if format_version == 0 {
+            return Err(CrossChainError::InvalidFormatVersion);
+        }
+
+        storage::set_chain_config(
+            &env,
+            &ChainProofConfig { chain_id, target_format, format_version },
+        );
+        Ok(())
+    }
+
+    /// Returns the proof format configuration for a chain.
+    ///
+    /// Chains that were never registered report Soroban XDR version 1.
+    ///
+    /// # Arguments
+    /// * `chain_id` - Chain to look up.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let config = client.get_chain_config(&ChainId::Ethereum);
+    /// ```
+    pub fn get_chain_config(env: Env, chain_id: ChainId) -> ChainProofConfig {
+        storage::get_chain_config(&env, &chain_id)
+    }
+
     /// Returns the cross-chain proof previously generated for a credential.
     ///
     /// # Arguments
@@ -332,6 +390,32 @@ impl CrossChainCredentials {
             .ok_or(CrossChainError::ProofNotFound)
     }
 
+    /// Returns the proof format a credential's cross-chain proof was generated in.
+    ///
+    /// Proofs generated before formats were tracked report Soroban XDR version 1.
+    ///
+    /// # Arguments
+    /// * `credential_id` - ID of the credential whose proof format to retrieve.
+    ///
+    /// # Errors
+    /// Returns [`CrossChainError::ProofNotFound`] if no proof has been generated yet.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let format = client.get_proof_format(&cred_id);
+    /// ```
+    pub fn get_proof_format(
+        env: Env,
+        credential_id: String,
+    ) -> Result<ChainProofConfig, CrossChainError> {
+        let proof: CrossChainProof = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Proof(credential_id.clone()))
+            .ok_or(CrossChainError::ProofNotFound)?;
+        Ok(storage::get_proof_format(&env, &credential_id, &proof.target_chain))
+    }
+
     /// Submits a verification request for a credential on a target chain.
     ///
     /// Anyone may submit a verification request. Returns the unique request ID string.
@@ -645,5 +729,35 @@ impl CrossChainCredentials {
     }
 }
 
+/// Hex-encoded SHA-256 over the credential, chains, proof format and timestamp,
+/// so a verifier on the target chain can recompute it from the proof fields.
+fn proof_hash(
+    env: &Env,
+    credential: &Credential,
+    target_chain: &ChainId,
+    chain_config: &ChainProofConfig,
+    verified_at: u64,
+) -> String {
+    let payload = (
+        credential.id.clone(),
+        credential.metadata_hash.clone(),
+        credential.chain_id.clone(),
+        target_chain.clone(),
+        chain_config.target_format.clone(),
+        chain_config.format_version,
+        verified_at,
+    )
+        .to_xdr(env);
+    let digest = env.crypto().sha256(&payload).to_array();
+
+    const HEX: &[u8; 16] = b"0123456789abcdef";
+    let mut hex = [0u8; 64];
+    for (i, byte) in digest.iter().enumerate() {
+        hex[2 * i] = HEX[(byte >> 4) as usize];
+        hex[2 * i + 1] = HEX[(byte & 0x0f) as usize];
+    }
+    String::from_bytes(env, &hex)
+}
+
 #[cfg(test)]
 mod tests;
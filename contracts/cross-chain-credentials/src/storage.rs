@@ -1,5 +1,7 @@
 use soroban_sdk::{contracttype, Address, Env, String};
 
+use crate::types::{ChainId, ChainProofConfig, ProofFormat};
+
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
@@ -11,6 +13,8 @@ pub enum DataKey {
     StudentCreds(Address),
     ChainBridge(u32),
     BridgeRequest(String),
+    ChainProofConfig(u32),
+    ProofFormat(String),
 }
 
 pub fn set_admin(env: &Env, admin: &Address) {
@@ -28,3 +32,38 @@ pub fn is_oracle(env: &Env, oracle: &Address) -> bool {
 pub fn add_oracle(env: &Env, oracle: &Address) {
     env.storage().instance().set(&DataKey::Oracle(oracle.clone()), &true);
 }
+
+pub fn set_chain_config(env: &Env, config: &ChainProofConfig) {
+    env.storage().instance().set(&DataKey::ChainProofConfig(config.chain_id.to_u32()), config);
+}
+
+/// Returns the proof format registered for `chain_id`, defaulting to Soroban XDR v1.
+pub fn get_chain_config(env: &Env, chain_id: &ChainId) -> ChainProofConfig {
+    env.storage().instance().get(&DataKey::ChainProofConfig(chain_id.to_u32())).unwrap_or(
+        ChainProofConfig {
+            chain_id: chain_id.clone(),
+            target_format: ProofFormat::SorobanXdr,
+            format_version: 1,
+        },
+    )
+}
+
+pub fn set_proof_format(env: &Env, credential_id: &String, config: &ChainProofConfig) {
+    env.storage().persistent().set(&DataKey::ProofFormat(credential_id.clone()), config);
+}
+
+/// Returns the format a credential's proof was generated in. Proofs stored before formats
+/// were tracked were all Soroban XDR v1.
+pub fn get_proof_format(
+    env: &Env,
+    credential_id: &String,
+    target_chain: &ChainId,
+) -> ChainProofConfig {
+    env.storage().persistent().get(&DataKey::ProofFormat(credential_id.clone())).unwrap_or(
+        ChainProofConfig {
+            chain_id: target_chain.clone(),
+            target_format: ProofFormat::SorobanXdr,
+            format_version: 1,
+        },
+    )
+}
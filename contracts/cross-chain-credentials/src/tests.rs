@@ -2,7 +2,7 @@ use super::*;
 use crate::errors::CrossChainError;
 use shared::monitoring::ContractHealthStatus;
 use soroban_sdk::{testutils::Address as _, Address, Env, String};
-use types::{ChainId, CredentialStatus};
+use types::{ChainId, CredentialStatus, ProofFormat};
 
 #[test]
 fn test_initialize() {
@@ -94,6 +94,100 @@ fn test_cross_chain_verification() {
     assert_eq!(proof.target_chain, ChainId::Ethereum);
 }
 
+#[test]
+fn test_proof_records_registered_chain_format() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(CrossChainCredentials, ());
+    let client = CrossChainCredentialsClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let student = Address::generate(&env);
+
+    client.initialize(&admin);
+    client.register_chain(&ChainId::Ethereum, &ProofFormat::EvmAbi, &2);
+    client.register_chain(&ChainId::Polygon, &ProofFormat::Json, &1);
+
+    let cred_id = client.issue_credential(
+        &student,
+        &String::from_str(&env, "DeFi Mastery"),
+        &String::from_str(&env, "hash456"),
+        &ChainId::Stellar,
+    );
+
+    let eth_proof = client.verify_cross_chain(&cred_id, &ChainId::Ethereum);
+    let eth_format = client.get_proof_format(&cred_id);
+    assert_eq!(eth_format.chain_id, ChainId::Ethereum);
+    assert_eq!(eth_format.target_format, ProofFormat::EvmAbi);
+    assert_eq!(eth_format.format_version, 2);
+
+    let poly_proof = client.verify_cross_chain(&cred_id, &ChainId::Polygon);
+    let poly_format = client.get_proof_format(&cred_id);
+    assert_eq!(poly_format.chain_id, ChainId::Polygon);
+    assert_eq!(poly_format.target_format, ProofFormat::Json);
+    assert_eq!(poly_format.format_version, 1);
+
+    // The proof hash commits to the target chain and its format
+    assert_eq!(eth_proof.proof_hash.len(), 64);
+    assert_ne!(eth_proof.proof_hash, poly_proof.proof_hash);
+
+    // Unregistered chains fall back to Soroban XDR v1
+    client.verify_cross_chain(&cred_id, &ChainId::Arbitrum);
+    let arb_format = client.get_proof_format(&cred_id);
+    assert_eq!(arb_format.target_format, ProofFormat::SorobanXdr);
+    assert_eq!(arb_format.format_version, 1);
+}
+
+#[test]
+fn test_proof_format_defaults_for_proofs_without_a_recorded_format() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(CrossChainCredentials, ());
+    let client = CrossChainCredentialsClient::new(&env, &contract_id);
+
+    client.initialize(&Address::generate(&env));
+    client.register_chain(&ChainId::Ethereum, &ProofFormat::EvmAbi, &2);
+
+    assert_eq!(
+        client.try_get_proof_format(&String::from_str(&env, "missing")),
+        Err(Ok(CrossChainError::ProofNotFound))
+    );
+
+    // A proof stored before formats were tracked has no format entry
+    let cred_id = String::from_str(&env, "legacy");
+    let proof = CrossChainProof {
+        credential_id: cred_id.clone(),
+        source_chain: ChainId::Stellar,
+        target_chain: ChainId::Ethereum,
+        proof_hash: String::from_str(&env, "proof_hash"),
+        verified_at: 0,
+    };
+    env.as_contract(&contract_id, || {
+        env.storage().persistent().set(&DataKey::Proof(cred_id.clone()), &proof);
+    });
+
+    assert_eq!(client.get_proof(&cred_id), proof);
+    let format = client.get_proof_format(&cred_id);
+    assert_eq!(format.chain_id, ChainId::Ethereum);
+    assert_eq!(format.target_format, ProofFormat::SorobanXdr);
+    assert_eq!(format.format_version, 1);
+}
+
+#[test]
+fn test_register_chain_rejects_zero_version() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(CrossChainCredentials, ());
+    let client = CrossChainCredentialsClient::new(&env, &contract_id);
+    client.initialize(&Address::generate(&env));
+
+    let result = client.try_register_chain(&ChainId::Ethereum, &ProofFormat::EvmAbi, &0);
+    assert_eq!(result, Err(Ok(CrossChainError::InvalidFormatVersion)));
+}
+
 #[test]
 fn test_oracle_management() {
     let env = Env::default();
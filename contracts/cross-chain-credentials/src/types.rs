@@ -125,6 +125,33 @@ pub struct CrossChainProof {
     pub verified_at: u64,
 }
 
+/// Proof encodings that target chains can expect.
+///
+/// The format is a marker recorded next to each proof for the target chain's verifier;
+/// `proof_hash` itself is always SHA-256 over the XDR-encoded proof fields.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ProofFormat {
+    /// Soroban-native XDR encoding.
+    SorobanXdr,
+    /// ABI-encoded payload for EVM-compatible chains.
+    EvmAbi,
+    /// JSON payload for generic off-chain verifiers.
+    Json,
+}
+
+/// Proof format registered for a target chain via `register_chain`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChainProofConfig {
+    /// Chain this configuration applies to.
+    pub chain_id: ChainId,
+    /// Encoding proofs for this chain are produced in.
+    pub target_format: ProofFormat,
+    /// Version of the encoding expected by the chain.
+    pub format_version: u32,
+}
+
 /// An attestation from an oracle confirming the validity of a credential on a given chain.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
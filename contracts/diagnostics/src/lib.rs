@@ -70,6 +70,23 @@ impl Diagnostics {
         PerformanceMonitor::stop_monitoring(&env, &contract_address)
     }
 
+    /// Set the percentage (1-100) of recorded metrics persisted to history for a contract
+    pub fn set_sample_rate(
+        env: Env,
+        contract_address: Address,
+        sample_rate: u32,
+    ) -> Result<(), DiagnosticsError> {
+        let admin = DiagnosticsStorage::get_admin(&env)?;
+        admin.require_auth();
+
+        PerformanceMonitor::set_sample_rate(&env, &contract_address, sample_rate)
+    }
+
+    /// Get the sample rate for a contract (100 when never configured)
+    pub fn get_sample_rate(env: Env, contract_address: Address) -> u32 {
+        DiagnosticsStorage::get_sample_rate(&env, &contract_address)
+    }
+
     /// Record performance metrics
     pub fn record_performance_metrics(
         env: Env,
@@ -87,6 +104,11 @@ impl Diagnostics {
         PerformanceMonitor::get_current_metrics(&env, &contract_address)
     }
 
+    /// Get running totals across every recorded sample, including unsampled ones
+    pub fn get_metrics_counters(env: Env, contract_address: Address) -> MetricsCounters {
+        PerformanceMonitor::get_metrics_counters(&env, &contract_address)
+    }
+
     /// Generate predictive capacity planning analysis
     pub fn generate_capacity_prediction(
         env: Env,
@@ -320,6 +342,11 @@ impl Diagnostics {
         Ok(health_report)
     }
 
+    /// Get the worst health status across all monitored contracts with a short reason
+    pub fn get_health_status(env: Env) -> HealthStatusSummary {
+        PerformanceMonitor::get_health_status(&env)
+    }
+
     /// Legacy diagnostic function - now enhanced
     pub fn run_diagnostic(
         env: Env,
@@ -44,6 +44,20 @@ impl PerformanceMonitor {
         Ok(())
     }
 
+    /// Set the percentage (1-100) of recorded samples persisted to history
+    pub fn set_sample_rate(
+        env: &Env,
+        contract_address: &Address,
+        sample_rate: u32,
+    ) -> Result<(), DiagnosticsError> {
+        if sample_rate == 0 || sample_rate > 100 {
+            return Err(DiagnosticsError::InvalidConfiguration);
+        }
+
+        DiagnosticsStorage::set_sample_rate(env, contract_address, sample_rate);
+        Ok(())
+    }
+
     /// Record performance metrics for a contract
     pub fn record_metrics(
         env: &Env,
@@ -53,8 +67,28 @@ impl PerformanceMonitor {
         // Validate metrics
         Self::validate_metrics(&metrics)?;
 
-        // Store metrics
-        DiagnosticsStorage::store_performance_metrics(env, contract_address, &metrics);
+        // Update running totals, then persist only the sampled fraction
+        let mut counters = DiagnosticsStorage::get_metrics_counters(env, contract_address);
+        let sample_rate = DiagnosticsStorage::get_sample_rate(env, contract_address) as u64;
+        let recorded = counters.samples_recorded;
+        let sampled = (recorded + 1) * sample_rate / 100 > recorded * sample_rate / 100;
+
+        counters.samples_recorded += 1;
+        counters.total_transactions =
+            counters.total_transactions.saturating_add(metrics.transaction_count as u64);
+        counters.total_gas_used = counters.total_gas_used.saturating_add(metrics.gas_used);
+        counters.total_errors = counters.total_errors.saturating_add(metrics.error_count as u64);
+        if sampled {
+            counters.samples_stored += 1;
+            DiagnosticsStorage::store_performance_metrics(
+                env,
+                contract_address,
+                &metrics,
+                sample_rate as u32,
+            );
+        }
+        DiagnosticsStorage::set_metrics_counters(env, contract_address, &counters);
+        DiagnosticsStorage::set_last_recorded_metrics(env, contract_address, &metrics);
 
         // Check for performance alerts
         Self::check_performance_alerts(env, contract_address, &metrics)?;
@@ -80,17 +114,86 @@ impl PerformanceMonitor {
             .ok_or(DiagnosticsError::MetricsNotFound)
     }
 
+    /// Get running totals across every recorded sample for a contract
+    pub fn get_metrics_counters(env: &Env, contract_address: &Address) -> MetricsCounters {
+        DiagnosticsStorage::get_metrics_counters(env, contract_address)
+    }
+
+    /// Return the worst health status across all monitored contracts.
+    ///
+    /// Uses only the last recorded metrics per contract so it stays cheap
+    /// enough to poll from alerting jobs.
+    pub fn get_health_status(env: &Env) -> HealthStatusSummary {
+        let contracts = DiagnosticsStorage::get_monitored_contracts(env);
+        let mut summary = HealthStatusSummary {
+            status: HealthStatus::Healthy,
+            reason: String::from_str(env, "ALL_HEALTHY"),
+            contract_address: None,
+            contracts_checked: contracts.len(),
+        };
+
+        if contracts.is_empty() {
+            summary.status = HealthStatus::Unknown;
+            summary.reason = String::from_str(env, "NO_MONITORED_CONTRACTS");
+            return summary;
+        }
+
+        for contract in contracts.iter() {
+            let (status, reason) =
+                match DiagnosticsStorage::get_last_recorded_metrics(env, &contract) {
+                    Some(metrics) => Self::classify_health(&metrics),
+                    None => (HealthStatus::Unknown, "METRICS_MISSING"),
+                };
+
+            if Self::severity(&status) > Self::severity(&summary.status) {
+                summary.status = status;
+                summary.reason = String::from_str(env, reason);
+                summary.contract_address = Some(contract.clone());
+            }
+        }
+
+        summary
+    }
+
+    /// Classify a single contract's health from its metrics
+    fn classify_health(metrics: &PerformanceMetrics) -> (HealthStatus, &'static str) {
+        if metrics.error_rate >= 20 {
+            (HealthStatus::Critical, "ERROR_RATE_CRITICAL")
+        } else if metrics.error_rate >= 5 {
+            (HealthStatus::Warning, "ERROR_RATE_HIGH")
+        } else if metrics.average_execution_time >= 1000 {
+            (HealthStatus::Warning, "EXECUTION_TIME_HIGH")
+        } else {
+            (HealthStatus::Healthy, "ALL_HEALTHY")
+        }
+    }
+
+    /// Ordering used to pick the worst status
+    fn severity(status: &HealthStatus) -> u32 {
+        match status {
+            HealthStatus::Healthy => 0,
+            HealthStatus::Unknown => 1,
+            HealthStatus::Warning => 2,
+            HealthStatus::Critical => 3,
+        }
+    }
+
     /// Generate historical performance report
+    ///
+    /// Only sampled metrics are in history, so totals scale each sample up by
+    /// the rate it was stored under; averages and rates come from the samples
+    /// as-is.
     pub fn generate_performance_report(
         env: &Env,
         contract_address: &Address,
         start_time: u64,
         end_time: u64,
     ) -> Result<PerformanceReport, DiagnosticsError> {
-        let mut total_transactions = 0;
-        let mut total_execution_time = 0;
-        let mut total_gas_used = 0;
-        let mut total_errors = 0;
+        let mut sampled_transactions = 0u64;
+        let mut total_transactions = 0u64;
+        let mut total_execution_time = 0u64;
+        let mut total_gas_used = 0u64;
+        let mut total_errors = 0u64;
         let mut peak_memory = 0;
         let mut min_execution_time = u64::MAX;
         let mut max_execution_time = 0;
@@ -100,37 +203,38 @@ impl PerformanceMonitor {
             if let Some(metrics) =
                 DiagnosticsStorage::get_performance_metrics(env, contract_address, timestamp)
             {
-                total_transactions += metrics.transaction_count;
-                total_execution_time += metrics.average_execution_time;
-                total_gas_used += metrics.gas_used;
-                total_errors += metrics.error_count;
+                let sample_rate =
+                    DiagnosticsStorage::get_metrics_sample_rate(env, contract_address, timestamp);
+
+                sampled_transactions += metrics.transaction_count as u64;
+                total_transactions = total_transactions.saturating_add(Self::scale_sample(
+                    metrics.transaction_count as u64,
+                    sample_rate,
+                ));
+                total_execution_time =
+                    total_execution_time.saturating_add(metrics.average_execution_time);
+                total_gas_used = total_gas_used
+                    .saturating_add(Self::scale_sample(metrics.gas_used, sample_rate));
+                total_errors += metrics.error_count as u64;
                 peak_memory = peak_memory.max(metrics.peak_memory_usage);
                 min_execution_time = min_execution_time.min(metrics.execution_time);
                 max_execution_time = max_execution_time.max(metrics.execution_time);
             }
         }
 
-        if total_transactions == 0 {
+        if sampled_transactions == 0 {
             return Err(DiagnosticsError::MetricsNotFound);
         }
 
-        let average_execution_time = if total_transactions > 0 {
-            total_execution_time / total_transactions as u64
-        } else {
-            0
-        };
-
-        let error_rate = if total_transactions > 0 {
-            (total_errors * 100).checked_div(total_transactions).unwrap_or(0)
-        } else {
-            0
-        };
+        let average_execution_time = total_execution_time / sampled_transactions;
+        let error_rate = u32::try_from(total_errors.saturating_mul(100) / sampled_transactions)
+            .unwrap_or(u32::MAX);
 
         Ok(PerformanceReport {
             contract_address: contract_address.clone(),
             start_time,
             end_time,
-            total_transactions,
+            total_transactions: u32::try_from(total_transactions).unwrap_or(u32::MAX),
             average_execution_time,
             min_execution_time: if min_execution_time == u64::MAX { 0 } else { min_execution_time },
             max_execution_time,
@@ -145,6 +249,11 @@ impl PerformanceMonitor {
         })
     }
 
+    /// Estimate the recorded total behind a value stored at `sample_rate` percent
+    fn scale_sample(value: u64, sample_rate: u32) -> u64 {
+        u64::try_from(value as u128 * 100 / sample_rate.max(1) as u128).unwrap_or(u64::MAX)
+    }
+
     /// Profile a specific operation
     #[allow(clippy::too_many_arguments)]
     pub fn profile_operation(
@@ -189,7 +298,7 @@ impl PerformanceMonitor {
         let seq_bytes = sequence.to_be_bytes();
 
         data[0..8].copy_from_slice(&ts_bytes);
-        data[8..16].copy_from_slice(&seq_bytes);
+        data[8..12].copy_from_slice(&seq_bytes);
 
         BytesN::from_array(env, &data)
     }
@@ -338,3 +447,273 @@ pub struct OperationProfile {
     pub success: bool,
     pub efficiency_score: u32,
 }
+
+#[cfg(all(test, feature = "testutils"))]
+mod tests {
+    use super::*;
+    use crate::{storage::DataKey, Diagnostics, DiagnosticsClient};
+    use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, Env};
+
+    fn setup(env: &Env) -> DiagnosticsClient<'_> {
+        env.mock_all_auths();
+        env.ledger().with_mut(|li| li.timestamp = 10_000);
+
+        let client = DiagnosticsClient::new(env, &env.register(Diagnostics, ()));
+        let admin = Address::generate(env);
+        let config = DiagnosticsConfig {
+            admin: admin.clone(),
+            monitoring_enabled: true,
+            anomaly_detection_enabled: true,
+            prediction_enabled: false,
+            max_trace_duration: 3600,
+            metrics_retention_period: 86_400,
+            alert_threshold_cpu: 90,
+            alert_threshold_memory: u32::MAX,
+            alert_threshold_gas: u64::MAX,
+        };
+        client.initialize(&admin, &config);
+        client
+    }
+
+    fn monitoring_config() -> MonitoringConfig {
+        MonitoringConfig {
+            metrics_collection_interval: 60,
+            enable_real_time_alerts: false,
+            enable_predictive_analysis: false,
+            enable_behavior_tracking: false,
+            max_metrics_history: 10,
+        }
+    }
+
+    fn sample_metrics(
+        env: &Env,
+        contract_address: &Address,
+        error_rate: u32,
+    ) -> PerformanceMetrics {
+        PerformanceMetrics {
+            timestamp: env.ledger().timestamp(),
+            contract_address: contract_address.clone(),
+            execution_time: 100,
+            gas_used: 50_000,
+            memory_usage: 1_000,
+            storage_reads: 10,
+            storage_writes: 5,
+            cpu_utilization: 40,
+            cpu_instructions: 100_000,
+            transaction_count: 100,
+            error_count: error_rate,
+            error_rate,
+            average_execution_time: 100,
+            average_response_time: 100,
+            network_bandwidth: 1_000,
+            gas_consumption: 50_000,
+            storage_usage: 100,
+            peak_memory_usage: 1_000,
+            network_latency: 50,
+        }
+    }
+
+    fn monitor_with_error_rate(env: &Env, client: &DiagnosticsClient, error_rate: u32) -> Address {
+        let contract_address = Address::generate(env);
+        client.start_performance_monitoring(&contract_address, &monitoring_config());
+        client.record_performance_metrics(
+            &contract_address,
+            &sample_metrics(env, &contract_address, error_rate),
+        );
+        contract_address
+    }
+
+    fn record_samples(env: &Env, client: &DiagnosticsClient, sample_rate: u32) -> Address {
+        let contract_address = Address::generate(env);
+        client.start_performance_monitoring(&contract_address, &monitoring_config());
+        client.set_sample_rate(&contract_address, &sample_rate);
+        record_batch(env, client, &contract_address, 40);
+        contract_address
+    }
+
+    fn record_batch(env: &Env, client: &DiagnosticsClient, contract_address: &Address, count: u32) {
+        for _ in 0..count {
+            env.ledger().with_mut(|li| li.timestamp += 1);
+            client.record_performance_metrics(
+                contract_address,
+                &sample_metrics(env, contract_address, 2),
+            );
+        }
+    }
+
+    fn report_totals(
+        env: &Env,
+        client: &DiagnosticsClient,
+        contract_address: &Address,
+        end_time: u64,
+    ) -> Result<(u32, u64, u32), DiagnosticsError> {
+        env.as_contract(&client.address, || {
+            PerformanceMonitor::generate_performance_report(env, contract_address, 10_001, end_time)
+                .map(|report| (report.total_transactions, report.total_gas_used, report.error_rate))
+        })
+    }
+
+    #[test]
+    fn test_health_status_all_healthy() {
+        let env = Env::default();
+        let client = setup(&env);
+        monitor_with_error_rate(&env, &client, 0);
+        monitor_with_error_rate(&env, &client, 1);
+
+        let summary = client.get_health_status();
+        assert_eq!(summary.status, HealthStatus::Healthy);
+        assert_eq!(summary.contracts_checked, 2);
+        assert_eq!(summary.contract_address, None);
+    }
+
+    #[test]
+    fn test_health_status_dragged_to_warning() {
+        let env = Env::default();
+        let client = setup(&env);
+        monitor_with_error_rate(&env, &client, 0);
+        let degraded = monitor_with_error_rate(&env, &client, 8);
+
+        let summary = client.get_health_status();
+        assert_eq!(summary.status, HealthStatus::Warning);
+        assert_eq!(summary.reason, String::from_str(&env, "ERROR_RATE_HIGH"));
+        assert_eq!(summary.contract_address, Some(degraded));
+    }
+
+    #[test]
+    fn test_health_status_reports_worst_contract() {
+        let env = Env::default();
+        let client = setup(&env);
+        monitor_with_error_rate(&env, &client, 8);
+        let failing = monitor_with_error_rate(&env, &client, 40);
+        monitor_with_error_rate(&env, &client, 0);
+
+        let summary = client.get_health_status();
+        assert_eq!(summary.status, HealthStatus::Critical);
+        assert_eq!(summary.reason, String::from_str(&env, "ERROR_RATE_CRITICAL"));
+        assert_eq!(summary.contract_address, Some(failing));
+    }
+
+    #[test]
+    fn test_sampling_persists_fraction_but_counts_every_sample() {
+        let env = Env::default();
+        let client = setup(&env);
+        let contract_address = record_samples(&env, &client, 25);
+
+        let counters = client.get_metrics_counters(&contract_address);
+        assert_eq!(counters.samples_recorded, 40);
+        assert_eq!(counters.samples_stored, 10);
+        assert_eq!(counters.total_transactions, 4_000);
+        assert_eq!(counters.total_gas_used, 2_000_000);
+        assert_eq!(counters.total_errors, 80);
+
+        let stored = env.as_contract(&client.address, || {
+            (10_001..=10_040)
+                .filter(|ts| {
+                    DiagnosticsStorage::get_performance_metrics(&env, &contract_address, *ts)
+                        .is_some()
+                })
+                .count()
+        });
+        assert_eq!(stored, 10);
+
+        // The latest sample is still visible even when it was not persisted
+        let latest = client.get_performance_metrics(&contract_address);
+        assert_eq!(latest.timestamp, 10_040);
+    }
+
+    #[test]
+    fn test_performance_report_scales_sampled_totals() {
+        let env = Env::default();
+        let client = setup(&env);
+        let contract_address = record_samples(&env, &client, 25);
+
+        assert_eq!(
+            report_totals(&env, &client, &contract_address, 10_040),
+            Ok((4_000, 2_000_000, 2))
+        );
+    }
+
+    #[test]
+    fn test_performance_report_scales_each_sample_by_its_own_rate() {
+        let env = Env::default();
+        let client = setup(&env);
+        let contract_address = Address::generate(&env);
+
+        // 5 of the first 20 samples are stored at 25%, then 10 of the next 20 at 50%
+        client.start_performance_monitoring(&contract_address, &monitoring_config());
+        client.set_sample_rate(&contract_address, &25);
+        record_batch(&env, &client, &contract_address, 20);
+        client.set_sample_rate(&contract_address, &50);
+        record_batch(&env, &client, &contract_address, 20);
+
+        assert_eq!(client.get_metrics_counters(&contract_address).samples_stored, 15);
+        assert_eq!(
+            report_totals(&env, &client, &contract_address, 10_040),
+            Ok((4_000, 2_000_000, 2))
+        );
+    }
+
+    #[test]
+    fn test_performance_report_saturates_scaled_totals() {
+        let env = Env::default();
+        let client = setup(&env);
+        let contract_address = Address::generate(&env);
+        client.start_performance_monitoring(&contract_address, &monitoring_config());
+        client.set_sample_rate(&contract_address, &50);
+
+        for _ in 0..2 {
+            env.ledger().with_mut(|li| li.timestamp += 1);
+            let mut metrics = sample_metrics(&env, &contract_address, 0);
+            metrics.transaction_count = u32::MAX;
+            metrics.gas_used = u64::MAX;
+            client.record_performance_metrics(&contract_address, &metrics);
+        }
+
+        assert_eq!(
+            report_totals(&env, &client, &contract_address, 10_002),
+            Ok((u32::MAX, u64::MAX, 0))
+        );
+    }
+
+    #[test]
+    fn test_legacy_metrics_slot_is_read_for_its_own_sample_only() {
+        let env = Env::default();
+        let client = setup(&env);
+        let contract_address = Address::generate(&env);
+        let other_address = Address::generate(&env);
+        let metrics = sample_metrics(&env, &contract_address, 2);
+
+        // Before per-sample keys, history was one shared slot under the bare symbol
+        env.as_contract(&client.address, || {
+            let key = DataKey::PerformanceMetrics(contract_address.clone(), metrics.timestamp);
+            env.storage().persistent().set(&key.to_symbol(&env), &metrics);
+
+            let legacy =
+                DiagnosticsStorage::get_performance_metrics(&env, &contract_address, 10_000);
+            assert_eq!(legacy.map(|m| m.timestamp), Some(10_000));
+            assert!(DiagnosticsStorage::get_performance_metrics(&env, &contract_address, 10_001)
+                .is_none());
+            assert!(
+                DiagnosticsStorage::get_performance_metrics(&env, &other_address, 10_000).is_none()
+            );
+        });
+    }
+
+    #[test]
+    fn test_sample_rate_must_be_a_percentage() {
+        let env = Env::default();
+        let client = setup(&env);
+        let contract_address = Address::generate(&env);
+
+        // Contracts without a configured rate persist every sample
+        assert_eq!(client.get_sample_rate(&contract_address), 100);
+
+        for sample_rate in [0, 101] {
+            let result = client.try_set_sample_rate(&contract_address, &sample_rate);
+            assert_eq!(result, Err(Ok(DiagnosticsError::InvalidConfiguration)));
+        }
+
+        client.set_sample_rate(&contract_address, &25);
+        assert_eq!(client.get_sample_rate(&contract_address), 25);
+    }
+}
@@ -445,12 +445,13 @@ impl PredictiveEngine {
         data.iter().map(|m| m.memory_usage).sum::<u32>().checked_div(data.len()).unwrap_or(0)
     }
 
+    /// Per-sample average, so a sampled (thinned) history doesn't shrink the estimate
     fn calculate_average_transactions_per_hour(data: &Vec<PerformanceMetrics>) -> u32 {
         if data.is_empty() {
             return 0;
         }
-        data.iter().map(|m| m.transaction_count).sum::<u32>()
-            / data.len().checked_div(data.len()).unwrap_or(0)
+        let total = data.iter().map(|m| m.transaction_count as u64).sum::<u64>();
+        u32::try_from(total / data.len() as u64).unwrap_or(u32::MAX)
     }
 
     fn calculate_average_gas_usage(data: &Vec<PerformanceMetrics>) -> u64 {
@@ -460,12 +461,13 @@ impl PredictiveEngine {
         data.iter().map(|m| m.gas_used).sum::<u64>().checked_div(data.len() as u64).unwrap_or(0)
     }
 
+    /// Per-sample average, so a sampled (thinned) history doesn't shrink the estimate
     fn calculate_average_storage_usage(data: &Vec<PerformanceMetrics>) -> u32 {
         if data.is_empty() {
             return 0;
         }
-        data.iter().map(|m| m.storage_reads + m.storage_writes).sum::<u32>()
-            / data.len().checked_div(data.len()).unwrap_or(0)
+        let total = data.iter().map(|m| (m.storage_reads + m.storage_writes) as u64).sum::<u64>();
+        u32::try_from(total / data.len() as u64).unwrap_or(u32::MAX)
     }
 
     fn calculate_growth_rate(data: &Vec<PerformanceMetrics>) -> f64 {
@@ -796,12 +796,14 @@ impl ResourceOptimizer {
     fn perform_cost_analysis(env: &Env, metrics: &Vec<PerformanceMetrics>) -> CostAnalysis {
         let gas_costs = Self::analyze_gas_costs(metrics);
         let storage_costs = Self::calculate_storage_costs(metrics);
-        let total_operations = if !metrics.is_empty() {
-            let mut total = 0u32;
+        // Costs are per-sample averages, so operations must be too; a raw sum
+        // would shrink with the sample rate instead of tracking real load.
+        let average_operations = if !metrics.is_empty() {
+            let mut total = 0u64;
             for i in 0..metrics.len() {
-                total += metrics.get(i).unwrap().transaction_count;
+                total += metrics.get(i).unwrap().transaction_count as u64;
             }
-            total
+            (total / metrics.len() as u64).max(1)
         } else {
             1
         };
@@ -819,7 +821,7 @@ impl ResourceOptimizer {
 
         CostAnalysis {
             total_cost: gas_costs + storage_costs,
-            cost_per_transaction: (gas_costs + storage_costs) / total_operations as u64,
+            cost_per_transaction: (gas_costs + storage_costs) / average_operations,
             cost_breakdown: Vec::new(env),
             cost_efficiency: Self::calculate_cost_efficiency_score(metrics) as u32,
             cost_trend: Self::analyze_cost_trend(metrics),
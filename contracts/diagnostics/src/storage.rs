@@ -17,6 +17,10 @@ pub enum DataKey {
     RegressionReports(String),         // test_name
     SystemHealth,
     MonitoredContracts,
+    LastRecordedMetrics(Address),    // contract
+    MetricsCounters(Address),        // contract
+    MetricsSampleRate(Address, u64), // (contract, timestamp)
+    SampleRate(Address),             // contract
 }
 
 impl DataKey {
@@ -36,6 +40,10 @@ impl DataKey {
             DataKey::RegressionReports(_name) => Symbol::new(env, "reg_rep"),
             DataKey::SystemHealth => Symbol::new(env, "sys_health"),
             DataKey::MonitoredContracts => Symbol::new(env, "monitored"),
+            DataKey::LastRecordedMetrics(_addr) => Symbol::new(env, "perf_last"),
+            DataKey::MetricsCounters(_addr) => Symbol::new(env, "perf_cnt"),
+            DataKey::MetricsSampleRate(_addr, _ts) => Symbol::new(env, "perf_rate"),
+            DataKey::SampleRate(_addr) => Symbol::new(env, "smpl_rate"),
         }
     }
 }
@@ -70,42 +78,102 @@ impl DiagnosticsStorage {
             .ok_or(DiagnosticsError::ConfigNotSet)
     }
 
-    /// Store performance metrics
+    /// Store a performance metrics sample in the contract's history
+    ///
+    /// The sample rate in effect is kept next to the sample so history can be
+    /// scaled correctly after the contract's rate changes.
     pub fn store_performance_metrics(
         env: &Env,
         contract_address: &Address,
         metrics: &PerformanceMetrics,
+        sample_rate: u32,
     ) {
         let key = DataKey::PerformanceMetrics(contract_address.clone(), metrics.timestamp);
-        env.storage().persistent().set(&key.to_symbol(env), metrics);
+        env.storage()
+            .persistent()
+            .set(&(key.to_symbol(env), contract_address.clone(), metrics.timestamp), metrics);
+
+        let key = DataKey::MetricsSampleRate(contract_address.clone(), metrics.timestamp);
+        env.storage()
+            .persistent()
+            .set(&(key.to_symbol(env), contract_address.clone(), metrics.timestamp), &sample_rate);
+    }
+
+    /// Get the sample rate a stored sample was recorded under (100 for unsampled history)
+    pub fn get_metrics_sample_rate(env: &Env, contract_address: &Address, timestamp: u64) -> u32 {
+        let key = DataKey::MetricsSampleRate(contract_address.clone(), timestamp);
+        env.storage()
+            .persistent()
+            .get(&(key.to_symbol(env), contract_address.clone(), timestamp))
+            .filter(|rate| *rate > 0)
+            .unwrap_or(100)
+    }
+
+    /// Remember the most recent metrics for a contract, whether or not it was sampled
+    pub fn set_last_recorded_metrics(
+        env: &Env,
+        contract_address: &Address,
+        metrics: &PerformanceMetrics,
+    ) {
+        let key = DataKey::LastRecordedMetrics(contract_address.clone());
+        env.storage().persistent().set(&(key.to_symbol(env), contract_address.clone()), metrics);
+    }
+
+    /// Get the most recently recorded metrics for a contract with a single read
+    pub fn get_last_recorded_metrics(
+        env: &Env,
+        contract_address: &Address,
+    ) -> Option<PerformanceMetrics> {
+        let key = DataKey::LastRecordedMetrics(contract_address.clone());
+        env.storage().persistent().get(&(key.to_symbol(env), contract_address.clone()))
     }
 
     /// Get performance metrics for a contract at a specific time
+    ///
+    /// History used to be written under the bare `perf` symbol, a single slot
+    /// every contract and timestamp overwrote. That slot is still read as a
+    /// fallback, but only when it holds this contract's sample at `timestamp`.
     pub fn get_performance_metrics(
         env: &Env,
         contract_address: &Address,
         timestamp: u64,
     ) -> Option<PerformanceMetrics> {
         let key = DataKey::PerformanceMetrics(contract_address.clone(), timestamp);
-        env.storage().persistent().get(&key.to_symbol(env))
+        let symbol = key.to_symbol(env);
+        env.storage()
+            .persistent()
+            .get(&(symbol.clone(), contract_address.clone(), timestamp))
+            .or_else(|| {
+                env.storage().persistent().get(&symbol).filter(|metrics: &PerformanceMetrics| {
+                    metrics.contract_address == *contract_address && metrics.timestamp == timestamp
+                })
+            })
     }
 
     /// Get latest performance metrics for a contract
+    ///
+    /// Sampling can leave the newest snapshot out of history, so this reads
+    /// the last recorded metrics rather than scanning stored timestamps.
     pub fn get_latest_performance_metrics(
         env: &Env,
         contract_address: &Address,
     ) -> Option<PerformanceMetrics> {
-        // In a real implementation, we would iterate through recent timestamps
-        // For now, we'll use current timestamp minus some offset
-        let current_time = env.ledger().timestamp();
-        for i in 0..3600 {
-            // Check last hour in second intervals
-            let timestamp = current_time - i;
-            if let Some(metrics) = Self::get_performance_metrics(env, contract_address, timestamp) {
-                return Some(metrics);
-            }
-        }
-        None
+        Self::get_last_recorded_metrics(env, contract_address)
+    }
+
+    /// Set running metrics counters for a contract
+    pub fn set_metrics_counters(env: &Env, contract_address: &Address, counters: &MetricsCounters) {
+        let key = DataKey::MetricsCounters(contract_address.clone());
+        env.storage().persistent().set(&(key.to_symbol(env), contract_address.clone()), counters);
+    }
+
+    /// Get running metrics counters for a contract
+    pub fn get_metrics_counters(env: &Env, contract_address: &Address) -> MetricsCounters {
+        let key = DataKey::MetricsCounters(contract_address.clone());
+        env.storage()
+            .persistent()
+            .get(&(key.to_symbol(env), contract_address.clone()))
+            .unwrap_or_default()
     }
 
     /// Set monitoring configuration for a contract
@@ -123,6 +191,24 @@ impl DiagnosticsStorage {
         env.storage().persistent().get(&key.to_symbol(env))
     }
 
+    /// Set the percentage of recorded samples persisted to history for a contract
+    pub fn set_sample_rate(env: &Env, contract_address: &Address, sample_rate: u32) {
+        let key = DataKey::SampleRate(contract_address.clone());
+        env.storage()
+            .persistent()
+            .set(&(key.to_symbol(env), contract_address.clone()), &sample_rate);
+    }
+
+    /// Get the sample rate configured for a contract (100 when unconfigured)
+    pub fn get_sample_rate(env: &Env, contract_address: &Address) -> u32 {
+        let key = DataKey::SampleRate(contract_address.clone());
+        env.storage()
+            .persistent()
+            .get(&(key.to_symbol(env), contract_address.clone()))
+            .filter(|rate| *rate > 0)
+            .unwrap_or(100)
+    }
+
     /// Store capacity prediction
     pub fn store_capacity_prediction(
         env: &Env,
@@ -32,6 +32,17 @@ pub struct MonitoringConfig {
     pub max_metrics_history: u32,
 }
 
+/// Running totals over every recorded sample, including ones not persisted
+#[derive(Clone, Debug, Default)]
+#[contracttype]
+pub struct MetricsCounters {
+    pub samples_recorded: u64,
+    pub samples_stored: u64,
+    pub total_transactions: u64,
+    pub total_gas_used: u64,
+    pub total_errors: u64,
+}
+
 /// Real-time performance metrics
 #[derive(Clone, Debug)]
 #[contracttype]
@@ -838,7 +849,7 @@ pub struct SystemHealthReport {
 }
 
 /// Health status levels
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 #[contracttype]
 pub enum HealthStatus {
     Healthy,
@@ -847,6 +858,17 @@ pub enum HealthStatus {
     Unknown,
 }
 
+/// Aggregate pass/fail health across all monitored contracts, for alerting
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct HealthStatusSummary {
+    pub status: HealthStatus,
+    pub reason: String,
+    /// Contract responsible for the worst status, if any
+    pub contract_address: Option<Address>,
+    pub contracts_checked: u32,
+}
+
 /// Individual contract health
 #[derive(Clone, Debug)]
 #[contracttype]
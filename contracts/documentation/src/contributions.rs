@@ -51,6 +51,10 @@ impl ContributionManager {
             .get(&DataKey::Contribution(contribution_id.clone()))
             .ok_or(Error::ContributionNotFound)?;
 
+        if status == ContributionStatus::Approved {
+            Storage::add_document_contributor(env, &contribution.doc_id, &contribution.contributor);
+        }
+
         contribution.status = status;
         contribution.reviewed_by = Some(reviewer.clone());
         contribution.review_notes = notes;
@@ -352,6 +352,22 @@ impl DocumentationContract {
         VersionManager::get_current_version(&env, doc_id)
     }
 
+    /// Return the addresses credited on a document, without duplicates.
+    ///
+    /// A contributor is added when one of their contributions is approved or when
+    /// they author a new version of the document.
+    ///
+    /// # Arguments
+    /// * `doc_id` - Identifier of the document.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let contributors = client.get_document_contributors(&doc_id);
+    /// ```
+    pub fn get_document_contributors(env: Env, doc_id: String) -> Vec<Address> {
+        Storage::get_document_contributors(&env, &doc_id)
+    }
+
     // ========================================================================
     // Knowledge Base
     // ========================================================================
@@ -36,4 +36,21 @@ impl Storage {
         docs.push_back(doc_id.clone());
         env.storage().persistent().set(&key, &docs);
     }
+
+    pub fn add_document_contributor(env: &Env, doc_id: &String, contributor: &Address) {
+        let key = DataKey::DocumentContributors(doc_id.clone());
+        let mut contributors: Vec<Address> =
+            env.storage().persistent().get(&key).unwrap_or(Vec::new(env));
+        if !contributors.contains(contributor) {
+            contributors.push_back(contributor.clone());
+            env.storage().persistent().set(&key, &contributors);
+        }
+    }
+
+    pub fn get_document_contributors(env: &Env, doc_id: &String) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::DocumentContributors(doc_id.clone()))
+            .unwrap_or(Vec::new(env))
+    }
 }
@@ -1129,6 +1129,79 @@ fn test_get_contribution() {
     assert!(client.get_contribution(&String::from_str(&env, "nope")).is_none());
 }
 
+#[test]
+fn test_document_contributors_grow_without_duplicates() {
+    let (env, admin, user1, user2) = create_test_env();
+    let client = setup_contract(&env, &admin);
+
+    let doc_id = String::from_str(&env, "doc-credits");
+    client.create_document(
+        &user1,
+        &doc_id,
+        &String::from_str(&env, "Title"),
+        &String::from_str(&env, "This is test content for documentation"),
+        &DocumentType::Guide,
+        &String::from_str(&env, "cat"),
+        &Vec::new(&env),
+        &String::from_str(&env, "en"),
+    );
+    assert_eq!(client.get_document_contributors(&doc_id).len(), 0);
+
+    // Version authors are credited
+    client.create_version(
+        &user1,
+        &doc_id,
+        &1,
+        &String::from_str(&env, "V1"),
+        &String::from_str(&env, "First"),
+    );
+    client.create_version(
+        &user1,
+        &doc_id,
+        &2,
+        &String::from_str(&env, "V2"),
+        &String::from_str(&env, "Second"),
+    );
+    assert_eq!(client.get_document_contributors(&doc_id).len(), 1);
+
+    // Approved contributions are credited once per contributor
+    for id in ["contrib-a", "contrib-b"] {
+        let contribution_id = String::from_str(&env, id);
+        client.submit_contribution(
+            &user2,
+            &contribution_id,
+            &doc_id,
+            &ContributionType::Correction,
+            &String::from_str(&env, "Fix typo"),
+        );
+        client.review_contribution(&admin, &contribution_id, &ContributionStatus::Approved, &None);
+    }
+
+    let contributors = client.get_document_contributors(&doc_id);
+    assert_eq!(contributors.len(), 2);
+    assert_eq!(contributors.get(0), Some(user1));
+    assert_eq!(contributors.get(1), Some(user2));
+}
+
+#[test]
+fn test_rejected_contribution_not_credited() {
+    let (env, admin, user1, _) = create_test_env();
+    let client = setup_contract(&env, &admin);
+
+    let doc_id = String::from_str(&env, "doc-rej");
+    let contribution_id = String::from_str(&env, "contrib-rej-credit");
+    client.submit_contribution(
+        &user1,
+        &contribution_id,
+        &doc_id,
+        &ContributionType::Edit,
+        &String::from_str(&env, "content"),
+    );
+    client.review_contribution(&admin, &contribution_id, &ContributionStatus::Rejected, &None);
+
+    assert_eq!(client.get_document_contributors(&doc_id).len(), 0);
+}
+
 // ============================================================================
 // Translation Tests
 // ============================================================================
@@ -519,6 +519,8 @@ pub enum DataKey {
     UserContributions(Address),
     /// List of document IDs authored by a user.
     DocumentsByAuthor(Address),
+    /// Deduplicated list of addresses that contributed to a document.
+    DocumentContributors(String),
     /// Running total of documents in the system.
     TotalDocuments,
     /// Running total of document views across the system.
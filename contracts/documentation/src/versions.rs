@@ -1,3 +1,4 @@
+use crate::storage::Storage;
 use crate::types::*;
 use soroban_sdk::{Address, Env, String};
 
@@ -57,7 +58,10 @@ impl VersionManager {
             }
         }
 
-        env.storage().persistent().set(&DataKey::DocumentVersion(doc_id, version_number), &version);
+        env.storage()
+            .persistent()
+            .set(&DataKey::DocumentVersion(doc_id.clone(), version_number), &version);
+        Storage::add_document_contributor(env, &doc_id, author);
 
         Ok(version)
     }
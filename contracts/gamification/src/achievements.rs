@@ -6,13 +6,16 @@ use crate::storage::GamificationStorage;
 use crate::types::{
     Achievement, AchievementCategory, AchievementRequirements, AchievementTier, ActivityRecord,
     ActivityType, AdaptiveDifficulty, ChallengeDifficulty, GamificationKey, GamificationProfile,
-    UserAchievement,
+    SkillNode, SkillTreeProgress, UserAchievement,
 };
 use shared::timestamp_utils::utc_day_index;
 
 /// First 25 IDs are reserved for milestone achievements seeded at init.
 const MILESTONE_RESERVE: u64 = 25;
 
+/// Fixed-point scale used when compounding reward decay.
+const DECAY_SCALE: i128 = 1_000_000_000;
+
 pub struct AchievementManager;
 
 impl AchievementManager {
@@ -70,12 +73,14 @@ impl AchievementManager {
         // ── 3. Streak update ──────────────────────────────────────────────
         let streak_bonus_xp = Self::update_streak(env, &mut profile, activity.timestamp, &config);
 
-        // ── 4. Season multiplier ──────────────────────────────────────────
+        // ── 4. Season & guild multipliers ─────────────────────────────────
         let season_mult = crate::seasons::SeasonManager::get_xp_multiplier(env);
         let base_with_season = (base_xp + streak_bonus_xp) * season_mult / 100;
+        let guild_mult = crate::guilds::GuildManager::get_member_xp_multiplier(env, user);
+        let base_with_guild = base_with_season * guild_mult / 100;
 
         // ── 5. Final XP accumulation ──────────────────────────────────────
-        let final_xp = base_with_season;
+        let final_xp = base_with_guild;
         profile.total_xp += final_xp;
         profile.last_activity = activity.timestamp;
         let prev_level = profile.level;
@@ -149,7 +154,9 @@ impl AchievementManager {
                     continue;
                 }
 
-                if Self::meets_requirements(env, user, profile, &ach.requirements) {
+                if Self::meets_requirements(env, user, profile, &ach.requirements)
+                    && Self::prerequisites_met(env, user, id)
+                {
                     let ua = UserAchievement {
                         user: user.clone(),
                         achievement_id: id,
@@ -177,6 +184,80 @@ impl AchievementManager {
         awarded
     }
 
+    // ── Skill tree ─────────────────────────────────────────────────────────
+
+    /// Define (or replace) the skill-tree node for an achievement. Prerequisites
+    /// must be earlier achievements, which keeps the tree acyclic and lets a
+    /// whole chain unlock in a single achievement check.
+    pub fn set_skill_node(
+        env: &Env,
+        achievement_id: u64,
+        prerequisites: Vec<u64>,
+    ) -> Result<(), Error> {
+        if !env.storage().persistent().has(&GamificationKey::Achievement(achievement_id)) {
+            return Err(Error::NotFound);
+        }
+        for prereq in prerequisites.iter() {
+            if prereq >= achievement_id {
+                return Err(Error::InvalidInput);
+            }
+            if !env.storage().persistent().has(&GamificationKey::Achievement(prereq)) {
+                return Err(Error::NotFound);
+            }
+        }
+
+        let node = SkillNode { achievement_id, prerequisites };
+        env.storage().persistent().set(&GamificationKey::SkillNode(achievement_id), &node);
+
+        let mut nodes = Self::get_skill_tree_nodes(env);
+        if !nodes.contains(achievement_id) {
+            nodes.push_back(achievement_id);
+            env.storage().persistent().set(&GamificationKey::SkillTreeNodes, &nodes);
+        }
+        Ok(())
+    }
+
+    pub fn get_skill_node(env: &Env, achievement_id: u64) -> Option<SkillNode> {
+        env.storage().persistent().get(&GamificationKey::SkillNode(achievement_id))
+    }
+
+    pub fn get_skill_tree_progress(env: &Env, user: &Address) -> SkillTreeProgress {
+        let nodes = Self::get_skill_tree_nodes(env);
+        let mut unlocked_nodes = Vec::new(env);
+        let mut available_nodes = Vec::new(env);
+
+        for id in nodes.iter() {
+            if Self::has_earned(env, user, id) {
+                unlocked_nodes.push_back(id);
+            } else if Self::prerequisites_met(env, user, id) {
+                available_nodes.push_back(id);
+            }
+        }
+
+        SkillTreeProgress { total_nodes: nodes.len(), unlocked_nodes, available_nodes }
+    }
+
+    fn get_skill_tree_nodes(env: &Env) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&GamificationKey::SkillTreeNodes)
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// Achievements outside the skill tree have no prerequisites.
+    fn prerequisites_met(env: &Env, user: &Address, achievement_id: u64) -> bool {
+        match Self::get_skill_node(env, achievement_id) {
+            Some(node) => node.prerequisites.iter().all(|p| Self::has_earned(env, user, p)),
+            None => true,
+        }
+    }
+
+    fn has_earned(env: &Env, user: &Address, achievement_id: u64) -> bool {
+        env.storage()
+            .persistent()
+            .has(&GamificationKey::UserAchievement(user.clone(), achievement_id))
+    }
+
     // ── Claim token reward ─────────────────────────────────────────────────
 
     pub fn claim_reward(env: &Env, user: &Address, achievement_id: u64) -> Result<i128, Error> {
@@ -188,6 +269,7 @@ impl AchievementManager {
             return Err(Error::AchievementAlreadyClaimed);
         }
 
+        ua.token_reward = Self::decayed_reward(env, user, ua.token_reward);
         ua.token_reward_claimed = true;
         env.storage().persistent().set(&key, &ua);
 
@@ -200,8 +282,44 @@ impl AchievementManager {
         Ok(ua.token_reward)
     }
 
+    // ── Reward decay ───────────────────────────────────────────────────────
+
+    /// Amount of an unclaimed `reward` still claimable by `user`, after
+    /// `decay_percent` is compounded for every full inactivity period elapsed
+    /// since their last activity.
+    ///
+    /// Nothing is written; the decayed amount is only persisted on claim.
+    pub fn decayed_reward(env: &Env, user: &Address, reward: i128) -> i128 {
+        let policy = match GamificationStorage::get_reward_decay_policy(env) {
+            Some(p) => p,
+            None => return reward,
+        };
+
+        let last_activity = GamificationStorage::get_profile(env, user).last_activity;
+        let now = env.ledger().timestamp();
+        if reward <= 0 || last_activity == 0 || now <= last_activity {
+            return reward;
+        }
+        let periods = (now - last_activity) / policy.inactivity_period;
+
+        // keep^periods by repeated squaring, in DECAY_SCALE fixed point
+        let mut base = (100 - policy.decay_percent.min(100) as i128) * DECAY_SCALE / 100;
+        let mut factor = DECAY_SCALE;
+        let mut n = periods;
+        while n > 0 && factor > 0 {
+            if n & 1 == 1 {
+                factor = factor * base / DECAY_SCALE;
+            }
+            base = base * base / DECAY_SCALE;
+            n >>= 1;
+        }
+        reward * factor / DECAY_SCALE
+    }
+
     // ── User achievement list ──────────────────────────────────────────────
 
+    /// Earned achievements, with unclaimed token rewards shown at their
+    /// currently claimable (decayed) amount.
     pub fn get_user_achievements(env: &Env, user: &Address) -> Vec<UserAchievement> {
         let ids: Vec<u64> = env
             .storage()
@@ -212,9 +330,12 @@ impl AchievementManager {
         let mut out = Vec::new(env);
         for id in ids.iter() {
             let key = GamificationKey::UserAchievement(user.clone(), id);
-            if let Some(ua) =
+            if let Some(mut ua) =
                 env.storage().persistent().get::<GamificationKey, UserAchievement>(&key)
             {
+                if !ua.token_reward_claimed {
+                    ua.token_reward = Self::decayed_reward(env, user, ua.token_reward);
+                }
                 out.push_back(ua);
             }
         }
@@ -3,15 +3,26 @@ use soroban_sdk::{Address, Env, Vec};
 use crate::errors::Error;
 use crate::events::GamificationEvents;
 use crate::storage::GamificationStorage;
-use crate::types::{Challenge, GamificationKey, UserChallenge};
+use crate::types::{
+    Challenge, ChallengeMode, GamificationKey, GuildMember, TeamCompletion, UserChallenge,
+};
 use shared::validation::CoreValidator;
 
+/// Most team members rewarded in a single call; the rest are paid by
+/// [`ChallengeManager::settle_team_payouts`].
+pub const MAX_TEAM_PAYOUTS_PER_CALL: u32 = 10;
+
 pub struct ChallengeManager;
 
 impl ChallengeManager {
     // ── Create ─────────────────────────────────────────────────────────────
 
-    pub fn create(env: &Env, creator: &Address, mut challenge: Challenge) -> Result<u64, Error> {
+    pub fn create(
+        env: &Env,
+        creator: &Address,
+        mut challenge: Challenge,
+        mode: ChallengeMode,
+    ) -> Result<u64, Error> {
         if challenge.target_progress == 0 {
             return Err(Error::InvalidInput);
         }
@@ -27,6 +38,9 @@ impl ChallengeManager {
         challenge.is_active = true;
 
         env.storage().persistent().set(&GamificationKey::Challenge(id), &challenge);
+        if mode == ChallengeMode::Team {
+            env.storage().persistent().set(&GamificationKey::ChallengeModeOf(id), &mode);
+        }
 
         // Add to active-challenges list
         let active_key = GamificationKey::ActiveChallenges;
@@ -84,6 +98,34 @@ impl ChallengeManager {
             return Err(Error::AlreadyJoinedChallenge);
         }
 
+        // Team mode: the user's guild is their team
+        if Self::get_mode(env, challenge_id) == ChallengeMode::Team {
+            let member: GuildMember = env
+                .storage()
+                .persistent()
+                .get(&GamificationKey::GuildMember(user.clone()))
+                .ok_or(Error::NotInGuild)?;
+            let team_id = member.guild_id;
+
+            let pooled: u32 = env
+                .storage()
+                .persistent()
+                .get(&GamificationKey::TeamChallengeProgress(challenge_id, team_id))
+                .unwrap_or(0);
+            if pooled >= challenge.target_progress {
+                return Err(Error::TeamChallengeCompleted);
+            }
+
+            let members_key = GamificationKey::TeamChallengeMembers(challenge_id, team_id);
+            let mut members: Vec<Address> =
+                env.storage().persistent().get(&members_key).unwrap_or_else(|| Vec::new(env));
+            members.push_back(user.clone());
+            env.storage().persistent().set(&members_key, &members);
+            env.storage()
+                .persistent()
+                .set(&GamificationKey::ChallengeTeam(user.clone(), challenge_id), &team_id);
+        }
+
         let uc = UserChallenge {
             user: user.clone(),
             challenge_id,
@@ -147,64 +189,206 @@ impl ChallengeManager {
             return Ok(true); // already done
         }
 
+        if Self::get_mode(env, challenge_id) == ChallengeMode::Team {
+            return Self::update_team_progress(env, &challenge, uc_key, uc, progress);
+        }
+
         uc.current_progress = progress.min(challenge.target_progress);
         let completed = uc.current_progress >= challenge.target_progress;
 
         if completed {
             uc.completed = true;
             uc.completed_at = now;
+            uc.rank = Self::next_completion_rank(env, challenge_id);
+            Self::grant_reward(env, user, &challenge, uc.rank, true);
+        }
 
-            // Rank = position among completions + 1
-            let count_key = GamificationKey::ChallengeCompletionCount(challenge_id);
-            let count: u32 = env.storage().persistent().get(&count_key).unwrap_or(0u32);
-            let new_count = count + 1;
-            uc.rank = new_count;
-            env.storage().persistent().set(&count_key, &new_count);
-
-            // Award XP to user profile
-            let mut profile = GamificationStorage::get_profile(env, user);
-            profile.total_xp += challenge.xp_reward;
-            profile.challenges_completed += 1;
-            let new_level =
-                crate::achievements::AchievementManager::calculate_level(profile.total_xp);
-            let leveled_up = new_level > profile.level;
-            profile.level = new_level;
-            GamificationStorage::set_profile(env, user, &profile);
-
-            if leveled_up {
-                GamificationEvents::emit_level_up(env, user, profile.level);
+        env.storage().persistent().set(&uc_key, &uc);
+        Ok(completed)
+    }
+
+    /// Record the caller's contribution to their team's pooled progress.
+    ///
+    /// When the pool reaches the target the team is recorded as complete, the
+    /// caller is rewarded, and up to [`MAX_TEAM_PAYOUTS_PER_CALL`] teammates
+    /// are rewarded with them. A member who is still unpaid is rewarded on
+    /// their own next update or by [`Self::settle_team_payouts`].
+    fn update_team_progress(
+        env: &Env,
+        challenge: &Challenge,
+        uc_key: GamificationKey,
+        mut uc: UserChallenge,
+        progress: u32,
+    ) -> Result<bool, Error> {
+        let challenge_id = challenge.id;
+        let team_id = Self::get_team(env, &uc.user, challenge_id);
+        let completion_key = GamificationKey::TeamChallengeCompletion(challenge_id, team_id);
+
+        if let Some(completion) =
+            env.storage().persistent().get::<GamificationKey, TeamCompletion>(&completion_key)
+        {
+            Self::pay_member(env, challenge, &uc_key, uc, &completion);
+            return Ok(true);
+        }
+
+        let pool_key = GamificationKey::TeamChallengeProgress(challenge_id, team_id);
+        let pooled: u32 = env.storage().persistent().get(&pool_key).unwrap_or(0);
+
+        let contribution = progress.min(challenge.target_progress);
+        let pooled = (pooled - uc.current_progress + contribution).min(challenge.target_progress);
+        uc.current_progress = contribution;
+        env.storage().persistent().set(&pool_key, &pooled);
+
+        if pooled < challenge.target_progress {
+            env.storage().persistent().set(&uc_key, &uc);
+            return Ok(false);
+        }
+
+        // The whole team shares one completion rank
+        let completion = TeamCompletion {
+            rank: Self::next_completion_rank(env, challenge_id),
+            completed_at: env.ledger().timestamp(),
+            next_member: 0,
+        };
+        env.storage().persistent().set(&completion_key, &completion);
+
+        Self::pay_member(env, challenge, &uc_key, uc, &completion);
+        Self::pay_team_batch(env, challenge, team_id);
+        Ok(true)
+    }
+
+    /// Reward the next batch of unpaid members of a completed team.
+    ///
+    /// Returns the number of members not yet reached by the payout cursor.
+    pub fn settle_team_payouts(env: &Env, challenge_id: u64, team_id: u64) -> Result<u32, Error> {
+        let challenge: Challenge = env
+            .storage()
+            .persistent()
+            .get(&GamificationKey::Challenge(challenge_id))
+            .ok_or(Error::NotFound)?;
+        if !env
+            .storage()
+            .persistent()
+            .has(&GamificationKey::TeamChallengeCompletion(challenge_id, team_id))
+        {
+            return Err(Error::InvalidInput);
+        }
+        Ok(Self::pay_team_batch(env, &challenge, team_id))
+    }
+
+    /// Walk up to [`MAX_TEAM_PAYOUTS_PER_CALL`] members from the payout
+    /// cursor, rewarding any who are still unpaid, and return how many
+    /// members remain after the cursor.
+    fn pay_team_batch(env: &Env, challenge: &Challenge, team_id: u64) -> u32 {
+        let challenge_id = challenge.id;
+        let completion_key = GamificationKey::TeamChallengeCompletion(challenge_id, team_id);
+        let Some(mut completion) =
+            env.storage().persistent().get::<GamificationKey, TeamCompletion>(&completion_key)
+        else {
+            return 0;
+        };
+        let members: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&GamificationKey::TeamChallengeMembers(challenge_id, team_id))
+            .unwrap_or_else(|| Vec::new(env));
+
+        let end = members.len().min(completion.next_member + MAX_TEAM_PAYOUTS_PER_CALL);
+        for i in completion.next_member..end {
+            let member = members.get_unchecked(i);
+            let member_key = GamificationKey::UserChallenge(member, challenge_id);
+            if let Some(member_uc) =
+                env.storage().persistent().get::<GamificationKey, UserChallenge>(&member_key)
+            {
+                Self::pay_member(env, challenge, &member_key, member_uc, &completion);
             }
-            GamificationEvents::emit_xp_earned(env, user, challenge.xp_reward);
+        }
 
-            // Guild contribution
-            crate::guilds::GuildManager::add_contribution(env, user, challenge.xp_reward);
+        completion.next_member = end;
+        env.storage().persistent().set(&completion_key, &completion);
+        members.len() - end
+    }
 
-            // Reputation: innovation points
-            crate::reputation::ReputationManager::add_innovation_points(
-                env,
-                user,
-                challenge.xp_reward / 10,
-            );
+    /// Mark one team member complete and grant their reward, unless they
+    /// have already been paid.
+    ///
+    /// Achievement scans are left to the member's next recorded activity so
+    /// a batch of payouts stays within the transaction footprint.
+    fn pay_member(
+        env: &Env,
+        challenge: &Challenge,
+        uc_key: &GamificationKey,
+        mut uc: UserChallenge,
+        completion: &TeamCompletion,
+    ) {
+        if uc.reward_claimed {
+            return;
+        }
+        uc.completed = true;
+        uc.completed_at = completion.completed_at;
+        uc.rank = completion.rank;
+        uc.reward_claimed = true;
+        env.storage().persistent().set(uc_key, &uc);
+        Self::grant_reward(env, &uc.user, challenge, completion.rank, false);
+    }
+
+    /// Bump the challenge's completion counter and return the new rank.
+    fn next_completion_rank(env: &Env, challenge_id: u64) -> u32 {
+        let count_key = GamificationKey::ChallengeCompletionCount(challenge_id);
+        let count: u32 = env.storage().persistent().get(&count_key).unwrap_or(0u32);
+        let new_count = count + 1;
+        env.storage().persistent().set(&count_key, &new_count);
+        new_count
+    }
+
+    /// Grant a completion's XP, guild, reputation, leaderboard, achievement
+    /// and adaptive difficulty effects.
+    fn grant_reward(
+        env: &Env,
+        user: &Address,
+        challenge: &Challenge,
+        rank: u32,
+        check_achievements: bool,
+    ) {
+        // Award XP to user profile
+        let mut profile = GamificationStorage::get_profile(env, user);
+        profile.total_xp += challenge.xp_reward;
+        profile.challenges_completed += 1;
+        let new_level = crate::achievements::AchievementManager::calculate_level(profile.total_xp);
+        let leveled_up = new_level > profile.level;
+        profile.level = new_level;
+        GamificationStorage::set_profile(env, user, &profile);
+
+        if leveled_up {
+            GamificationEvents::emit_level_up(env, user, profile.level);
+        }
+        GamificationEvents::emit_xp_earned(env, user, challenge.xp_reward);
+
+        // Guild contribution
+        crate::guilds::GuildManager::add_contribution(env, user, challenge.xp_reward);
+
+        // Reputation: innovation points
+        crate::reputation::ReputationManager::add_innovation_points(
+            env,
+            user,
+            challenge.xp_reward / 10,
+        );
 
-            // Update leaderboards
-            let updated = GamificationStorage::get_profile(env, user);
-            crate::leaderboard::LeaderboardManager::update_user_score(env, &updated);
+        // Update leaderboards
+        let updated = GamificationStorage::get_profile(env, user);
+        crate::leaderboard::LeaderboardManager::update_user_score(env, &updated);
 
-            // Achievement check
+        // Achievement check
+        if check_achievements {
             crate::achievements::AchievementManager::check_and_award_achievements(
                 env, user, &updated,
             );
-
-            // Adaptive difficulty update (success)
-            crate::achievements::AchievementManager::update_adaptive_difficulty(
-                env, user, true, 100,
-            );
-
-            GamificationEvents::emit_challenge_completed(env, user, challenge_id, uc.rank);
         }
 
-        env.storage().persistent().set(&uc_key, &uc);
-        Ok(completed)
+        // Adaptive difficulty update (success)
+        crate::achievements::AchievementManager::update_adaptive_difficulty(env, user, true, 100);
+
+        GamificationEvents::emit_challenge_completed(env, user, challenge.id, rank);
     }
 
     // ── Queries ────────────────────────────────────────────────────────────
@@ -240,6 +424,30 @@ impl ChallengeManager {
         env.storage().persistent().get(&GamificationKey::UserChallenge(user.clone(), challenge_id))
     }
 
+    /// Participation mode of a challenge; challenges without a stored mode are solo.
+    pub fn get_mode(env: &Env, challenge_id: u64) -> ChallengeMode {
+        env.storage()
+            .persistent()
+            .get(&GamificationKey::ChallengeModeOf(challenge_id))
+            .unwrap_or(ChallengeMode::Solo)
+    }
+
+    /// Guild `user` joined a team-mode challenge with; 0 for solo challenges.
+    pub fn get_team(env: &Env, user: &Address, challenge_id: u64) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&GamificationKey::ChallengeTeam(user.clone(), challenge_id))
+            .unwrap_or(0)
+    }
+
+    /// Pooled progress of `guild_id`'s team on a team-mode challenge.
+    pub fn get_team_progress(env: &Env, challenge_id: u64, guild_id: u64) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&GamificationKey::TeamChallengeProgress(challenge_id, guild_id))
+            .unwrap_or(0)
+    }
+
     pub fn get_user_active_challenges(env: &Env, user: &Address) -> Vec<u64> {
         env.storage()
             .persistent()
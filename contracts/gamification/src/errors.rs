@@ -55,6 +55,14 @@ pub enum GamificationError {
     /// The user does not have enough XP to perform the requested action.
     InsufficientXP = 25,
     RateLimitExceeded = 26,
+    /// The user's team has already completed this team-mode challenge.
+    TeamChallengeCompleted = 27,
+    /// No token contract has been configured for guild treasuries.
+    TreasuryNotConfigured = 28,
+    /// The guild treasury holds fewer tokens than the requested spend.
+    InsufficientTreasury = 29,
+    /// The token contract rejected the treasury transfer.
+    TreasuryTransferFailed = 30,
 }
 
 /// Backward-compatible alias used by internal submodules.
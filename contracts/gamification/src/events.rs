@@ -2,8 +2,9 @@ use shared::event_schema::{
     AccessControlEventData, AchievementClaimedEvent, AchievementEarnedEvent,
     ChallengeCompletedEvent, ChallengeCreatedEvent, ChallengeJoinedEvent, ContractInitializedEvent,
     EndorsedEvent, GamificationEventData, GuildCreatedEvent, GuildJoinedEvent, GuildLeftEvent,
-    LevelUpEvent, RecognizedEvent, ReputationUpdatedEvent, SeasonEndedEvent, SeasonStartedEvent,
-    StreakMilestoneEvent, XPAddedEvent,
+    GuildTreasuryContributedEvent, GuildTreasurySpentEvent, LevelUpEvent, RecognizedEvent,
+    ReputationUpdatedEvent, SeasonEndedEvent, SeasonStartedEvent, StreakMilestoneEvent,
+    XPAddedEvent,
 };
 use shared::{emit_access_control_event, emit_gamification_event};
 use soroban_sdk::{symbol_short, Address, Env};
@@ -144,6 +145,44 @@ impl GamificationEvents {
         );
     }
 
+    pub fn emit_guild_treasury_contributed(
+        env: &Env,
+        guild_id: u64,
+        member: &Address,
+        amount: u64,
+    ) {
+        emit_gamification_event!(
+            env,
+            symbol_short!("gam"),
+            member.clone(),
+            GamificationEventData::GuildTreasuryContributed(GuildTreasuryContributedEvent {
+                guild_id,
+                member: member.clone(),
+                amount
+            })
+        );
+    }
+
+    pub fn emit_guild_treasury_spent(
+        env: &Env,
+        guild_id: u64,
+        officer: &Address,
+        recipient: &Address,
+        amount: u64,
+    ) {
+        emit_gamification_event!(
+            env,
+            symbol_short!("gam"),
+            officer.clone(),
+            GamificationEventData::GuildTreasurySpent(GuildTreasurySpentEvent {
+                guild_id,
+                officer: officer.clone(),
+                recipient: recipient.clone(),
+                amount
+            })
+        );
+    }
+
     pub fn emit_season_started(env: &Env, admin: &Address, season_id: u64) {
         emit_gamification_event!(
             env,
@@ -1,4 +1,4 @@
-use soroban_sdk::{Address, Env, String, Vec};
+use soroban_sdk::{vec, Address, Env, IntoVal, String, Symbol, Vec};
 
 use crate::errors::Error;
 use crate::events::GamificationEvents;
@@ -6,6 +6,9 @@ use crate::storage::GamificationStorage;
 use crate::types::{GamificationKey, Guild, GuildMember, GuildRole};
 use shared::validation::{CoreValidator, ValidationConfig};
 
+/// Highest XP multiplier (100 = 1×) the admin may approve for a guild.
+pub const MAX_GUILD_XP_MULTIPLIER: u32 = 150;
+
 pub struct GuildManager;
 
 impl GuildManager {
@@ -248,6 +251,124 @@ impl GuildManager {
         }
     }
 
+    // ── XP multiplier ──────────────────────────────────────────────────────
+
+    /// Approve an XP multiplier (100 = 1×) for all members of `guild_id`.
+    pub fn set_xp_multiplier(env: &Env, guild_id: u64, multiplier: u32) -> Result<(), Error> {
+        if Self::get_guild(env, guild_id).is_none() {
+            return Err(Error::NotFound);
+        }
+        if !(100..=MAX_GUILD_XP_MULTIPLIER).contains(&multiplier) {
+            return Err(Error::InvalidInput);
+        }
+        let key = GamificationKey::GuildXpMultiplier(guild_id);
+        if multiplier == 100 {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &multiplier);
+        }
+        Ok(())
+    }
+
+    pub fn get_xp_multiplier(env: &Env, guild_id: u64) -> u32 {
+        env.storage().persistent().get(&GamificationKey::GuildXpMultiplier(guild_id)).unwrap_or(100)
+    }
+
+    /// Multiplier applied to `user`'s activity XP; 100 for non-members.
+    pub fn get_member_xp_multiplier(env: &Env, user: &Address) -> u32 {
+        let member: Option<GuildMember> =
+            env.storage().persistent().get(&GamificationKey::GuildMember(user.clone()));
+        member.map(|m| Self::get_xp_multiplier(env, m.guild_id)).unwrap_or(100)
+    }
+
+    // ── Treasury ───────────────────────────────────────────────────────────
+
+    /// Move `amount` tokens from `member` into their guild's treasury.
+    pub fn contribute_to_treasury(
+        env: &Env,
+        member: &Address,
+        guild_id: u64,
+        amount: u64,
+    ) -> Result<u64, Error> {
+        if amount == 0 {
+            return Err(Error::InvalidAmount);
+        }
+        Self::require_member_of(env, member, guild_id)?;
+
+        let contract = env.current_contract_address();
+        Self::transfer_tokens(env, member, &contract, amount)?;
+
+        let balance = Self::get_treasury_balance(env, guild_id) + amount;
+        env.storage().persistent().set(&GamificationKey::GuildTreasury(guild_id), &balance);
+
+        GamificationEvents::emit_guild_treasury_contributed(env, guild_id, member, amount);
+        Ok(balance)
+    }
+
+    /// Pay `amount` tokens out of the guild treasury; leader and officers only.
+    pub fn spend_treasury(
+        env: &Env,
+        officer: &Address,
+        guild_id: u64,
+        recipient: &Address,
+        amount: u64,
+    ) -> Result<u64, Error> {
+        if amount == 0 {
+            return Err(Error::InvalidAmount);
+        }
+        let member = Self::require_member_of(env, officer, guild_id)?;
+        if member.role == GuildRole::Member {
+            return Err(Error::Unauthorized);
+        }
+
+        let balance = Self::get_treasury_balance(env, guild_id);
+        if amount > balance {
+            return Err(Error::InsufficientTreasury);
+        }
+
+        let contract = env.current_contract_address();
+        Self::transfer_tokens(env, &contract, recipient, amount)?;
+
+        let remaining = balance - amount;
+        env.storage().persistent().set(&GamificationKey::GuildTreasury(guild_id), &remaining);
+
+        GamificationEvents::emit_guild_treasury_spent(env, guild_id, officer, recipient, amount);
+        Ok(remaining)
+    }
+
+    pub fn get_treasury_balance(env: &Env, guild_id: u64) -> u64 {
+        env.storage().persistent().get(&GamificationKey::GuildTreasury(guild_id)).unwrap_or(0)
+    }
+
+    fn require_member_of(env: &Env, user: &Address, guild_id: u64) -> Result<GuildMember, Error> {
+        if Self::get_guild(env, guild_id).is_none() {
+            return Err(Error::NotFound);
+        }
+        env.storage()
+            .persistent()
+            .get::<GamificationKey, GuildMember>(&GamificationKey::GuildMember(user.clone()))
+            .filter(|m| m.guild_id == guild_id)
+            .ok_or(Error::NotInGuild)
+    }
+
+    /// Call `transfer(from, to, amount)` on the configured treasury token.
+    fn transfer_tokens(env: &Env, from: &Address, to: &Address, amount: u64) -> Result<(), Error> {
+        let token: Address = env
+            .storage()
+            .instance()
+            .get(&GamificationKey::GuildTreasuryToken)
+            .ok_or(Error::TreasuryNotConfigured)?;
+
+        match env.try_invoke_contract::<(), soroban_sdk::Error>(
+            &token,
+            &Symbol::new(env, "transfer"),
+            vec![env, from.into_val(env), to.into_val(env), amount.into_val(env)],
+        ) {
+            Ok(Ok(())) => Ok(()),
+            _ => Err(Error::TreasuryTransferFailed),
+        }
+    }
+
     // ── Queries ────────────────────────────────────────────────────────────
 
     pub fn get_guild(env: &Env, guild_id: u64) -> Option<Guild> {
@@ -1,8 +1,9 @@
-use soroban_sdk::{Env, Vec};
+use soroban_sdk::{Address, Env, Vec};
 
+use crate::storage::GamificationStorage;
 use crate::types::{
     GamificationKey, GamificationProfile, Guild, GuildLeaderboardEntry, LeaderboardCategory,
-    LeaderboardEntry, SeasonLeaderboardEntry,
+    LeaderboardEntry, SeasonLeaderboardEntry, SeasonRewardTier,
 };
 
 /// Maximum number of entries kept per leaderboard.
@@ -15,39 +16,16 @@ impl LeaderboardManager {
 
     /// Called whenever a user's profile changes to reflect the new score
     /// across all relevant leaderboard categories.
+    ///
+    /// Users who opted out of leaderboards are skipped; their profile keeps
+    /// tracking progress privately.
     pub fn update_user_score(env: &Env, profile: &GamificationProfile) {
-        Self::upsert(env, &profile.user, profile.total_xp, &LeaderboardCategory::TotalXP);
-        Self::upsert(
-            env,
-            &profile.user,
-            profile.current_streak,
-            &LeaderboardCategory::CurrentStreak,
-        );
-        Self::upsert(
-            env,
-            &profile.user,
-            profile.courses_completed,
-            &LeaderboardCategory::CoursesCompleted,
-        );
-        Self::upsert(
-            env,
-            &profile.user,
-            profile.reputation_score,
-            &LeaderboardCategory::Reputation,
-        );
-        Self::upsert(env, &profile.user, profile.season_xp, &LeaderboardCategory::SeasonXP);
-        Self::upsert(
-            env,
-            &profile.user,
-            profile.challenges_completed,
-            &LeaderboardCategory::ChallengesCompleted,
-        );
-        Self::upsert(
-            env,
-            &profile.user,
-            profile.endorsements_received,
-            &LeaderboardCategory::Endorsements,
-        );
+        if Self::is_hidden(env, &profile.user) {
+            return;
+        }
+        for (category, score) in Self::category_scores(profile) {
+            Self::upsert(env, &profile.user, score, &category);
+        }
     }
 
     pub fn get_leaderboard(
@@ -61,16 +39,83 @@ impl LeaderboardManager {
             .get(&GamificationKey::Leaderboard(category.clone()))
             .unwrap_or_else(|| Vec::new(env));
 
-        let cap = limit.min(MAX_ENTRIES).min(all.len());
+        // Entries written before the user opted out are dropped here
+        let cap = limit.min(MAX_ENTRIES);
         let mut out = Vec::new(env);
-        for i in 0..cap {
-            if let Some(e) = all.get(i) {
+        for e in all.iter() {
+            if out.len() >= cap {
+                break;
+            }
+            if !Self::is_hidden(env, &e.user) {
                 out.push_back(e);
             }
         }
+        Self::assign_ranks(env, &mut out, category);
         out
     }
 
+    // ── Privacy ────────────────────────────────────────────────────────────
+
+    /// Show or hide `user` on every leaderboard.
+    ///
+    /// Hiding removes the user's entries from the global and active season
+    /// boards immediately; past season boards are filtered when read. Showing
+    /// again re-ranks the user from their current profile and season XP.
+    pub fn set_visibility(env: &Env, user: &Address, visible: bool) {
+        let key = GamificationKey::LeaderboardHidden(user.clone());
+        let profile = GamificationStorage::get_profile(env, user);
+        let season_id = GamificationStorage::get_active_season_id(env);
+
+        if visible {
+            env.storage().persistent().remove(&key);
+            Self::update_user_score(env, &profile);
+
+            let season_xp: u32 = env
+                .storage()
+                .persistent()
+                .get(&GamificationKey::UserSeasonXP(user.clone(), season_id))
+                .unwrap_or(0);
+            if season_id != 0 && season_xp > 0 {
+                let entry = SeasonLeaderboardEntry {
+                    user: user.clone(),
+                    season_xp,
+                    rank: 0,
+                    reward_tier: SeasonRewardTier::None,
+                };
+                Self::update_season_score(env, season_id, entry);
+            }
+            return;
+        }
+
+        env.storage().persistent().set(&key, &true);
+
+        for (category, _) in Self::category_scores(&profile) {
+            let board_key = GamificationKey::Leaderboard(category.clone());
+            let existing: Vec<LeaderboardEntry> =
+                env.storage().persistent().get(&board_key).unwrap_or_else(|| Vec::new(env));
+            let mut filtered = Self::remove_user(env, &existing, user);
+            Self::assign_ranks(env, &mut filtered, &category);
+            env.storage().persistent().set(&board_key, &filtered);
+        }
+
+        if season_id != 0 {
+            let board_key = GamificationKey::SeasonLeaderboard(season_id);
+            let existing: Vec<SeasonLeaderboardEntry> =
+                env.storage().persistent().get(&board_key).unwrap_or_else(|| Vec::new(env));
+            let mut filtered = Self::remove_season_user(env, &existing, user);
+            Self::assign_season_ranks(env, &mut filtered);
+            env.storage().persistent().set(&board_key, &filtered);
+        }
+    }
+
+    /// Whether `user` has opted out of leaderboards.
+    pub fn is_hidden(env: &Env, user: &Address) -> bool {
+        env.storage()
+            .persistent()
+            .get(&GamificationKey::LeaderboardHidden(user.clone()))
+            .unwrap_or(false)
+    }
+
     // ── Guild leaderboard ──────────────────────────────────────────────────
 
     pub fn update_guild_score(env: &Env, guild: &Guild) {
@@ -107,6 +152,10 @@ impl LeaderboardManager {
     // ── Season leaderboard ─────────────────────────────────────────────────
 
     pub fn update_season_score(env: &Env, season_id: u64, entry: SeasonLeaderboardEntry) {
+        if Self::is_hidden(env, &entry.user) {
+            return;
+        }
+
         let key = GamificationKey::SeasonLeaderboard(season_id);
         let existing: Vec<SeasonLeaderboardEntry> =
             env.storage().persistent().get(&key).unwrap_or_else(|| Vec::new(env));
@@ -121,15 +170,43 @@ impl LeaderboardManager {
         env.storage().persistent().set(&key, &sorted);
     }
 
+    /// Season standings without users who have opted out; ranks are
+    /// renumbered and the reward tiers recorded for the season are kept.
     pub fn get_season_leaderboard(env: &Env, season_id: u64) -> Vec<SeasonLeaderboardEntry> {
-        env.storage()
+        let all: Vec<SeasonLeaderboardEntry> = env
+            .storage()
             .persistent()
             .get(&GamificationKey::SeasonLeaderboard(season_id))
-            .unwrap_or_else(|| Vec::new(env))
+            .unwrap_or_else(|| Vec::new(env));
+
+        let mut out = Vec::new(env);
+        let mut rank = 1u32;
+        for mut e in all.iter() {
+            if Self::is_hidden(env, &e.user) {
+                continue;
+            }
+            e.rank = rank;
+            rank += 1;
+            out.push_back(e);
+        }
+        out
     }
 
     // ── Internals – global ─────────────────────────────────────────────────
 
+    /// Score the profile contributes to each global leaderboard category.
+    fn category_scores(profile: &GamificationProfile) -> [(LeaderboardCategory, u32); 7] {
+        [
+            (LeaderboardCategory::TotalXP, profile.total_xp),
+            (LeaderboardCategory::CurrentStreak, profile.current_streak),
+            (LeaderboardCategory::CoursesCompleted, profile.courses_completed),
+            (LeaderboardCategory::Reputation, profile.reputation_score),
+            (LeaderboardCategory::SeasonXP, profile.season_xp),
+            (LeaderboardCategory::ChallengesCompleted, profile.challenges_completed),
+            (LeaderboardCategory::Endorsements, profile.endorsements_received),
+        ]
+    }
+
     fn upsert(env: &Env, user: &soroban_sdk::Address, score: u32, category: &LeaderboardCategory) {
         let key = GamificationKey::Leaderboard(category.clone());
         let existing: Vec<LeaderboardEntry> =
@@ -190,6 +190,9 @@ impl Gamification {
 
     /// Return all achievements earned by `user`.
     ///
+    /// Unclaimed token rewards are reported at their currently claimable
+    /// amount; reading never applies decay to stored rewards.
+    ///
     /// # Arguments
     /// * `env` - The Soroban environment.
     /// * `user` - Address of the user whose achievements to list.
@@ -203,6 +206,9 @@ impl Gamification {
     }
 
     /// Claim the token reward attached to an earned achievement.
+    ///
+    /// If a reward decay policy is set, the reward is decayed for the
+    /// inactivity since the user's last activity before it is paid.
     pub fn claim_achievement_reward(
         env: Env,
         user: Address,
@@ -212,6 +218,56 @@ impl Gamification {
         AchievementManager::claim_reward(&env, &user, achievement_id)
     }
 
+    /// Admin: place an achievement in the skill tree behind prerequisite nodes.
+    ///
+    /// `record_activity` only awards the achievement once every prerequisite
+    /// achievement has been earned. Calling again replaces the prerequisites.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment.
+    /// * `admin` - Admin address (must match stored admin).
+    /// * `achievement_id` - Achievement gated by this node.
+    /// * `prerequisites` - Achievement IDs that must be earned first; each must be lower than `achievement_id`.
+    ///
+    /// # Errors
+    /// Returns [`GamificationError::Unauthorized`] if `admin` is not the contract admin.
+    /// Returns [`GamificationError::NotFound`] if the achievement or a prerequisite does not exist.
+    /// Returns [`GamificationError::InvalidInput`] if a prerequisite ID is not lower than `achievement_id`.
+    ///
+    /// # Example
+    /// ```ignore
+    /// client.set_skill_node(&admin, &advanced_id, &vec![&env, basics_id]);
+    /// ```
+    pub fn set_skill_node(
+        env: Env,
+        admin: Address,
+        achievement_id: u64,
+        prerequisites: Vec<u64>,
+    ) -> Result<(), GamificationError> {
+        admin.require_auth();
+        GamificationStorage::require_admin(&env, &admin)?;
+        AchievementManager::set_skill_node(&env, achievement_id, prerequisites)
+    }
+
+    /// Return the skill-tree node for an achievement, if one is defined.
+    pub fn get_skill_node(env: Env, achievement_id: u64) -> Option<SkillNode> {
+        AchievementManager::get_skill_node(&env, achievement_id)
+    }
+
+    /// Return which skill-tree nodes `user` has unlocked and which are available next.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment.
+    /// * `user` - Address of the user whose progress to report.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let progress = client.get_skill_tree_progress(&user);
+    /// ```
+    pub fn get_skill_tree_progress(env: Env, user: Address) -> SkillTreeProgress {
+        AchievementManager::get_skill_tree_progress(&env, &user)
+    }
+
     // ══════════════════════════════════════════════════════════════════════
     //  Leaderboard Functions
     // ══════════════════════════════════════════════════════════════════════
@@ -225,6 +281,25 @@ impl Gamification {
         LeaderboardManager::get_leaderboard(&env, &category, limit)
     }
 
+    /// Show or hide the caller on all leaderboards.
+    ///
+    /// Opted-out users keep earning XP and can still query their own
+    /// profile; they are just left out of every ranking.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment.
+    /// * `user` - Address of the user changing their visibility.
+    /// * `visible` - `false` to opt out of leaderboards, `true` to opt back in.
+    ///
+    /// # Example
+    /// ```ignore
+    /// client.set_leaderboard_visibility(&user, &false);
+    /// ```
+    pub fn set_leaderboard_visibility(env: Env, user: Address, visible: bool) {
+        user.require_auth();
+        LeaderboardManager::set_visibility(&env, &user, visible);
+    }
+
     /// Return the global guild leaderboard sorted by total XP.
     ///
     /// # Arguments
@@ -265,11 +340,40 @@ impl Gamification {
     ) -> Result<u64, GamificationError> {
         admin.require_auth();
         GamificationStorage::require_admin(&env, &admin)?;
-        ChallengeManager::create(&env, &admin, challenge)
+        ChallengeManager::create(&env, &admin, challenge, ChallengeMode::Solo)
+    }
+
+    /// Admin: create a team-mode challenge whose progress is pooled per guild.
+    ///
+    /// Users join through their guild; when a team's pooled progress reaches
+    /// the target every joined member of that team is rewarded.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment.
+    /// * `admin` - Admin address (must match stored admin).
+    /// * `challenge` - Challenge definition to persist.
+    ///
+    /// # Errors
+    /// Returns [`GamificationError::Unauthorized`] if `admin` is not the contract admin.
+    ///
+    /// # Example
+    /// ```ignore
+    /// client.create_team_challenge(&admin, &challenge);
+    /// ```
+    pub fn create_team_challenge(
+        env: Env,
+        admin: Address,
+        challenge: Challenge,
+    ) -> Result<u64, GamificationError> {
+        admin.require_auth();
+        GamificationStorage::require_admin(&env, &admin)?;
+        ChallengeManager::create(&env, &admin, challenge, ChallengeMode::Team)
     }
 
     /// Enroll `user` into an active challenge.
     ///
+    /// Team-mode challenges enroll the user on behalf of their guild.
+    ///
     /// # Arguments
     /// * `env` - The Soroban environment.
     /// * `user` - Address of the user joining the challenge.
@@ -349,6 +453,65 @@ impl Gamification {
         ChallengeManager::get_user_active_challenges(&env, &user)
     }
 
+    /// Return the pooled progress a guild's team has made on a team-mode challenge.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment.
+    /// * `challenge_id` - ID of the team-mode challenge.
+    /// * `guild_id` - ID of the guild acting as the team.
+    ///
+    /// # Example
+    /// ```ignore
+    /// client.get_team_challenge_progress(&challenge_id, &guild_id);
+    /// ```
+    pub fn get_team_challenge_progress(env: Env, challenge_id: u64, guild_id: u64) -> u32 {
+        ChallengeManager::get_team_progress(&env, challenge_id, guild_id)
+    }
+
+    /// Reward the next batch of unpaid members of a team that has completed a
+    /// team-mode challenge. Anyone may call this until it returns 0.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment.
+    /// * `challenge_id` - ID of the team-mode challenge.
+    /// * `guild_id` - ID of the guild acting as the team.
+    ///
+    /// # Errors
+    /// Returns [`GamificationError::NotFound`] if the challenge does not exist.
+    /// Returns [`GamificationError::InvalidInput`] if the team has not completed the challenge.
+    ///
+    /// # Example
+    /// ```ignore
+    /// while client.settle_team_challenge(&challenge_id, &guild_id) > 0 {}
+    /// ```
+    pub fn settle_team_challenge(
+        env: Env,
+        challenge_id: u64,
+        guild_id: u64,
+    ) -> Result<u32, GamificationError> {
+        ChallengeManager::settle_team_payouts(&env, challenge_id, guild_id)
+    }
+
+    /// Return whether a challenge is solo or team mode.
+    ///
+    /// # Example
+    /// ```ignore
+    /// client.get_challenge_mode(&challenge_id);
+    /// ```
+    pub fn get_challenge_mode(env: Env, challenge_id: u64) -> ChallengeMode {
+        ChallengeManager::get_mode(&env, challenge_id)
+    }
+
+    /// Return the guild `user` joined a team-mode challenge with; 0 for solo challenges.
+    ///
+    /// # Example
+    /// ```ignore
+    /// client.get_user_challenge_team(&user, &challenge_id);
+    /// ```
+    pub fn get_user_challenge_team(env: Env, user: Address, challenge_id: u64) -> u64 {
+        ChallengeManager::get_team(&env, &user, challenge_id)
+    }
+
     // ══════════════════════════════════════════════════════════════════════
     //  Guild Functions
     // ══════════════════════════════════════════════════════════════════════
@@ -448,6 +611,155 @@ impl Gamification {
         GuildManager::get_members(&env, guild_id)
     }
 
+    /// Admin: approve an XP multiplier (100 = 1×) for a guild's members.
+    ///
+    /// Applied in `record_activity` on top of any season multiplier. Setting 100
+    /// removes the bonus.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment.
+    /// * `admin` - Admin address (must match stored admin).
+    /// * `guild_id` - ID of the guild to boost.
+    /// * `multiplier` - Percentage multiplier between 100 and 150.
+    ///
+    /// # Errors
+    /// Returns [`GamificationError::Unauthorized`] if `admin` is not the contract admin.
+    /// Returns [`GamificationError::NotFound`] if the guild does not exist.
+    /// Returns [`GamificationError::InvalidInput`] if `multiplier` is outside 100–150.
+    ///
+    /// # Example
+    /// ```ignore
+    /// client.set_guild_xp_multiplier(&admin, &guild_id, &110);
+    /// ```
+    pub fn set_guild_xp_multiplier(
+        env: Env,
+        admin: Address,
+        guild_id: u64,
+        multiplier: u32,
+    ) -> Result<(), GamificationError> {
+        admin.require_auth();
+        GamificationStorage::require_admin(&env, &admin)?;
+        GuildManager::set_xp_multiplier(&env, guild_id, multiplier)
+    }
+
+    /// Return the XP multiplier (100 = 1×) approved for a guild.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment.
+    /// * `guild_id` - ID of the guild to query.
+    ///
+    /// # Example
+    /// ```ignore
+    /// client.get_guild_xp_multiplier(&guild_id);
+    /// ```
+    pub fn get_guild_xp_multiplier(env: Env, guild_id: u64) -> u32 {
+        GuildManager::get_xp_multiplier(&env, guild_id)
+    }
+
+    /// Admin: set the token contract that holds guild treasury funds.
+    ///
+    /// The token must expose `transfer(from, to, amount)` with a `u64` amount,
+    /// as the platform token contract does.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment.
+    /// * `admin` - Admin address (must match stored admin).
+    /// * `token` - Address of the token contract.
+    ///
+    /// # Errors
+    /// Returns [`GamificationError::Unauthorized`] if `admin` is not the contract admin.
+    ///
+    /// # Example
+    /// ```ignore
+    /// client.set_guild_treasury_token(&admin, &token);
+    /// ```
+    pub fn set_guild_treasury_token(
+        env: Env,
+        admin: Address,
+        token: Address,
+    ) -> Result<(), GamificationError> {
+        admin.require_auth();
+        GamificationStorage::require_admin(&env, &admin)?;
+        env.storage().instance().set(&GamificationKey::GuildTreasuryToken, &token);
+        Ok(())
+    }
+
+    /// Contribute tokens from `member` to their guild's treasury.
+    ///
+    /// Returns the treasury balance after the contribution.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment.
+    /// * `member` - Guild member paying into the treasury.
+    /// * `guild_id` - ID of the member's guild.
+    /// * `amount` - Number of tokens to contribute.
+    ///
+    /// # Errors
+    /// Returns [`GamificationError::InvalidAmount`] if `amount` is zero.
+    /// Returns [`GamificationError::NotInGuild`] if `member` does not belong to the guild.
+    /// Returns [`GamificationError::TreasuryNotConfigured`] if no treasury token is set.
+    /// Returns [`GamificationError::TreasuryTransferFailed`] if the token transfer fails.
+    ///
+    /// # Example
+    /// ```ignore
+    /// client.contribute_to_guild(&member, &guild_id, &100);
+    /// ```
+    pub fn contribute_to_guild(
+        env: Env,
+        member: Address,
+        guild_id: u64,
+        amount: u64,
+    ) -> Result<u64, GamificationError> {
+        member.require_auth();
+        GuildManager::contribute_to_treasury(&env, &member, guild_id, amount)
+    }
+
+    /// Pay tokens out of a guild treasury to `recipient`.
+    ///
+    /// Only the guild leader and officers may spend. Returns the remaining balance.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment.
+    /// * `officer` - Guild leader or officer authorizing the spend.
+    /// * `guild_id` - ID of the guild whose treasury is spent.
+    /// * `recipient` - Address receiving the tokens.
+    /// * `amount` - Number of tokens to pay out.
+    ///
+    /// # Errors
+    /// Returns [`GamificationError::Unauthorized`] if `officer` is a regular member.
+    /// Returns [`GamificationError::NotInGuild`] if `officer` does not belong to the guild.
+    /// Returns [`GamificationError::InsufficientTreasury`] if the treasury holds less than `amount`.
+    /// Returns [`GamificationError::TreasuryTransferFailed`] if the token transfer fails.
+    ///
+    /// # Example
+    /// ```ignore
+    /// client.spend_guild_treasury(&leader, &guild_id, &recipient, &50);
+    /// ```
+    pub fn spend_guild_treasury(
+        env: Env,
+        officer: Address,
+        guild_id: u64,
+        recipient: Address,
+        amount: u64,
+    ) -> Result<u64, GamificationError> {
+        officer.require_auth();
+        GuildManager::spend_treasury(&env, &officer, guild_id, &recipient, amount)
+    }
+
+    /// Return the token balance held in a guild's treasury.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment.
+    /// * `guild_id` - ID of the guild to query.
+    ///
+    /// # Example
+    /// ```ignore
+    /// client.get_guild_treasury(&guild_id);
+    /// ```
+    pub fn get_guild_treasury(env: Env, guild_id: u64) -> u64 {
+        GuildManager::get_treasury_balance(&env, guild_id)
+    }
+
     // ══════════════════════════════════════════════════════════════════════
     //  Season Functions
     // ══════════════════════════════════════════════════════════════════════
@@ -628,6 +940,53 @@ impl Gamification {
         env.storage().instance().get(&GamificationKey::Admin)
     }
 
+    /// Admin: set or clear (`None`) the unclaimed reward decay policy.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment.
+    /// * `admin` - Admin address (must match stored admin).
+    /// * `policy` - Decay policy to apply, or `None` to disable decay.
+    ///
+    /// # Errors
+    /// Returns [`GamificationError::Unauthorized`] if `admin` is not the contract admin.
+    /// Returns [`GamificationError::InvalidInput`] if the period is zero or the percent is not in 1–100.
+    ///
+    /// # Example
+    /// ```ignore
+    /// client.set_reward_decay_policy(&admin, &Some(policy));
+    /// ```
+    pub fn set_reward_decay_policy(
+        env: Env,
+        admin: Address,
+        policy: Option<RewardDecayPolicy>,
+    ) -> Result<(), GamificationError> {
+        admin.require_auth();
+        GamificationStorage::require_admin(&env, &admin)?;
+        match policy {
+            Some(p) => {
+                if p.inactivity_period == 0 || p.decay_percent == 0 || p.decay_percent > 100 {
+                    return Err(Error::InvalidInput);
+                }
+                env.storage().instance().set(&GamificationKey::RewardDecayPolicy, &p);
+            }
+            None => env.storage().instance().remove(&GamificationKey::RewardDecayPolicy),
+        }
+        Ok(())
+    }
+
+    /// Return the active reward decay policy, or `None` if decay is disabled.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment.
+    ///
+    /// # Example
+    /// ```ignore
+    /// client.get_reward_decay_policy();
+    /// ```
+    pub fn get_reward_decay_policy(env: Env) -> Option<RewardDecayPolicy> {
+        GamificationStorage::get_reward_decay_policy(&env)
+    }
+
     pub fn export_user_data(env: Env, user: Address) -> GamificationExport {
         let profile = GamificationStorage::get_profile(&env, &user);
         GamificationExport {
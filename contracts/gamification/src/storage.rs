@@ -1,7 +1,7 @@
 use soroban_sdk::{Address, Env};
 
 use crate::errors::Error;
-use crate::types::{GamificationConfig, GamificationKey, GamificationProfile};
+use crate::types::{GamificationConfig, GamificationKey, GamificationProfile, RewardDecayPolicy};
 
 pub struct GamificationStorage;
 
@@ -40,6 +40,10 @@ impl GamificationStorage {
         })
     }
 
+    pub fn get_reward_decay_policy(env: &Env) -> Option<RewardDecayPolicy> {
+        env.storage().instance().get(&GamificationKey::RewardDecayPolicy)
+    }
+
     // ── User Profile ───────────────────────────────────────────────────────
 
     pub fn get_profile(env: &Env, user: &Address) -> GamificationProfile {
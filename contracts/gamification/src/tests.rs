@@ -1,9 +1,13 @@
-use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, Address, Env, String};
+use soroban_sdk::{
+    contract, contractimpl, testutils::Address as _, testutils::Ledger as _, vec, Address, Env,
+    String, Vec,
+};
 
+use crate::challenges::MAX_TEAM_PAYOUTS_PER_CALL;
 use crate::types::{
     Achievement, AchievementCategory, AchievementRequirements, AchievementTier, ActivityRecord,
-    ActivityType, Challenge, ChallengeDifficulty, ChallengeType, LeaderboardCategory,
-    RecognitionType, Season,
+    ActivityType, Challenge, ChallengeDifficulty, ChallengeMode, ChallengeType,
+    LeaderboardCategory, RecognitionType, RewardDecayPolicy, Season,
 };
 use crate::{Gamification, GamificationClient, GamificationError};
 
@@ -280,6 +284,171 @@ fn test_claim_achievement_reward() {
     }
 }
 
+fn make_module_achievement(env: &Env, name: &str, modules_completed: u32) -> Achievement {
+    Achievement {
+        id: 0,
+        name: String::from_str(env, name),
+        description: String::from_str(env, "Skill tree node for testing"),
+        tier: AchievementTier::Bronze,
+        category: AchievementCategory::Learning,
+        xp_reward: 50,
+        token_reward: 0,
+        requirements: AchievementRequirements {
+            courses_completed: 0,
+            modules_completed,
+            streak_days: 0,
+            total_xp: 0,
+            challenges_completed: 0,
+            endorsements_received: 0,
+            guild_contributions: 0,
+            seasons_completed: 0,
+        },
+        created_at: 0,
+        is_active: true,
+        is_cross_course: false,
+    }
+}
+
+#[test]
+fn test_skill_node_waits_for_prerequisites() {
+    let (env, client, admin) = setup_env();
+    let student = Address::generate(&env);
+
+    // `advanced` is easier to qualify for than `basics`, but sits behind it
+    let basics = client.create_achievement(&admin, &make_module_achievement(&env, "Basics", 2));
+    let advanced = client.create_achievement(&admin, &make_module_achievement(&env, "Advanced", 1));
+    client.set_skill_node(&admin, &basics, &vec![&env]);
+    client.set_skill_node(&admin, &advanced, &vec![&env, basics]);
+
+    env.ledger().with_mut(|l| l.timestamp = 1_000_000);
+    let first = client
+        .record_activity(&student, &make_activity(&env, ActivityType::ModuleCompleted, 1_000_000));
+    assert!(!first.contains(basics));
+    assert!(!first.contains(advanced));
+
+    let progress = client.get_skill_tree_progress(&student);
+    assert_eq!(progress.total_nodes, 2);
+    assert!(progress.unlocked_nodes.is_empty());
+    assert_eq!(progress.available_nodes, vec![&env, basics]);
+
+    let second = client
+        .record_activity(&student, &make_activity(&env, ActivityType::ModuleCompleted, 1_000_100));
+    assert!(second.contains(basics));
+    assert!(second.contains(advanced));
+
+    let progress = client.get_skill_tree_progress(&student);
+    assert_eq!(progress.unlocked_nodes, vec![&env, basics, advanced]);
+    assert!(progress.available_nodes.is_empty());
+}
+
+#[test]
+fn test_skill_node_rejects_invalid_prerequisites() {
+    let (env, client, admin) = setup_env();
+    let first = client.create_achievement(&admin, &make_module_achievement(&env, "First", 1));
+    let second = client.create_achievement(&admin, &make_module_achievement(&env, "Second", 1));
+
+    // Prerequisites must be earlier achievements, which rules out cycles
+    let forward = client.try_set_skill_node(&admin, &first, &vec![&env, second]);
+    assert_eq!(forward, Err(Ok(GamificationError::InvalidInput)));
+    let missing = client.try_set_skill_node(&admin, &999u64, &vec![&env, first]);
+    assert_eq!(missing, Err(Ok(GamificationError::NotFound)));
+    let non_admin =
+        client.try_set_skill_node(&Address::generate(&env), &second, &vec![&env, first]);
+    assert_eq!(non_admin, Err(Ok(GamificationError::Unauthorized)));
+
+    client.set_skill_node(&admin, &second, &vec![&env, first]);
+    assert_eq!(client.get_skill_node(&second).unwrap().prerequisites, vec![&env, first]);
+}
+
+fn set_weekly_decay(client: &GamificationClient, admin: &Address) {
+    let policy = RewardDecayPolicy { inactivity_period: 7 * 86_400, decay_percent: 10 };
+    client.set_reward_decay_policy(admin, &Some(policy.clone()));
+    assert_eq!(client.get_reward_decay_policy(), Some(policy));
+}
+
+#[test]
+fn test_unclaimed_reward_decays_after_inactivity() {
+    let (env, client, admin) = setup_env();
+    let student = Address::generate(&env);
+    set_weekly_decay(&client, &admin);
+
+    env.ledger().with_mut(|l| l.timestamp = 1_000_000);
+    let activity = make_activity(&env, ActivityType::CourseCompleted, 1_000_000);
+    client.record_activity(&student, &activity);
+
+    // Two full inactivity periods (plus a bit) elapse: 1_000 → 900 → 810.
+    env.ledger().with_mut(|l| l.timestamp = 1_000_000 + 15 * 86_400);
+    let tokens = client.claim_achievement_reward(&student, &1);
+    assert_eq!(tokens, 810);
+}
+
+#[test]
+fn test_reward_decay_applied_once_per_period() {
+    let (env, client, admin) = setup_env();
+    let student = Address::generate(&env);
+    set_weekly_decay(&client, &admin);
+
+    env.ledger().with_mut(|l| l.timestamp = 1_000_000);
+    let activity = make_activity(&env, ActivityType::CourseCompleted, 1_000_000);
+    client.record_activity(&student, &activity);
+
+    // Reads show the decayed amount without applying it again.
+    env.ledger().with_mut(|l| l.timestamp = 1_000_000 + 8 * 86_400);
+    assert_eq!(client.get_user_achievements(&student).get(0).unwrap().token_reward, 900);
+    assert_eq!(client.get_user_achievements(&student).get(0).unwrap().token_reward, 900);
+    let tokens = client.claim_achievement_reward(&student, &1);
+    assert_eq!(tokens, 900);
+}
+
+#[test]
+fn test_reward_decay_over_long_inactivity_reaches_zero() {
+    let (env, client, admin) = setup_env();
+    let student = Address::generate(&env);
+    set_weekly_decay(&client, &admin);
+
+    env.ledger().with_mut(|l| l.timestamp = 1_000_000);
+    let activity = make_activity(&env, ActivityType::CourseCompleted, 1_000_000);
+    client.record_activity(&student, &activity);
+
+    // Roughly 20 years of weekly periods is computed without a per-period loop.
+    env.ledger().with_mut(|l| l.timestamp = 1_000_000 + 1_040 * 7 * 86_400);
+    let tokens = client.claim_achievement_reward(&student, &1);
+    assert_eq!(tokens, 0);
+}
+
+#[test]
+fn test_no_reward_decay_for_active_user() {
+    let (env, client, admin) = setup_env();
+    let student = Address::generate(&env);
+    set_weekly_decay(&client, &admin);
+
+    env.ledger().with_mut(|l| l.timestamp = 1_000_000);
+    let activity = make_activity(&env, ActivityType::CourseCompleted, 1_000_000);
+    client.record_activity(&student, &activity);
+
+    // Staying active every few days keeps the reward intact.
+    for day in [5u64, 10, 15] {
+        let ts = 1_000_000 + day * 86_400;
+        env.ledger().with_mut(|l| l.timestamp = ts);
+        client.record_activity(&student, &make_activity(&env, ActivityType::ModuleCompleted, ts));
+    }
+    env.ledger().with_mut(|l| l.timestamp = 1_000_000 + 18 * 86_400);
+    let tokens = client.claim_achievement_reward(&student, &1);
+    assert_eq!(tokens, 1_000);
+}
+
+#[test]
+fn test_reward_decay_policy_validation() {
+    let (_env, client, admin) = setup_env();
+    let bad = RewardDecayPolicy { inactivity_period: 0, decay_percent: 10 };
+    assert!(client.try_set_reward_decay_policy(&admin, &Some(bad)).is_err());
+    let bad = RewardDecayPolicy { inactivity_period: 86_400, decay_percent: 101 };
+    assert!(client.try_set_reward_decay_policy(&admin, &Some(bad)).is_err());
+
+    client.set_reward_decay_policy(&admin, &None);
+    assert_eq!(client.get_reward_decay_policy(), None);
+}
+
 // ─── Leaderboard ─────────────────────────────────────────────────────────────
 
 #[test]
@@ -319,6 +488,108 @@ fn test_leaderboard_limit_respected() {
     assert!(board.len() <= 3, "leaderboard should respect limit");
 }
 
+#[test]
+fn test_opted_out_user_excluded_from_leaderboards() {
+    let (env, client, admin) = setup_env();
+    let hidden = Address::generate(&env);
+    let visible = Address::generate(&env);
+
+    let now = 2_000_000u64;
+    env.ledger().with_mut(|l| l.timestamp = now);
+    let season = Season {
+        id: 0,
+        name: String::from_str(&env, "Private Season"),
+        description: String::from_str(&env, "Opt-out check"),
+        start_time: now,
+        end_time: now + 30 * 86_400,
+        xp_multiplier: 100,
+        is_active: false,
+        total_participants: 0,
+        reward_pool: 0,
+    };
+    let season_id = client.create_season(&admin, &season);
+
+    client.record_activity(&hidden, &make_activity(&env, ActivityType::CourseCompleted, now));
+    client.set_leaderboard_visibility(&hidden, &false);
+    client.record_activity(&hidden, &make_activity(&env, ActivityType::CourseCompleted, now));
+    client.record_activity(&visible, &make_activity(&env, ActivityType::ModuleCompleted, now));
+
+    for category in [
+        LeaderboardCategory::TotalXP,
+        LeaderboardCategory::CurrentStreak,
+        LeaderboardCategory::CoursesCompleted,
+        LeaderboardCategory::SeasonXP,
+    ] {
+        let board = client.get_leaderboard(&category, &50u32);
+        assert!(board.iter().all(|e| e.user != hidden));
+        assert_eq!(board.get(0).unwrap().user, visible);
+        assert_eq!(board.get(0).unwrap().rank, 1);
+    }
+    let season_board = client.get_season_leaderboard(&season_id);
+    assert_eq!(season_board.len(), 1);
+    assert_eq!(season_board.get(0).unwrap().user, visible);
+
+    // Progress is still tracked privately
+    let profile = client.get_user_profile(&hidden);
+    assert_eq!(profile.courses_completed, 2);
+    assert!(profile.total_xp > client.get_user_profile(&visible).total_xp);
+}
+
+#[test]
+fn test_opted_out_user_hidden_from_past_season_leaderboard() {
+    let (env, client, admin) = setup_env();
+    let hidden = Address::generate(&env);
+    let visible = Address::generate(&env);
+
+    let now = 2_000_000u64;
+    env.ledger().with_mut(|l| l.timestamp = now);
+    let season = Season {
+        id: 0,
+        name: String::from_str(&env, "Closed Season"),
+        description: String::from_str(&env, "Opt-out after the season"),
+        start_time: now,
+        end_time: now + 86_400,
+        xp_multiplier: 100,
+        is_active: false,
+        total_participants: 0,
+        reward_pool: 0,
+    };
+    let season_id = client.create_season(&admin, &season);
+    client.record_activity(&hidden, &make_activity(&env, ActivityType::CourseCompleted, now));
+    client.record_activity(&visible, &make_activity(&env, ActivityType::ModuleCompleted, now));
+
+    env.ledger().with_mut(|l| l.timestamp = now + 2 * 86_400);
+    client.end_season(&admin);
+    assert_eq!(client.get_season_leaderboard(&season_id).get(0).unwrap().user, hidden);
+
+    client.set_leaderboard_visibility(&hidden, &false);
+    let board = client.get_season_leaderboard(&season_id);
+    assert_eq!(board.len(), 1);
+    assert_eq!(board.get(0).unwrap().user, visible);
+    assert_eq!(board.get(0).unwrap().rank, 1);
+
+    client.set_leaderboard_visibility(&hidden, &true);
+    assert_eq!(client.get_season_leaderboard(&season_id).len(), 2);
+}
+
+#[test]
+fn test_opting_back_in_restores_leaderboard_entry() {
+    let (env, client, _admin) = setup_env();
+    let student = Address::generate(&env);
+
+    env.ledger().with_mut(|l| l.timestamp = 1_000_000);
+    client.set_leaderboard_visibility(&student, &false);
+    client
+        .record_activity(&student, &make_activity(&env, ActivityType::CourseCompleted, 1_000_000));
+    assert!(client.get_leaderboard(&LeaderboardCategory::TotalXP, &10u32).is_empty());
+
+    client.set_leaderboard_visibility(&student, &true);
+    let board = client.get_leaderboard(&LeaderboardCategory::TotalXP, &10u32);
+    assert_eq!(board.len(), 1);
+    assert_eq!(board.get(0).unwrap().user, student);
+    assert_eq!(board.get(0).unwrap().score, client.get_user_profile(&student).total_xp);
+}
+
 // ─── Challenges ───────────────────────────────────────────────────────────────
 
 #[test]
@@ -410,6 +681,167 @@ fn test_quest_chain_prerequisite_enforced() {
     assert!(result2.is_ok(), "should join child after completing parent");
 }
 
+#[test]
+fn test_team_challenge_pools_progress_and_rewards_all_members() {
+    let (env, client, admin) = setup_env();
+    let leader = Address::generate(&env);
+    let member = Address::generate(&env);
+    let idle = Address::generate(&env);
+
+    env.ledger().with_mut(|l| l.timestamp = 1_000_000);
+    let now = 1_000_000u64;
+
+    let guild_id = client.create_guild(
+        &leader,
+        &String::from_str(&env, "Team Guild"),
+        &String::from_str(&env, "Works together"),
+        &20u32,
+        &true,
+    );
+    client.join_guild(&member, &guild_id);
+    client.join_guild(&idle, &guild_id);
+
+    let challenge = make_challenge(&env, &admin, now);
+    let challenge_id = client.create_team_challenge(&admin, &challenge);
+
+    client.join_challenge(&leader, &challenge_id);
+    client.join_challenge(&member, &challenge_id);
+    client.join_challenge(&idle, &challenge_id);
+
+    // Neither member reaches the target of 3 alone
+    assert!(!client.update_challenge_progress(&leader, &challenge_id, &2u32));
+    assert_eq!(client.get_team_challenge_progress(&challenge_id, &guild_id), 2);
+    assert!(client.update_challenge_progress(&member, &challenge_id, &1u32));
+    assert_eq!(client.get_team_challenge_progress(&challenge_id, &guild_id), 3);
+
+    // Every joined member is rewarded when the team completes, including
+    // one who never reported progress
+    for user in [&leader, &member, &idle] {
+        let status = client.get_user_challenge_status(user, &challenge_id).unwrap();
+        assert!(status.completed);
+        assert_eq!(status.rank, 1);
+        assert_eq!(client.get_user_challenge_team(user, &challenge_id), guild_id);
+        assert!(status.reward_claimed);
+
+        let profile = client.get_user_profile(user);
+        assert_eq!(profile.challenges_completed, 1);
+        assert!(profile.total_xp >= challenge.xp_reward);
+    }
+
+    // Later updates do not pay out again
+    assert!(client.update_challenge_progress(&leader, &challenge_id, &2u32));
+    assert_eq!(client.get_user_profile(&leader).challenges_completed, 1);
+}
+
+#[test]
+fn test_solo_challenge_completion_unchanged_by_team_mode() {
+    let (env, client, admin) = setup_env();
+    let student = Address::generate(&env);
+
+    env.ledger().with_mut(|l| l.timestamp = 1_000_000);
+    let challenge_id = client.create_challenge(&admin, &make_challenge(&env, &admin, 1_000_000));
+    assert_eq!(client.get_challenge_mode(&challenge_id), ChallengeMode::Solo);
+    client.join_challenge(&student, &challenge_id);
+    assert!(client.update_challenge_progress(&student, &challenge_id, &3u32));
+    let xp = client.get_user_profile(&student).total_xp;
+
+    let status = client.get_user_challenge_status(&student, &challenge_id).unwrap();
+    assert!(status.completed);
+    assert!(!status.reward_claimed);
+    assert_eq!(client.get_user_challenge_team(&student, &challenge_id), 0);
+
+    assert!(client.update_challenge_progress(&student, &challenge_id, &3u32));
+    let profile = client.get_user_profile(&student);
+    assert_eq!(profile.challenges_completed, 1);
+    assert_eq!(profile.total_xp, xp);
+}
+
+#[test]
+fn test_team_challenge_payouts_are_batched() {
+    let (env, client, admin) = setup_env();
+    let leader = Address::generate(&env);
+
+    env.ledger().with_mut(|l| l.timestamp = 1_000_000);
+    let now = 1_000_000u64;
+
+    let guild_id = client.create_guild(
+        &leader,
+        &String::from_str(&env, "Big Guild"),
+        &String::from_str(&env, "Many members"),
+        &50u32,
+        &true,
+    );
+    let challenge_id = client.create_team_challenge(&admin, &make_challenge(&env, &admin, now));
+    client.join_challenge(&leader, &challenge_id);
+
+    let mut members = Vec::new(&env);
+    for _ in 0..(MAX_TEAM_PAYOUTS_PER_CALL + 4) {
+        let member = Address::generate(&env);
+        client.join_guild(&member, &guild_id);
+        client.join_challenge(&member, &challenge_id);
+        members.push_back(member);
+    }
+
+    assert!(client.update_challenge_progress(&leader, &challenge_id, &3u32));
+
+    // The completing call reaches only the first batch of the member list
+    let unpaid = |client: &GamificationClient| {
+        members
+            .iter()
+            .filter(|m| !client.get_user_challenge_status(m, &challenge_id).unwrap().reward_claimed)
+            .count() as u32
+    };
+    assert_eq!(unpaid(&client), 5);
+
+    // An unpaid member can collect on their own update
+    let last = members.get(members.len() - 1).unwrap();
+    assert!(client.update_challenge_progress(&last, &challenge_id, &0u32));
+    assert_eq!(client.get_user_profile(&last).challenges_completed, 1);
+    assert_eq!(unpaid(&client), 4);
+
+    // Settling pays the rest without paying anyone twice
+    assert_eq!(client.settle_team_challenge(&challenge_id, &guild_id), 0);
+    assert_eq!(unpaid(&client), 0);
+    for member in members.iter() {
+        let status = client.get_user_challenge_status(&member, &challenge_id).unwrap();
+        assert_eq!(status.rank, 1);
+        assert_eq!(client.get_user_profile(&member).challenges_completed, 1);
+    }
+    assert_eq!(client.get_user_profile(&leader).challenges_completed, 1);
+}
+
+#[test]
+fn test_team_challenge_requires_guild_and_closes_after_completion() {
+    let (env, client, admin) = setup_env();
+    let leader = Address::generate(&env);
+    let latecomer = Address::generate(&env);
+
+    env.ledger().with_mut(|l| l.timestamp = 1_000_000);
+    let now = 1_000_000u64;
+
+    let challenge = make_challenge(&env, &admin, now);
+    let challenge_id = client.create_team_challenge(&admin, &challenge);
+
+    let result = client.try_join_challenge(&leader, &challenge_id);
+    assert_eq!(result, Err(Ok(GamificationError::NotInGuild)));
+
+    let guild_id = client.create_guild(
+        &leader,
+        &String::from_str(&env, "Solo Guild"),
+        &String::from_str(&env, "One member so far"),
+        &20u32,
+        &true,
+    );
+    client.join_challenge(&leader, &challenge_id);
+    // Progress reported above the target only counts up to it
+    assert!(client.update_challenge_progress(&leader, &challenge_id, &10u32));
+    assert_eq!(client.get_team_challenge_progress(&challenge_id, &guild_id), 3);
+
+    client.join_guild(&latecomer, &guild_id);
+    let result = client.try_join_challenge(&latecomer, &challenge_id);
+    assert_eq!(result, Err(Ok(GamificationError::TeamChallengeCompleted)));
+}
+
 // ─── Guild System ─────────────────────────────────────────────────────────────
 
 #[test]
@@ -498,6 +930,58 @@ fn test_guild_xp_accumulates_from_members() {
     assert!(guild.total_xp > 0, "guild should accumulate XP from member activity");
 }
 
+#[test]
+fn test_guild_xp_multiplier_boosts_members_only() {
+    let (env, client, admin) = setup_env();
+    let member = Address::generate(&env);
+    let outsider = Address::generate(&env);
+
+    let guild_id = client.create_guild(
+        &member,
+        &String::from_str(&env, "Boost Guild"),
+        &String::from_str(&env, "Guild with an XP bonus"),
+        &10u32,
+        &true,
+    );
+    client.set_guild_xp_multiplier(&admin, &guild_id, &120u32);
+    assert_eq!(client.get_guild_xp_multiplier(&guild_id), 120);
+
+    env.ledger().with_mut(|l| l.timestamp = 1_000_000);
+    client.record_activity(&member, &make_activity(&env, ActivityType::ModuleCompleted, 1_000_000));
+    client
+        .record_activity(&outsider, &make_activity(&env, ActivityType::ModuleCompleted, 1_000_000));
+
+    let member_xp = client.get_user_profile(&member).total_xp;
+    let outsider_xp = client.get_user_profile(&outsider).total_xp;
+    assert!(outsider_xp > 0);
+    assert_eq!(member_xp, outsider_xp * 120 / 100);
+}
+
+#[test]
+fn test_guild_xp_multiplier_cap_enforced() {
+    let (env, client, admin) = setup_env();
+    let creator = Address::generate(&env);
+
+    let guild_id = client.create_guild(
+        &creator,
+        &String::from_str(&env, "Capped Guild"),
+        &String::from_str(&env, "Guild testing the multiplier cap"),
+        &10u32,
+        &true,
+    );
+
+    let too_high = client.try_set_guild_xp_multiplier(&admin, &guild_id, &151u32);
+    assert_eq!(too_high, Err(Ok(GamificationError::InvalidInput)));
+    let below_base = client.try_set_guild_xp_multiplier(&admin, &guild_id, &90u32);
+    assert_eq!(below_base, Err(Ok(GamificationError::InvalidInput)));
+    let non_admin = client.try_set_guild_xp_multiplier(&creator, &guild_id, &110u32);
+    assert_eq!(non_admin, Err(Ok(GamificationError::Unauthorized)));
+    assert_eq!(client.get_guild_xp_multiplier(&guild_id), 100);
+
+    client.set_guild_xp_multiplier(&admin, &guild_id, &150u32);
+    assert_eq!(client.get_guild_xp_multiplier(&guild_id), 150);
+}
+
 #[test]
 fn test_cannot_join_two_guilds() {
     let (env, client, _admin) = setup_env();
@@ -527,6 +1011,91 @@ fn test_cannot_join_two_guilds() {
     assert!(result.is_err(), "cannot be in two guilds simultaneously");
 }
 
+/// Minimal stand-in for the platform token: `transfer(from, to, amount)` with auth.
+#[contract]
+struct MockTreasuryToken;
+
+#[contractimpl]
+impl MockTreasuryToken {
+    pub fn mint(env: Env, to: Address, amount: u64) {
+        let balance = Self::balance(env.clone(), to.clone());
+        env.storage().instance().set(&to, &(balance + amount));
+    }
+
+    pub fn balance(env: Env, account: Address) -> u64 {
+        env.storage().instance().get(&account).unwrap_or(0)
+    }
+
+    pub fn transfer(env: Env, from: Address, to: Address, amount: u64) {
+        from.require_auth();
+        let from_balance = Self::balance(env.clone(), from.clone());
+        assert!(from_balance >= amount, "insufficient balance");
+        env.storage().instance().set(&from, &(from_balance - amount));
+        Self::mint(env, to, amount);
+    }
+}
+
+fn setup_guild_treasury(
+    env: &Env,
+    client: &GamificationClient,
+    admin: &Address,
+) -> (MockTreasuryTokenClient<'static>, u64, Address, Address) {
+    let token_id = env.register(MockTreasuryToken, ());
+    let token = MockTreasuryTokenClient::new(env, &token_id);
+    client.set_guild_treasury_token(admin, &token_id);
+
+    let leader = Address::generate(env);
+    let member = Address::generate(env);
+    let guild_id = client.create_guild(
+        &leader,
+        &String::from_str(env, "Treasury Guild"),
+        &String::from_str(env, "Pooling tokens"),
+        &10u32,
+        &true,
+    );
+    client.join_guild(&member, &guild_id);
+    token.mint(&member, &500);
+    (token, guild_id, leader, member)
+}
+
+#[test]
+fn test_guild_treasury_contribute_and_spend() {
+    let (env, client, admin) = setup_env();
+    let (token, guild_id, leader, member) = setup_guild_treasury(&env, &client, &admin);
+
+    assert_eq!(client.contribute_to_guild(&member, &guild_id, &300), 300);
+    assert_eq!(client.get_guild_treasury(&guild_id), 300);
+    assert_eq!(token.balance(&member), 200);
+    assert_eq!(token.balance(&client.address), 300);
+
+    let recipient = Address::generate(&env);
+    assert_eq!(client.spend_guild_treasury(&leader, &guild_id, &recipient, &120), 180);
+    assert_eq!(client.get_guild_treasury(&guild_id), 180);
+    assert_eq!(token.balance(&recipient), 120);
+
+    let overspend = client.try_spend_guild_treasury(&leader, &guild_id, &recipient, &181);
+    assert_eq!(overspend, Err(Ok(GamificationError::InsufficientTreasury)));
+}
+
+#[test]
+fn test_guild_treasury_rejects_unauthorized_spends() {
+    let (env, client, admin) = setup_env();
+    let (_token, guild_id, _leader, member) = setup_guild_treasury(&env, &client, &admin);
+    client.contribute_to_guild(&member, &guild_id, &300);
+
+    let recipient = Address::generate(&env);
+    let by_member = client.try_spend_guild_treasury(&member, &guild_id, &recipient, &10);
+    assert_eq!(by_member, Err(Ok(GamificationError::Unauthorized)));
+
+    let outsider = Address::generate(&env);
+    let by_outsider = client.try_spend_guild_treasury(&outsider, &guild_id, &outsider, &10);
+    assert_eq!(by_outsider, Err(Ok(GamificationError::NotInGuild)));
+    let contribution = client.try_contribute_to_guild(&outsider, &guild_id, &10);
+    assert_eq!(contribution, Err(Ok(GamificationError::NotInGuild)));
+
+    assert_eq!(client.get_guild_treasury(&guild_id), 300);
+}
+
 // ─── Seasons ─────────────────────────────────────────────────────────────────
 
 #[test]
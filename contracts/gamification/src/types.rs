@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address, String};
+use soroban_sdk::{contracttype, Address, String, Vec};
 
 // ───────────────────────────────────────────────
 //  Achievement System
@@ -91,6 +91,27 @@ pub struct UserAchievement {
     pub token_reward: i128,
 }
 
+/// Skill-tree node gating an achievement behind other achievements.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct SkillNode {
+    /// Achievement awarded when this node unlocks; also the node's ID.
+    pub achievement_id: u64,
+    /// Node IDs that must be unlocked first. Each must be lower than `achievement_id`.
+    pub prerequisites: Vec<u64>,
+}
+
+/// A user's progress through the skill tree.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct SkillTreeProgress {
+    pub total_nodes: u32,
+    /// Node IDs whose achievement the user has earned.
+    pub unlocked_nodes: Vec<u64>,
+    /// Locked node IDs whose prerequisites are all unlocked.
+    pub available_nodes: Vec<u64>,
+}
+
 // ───────────────────────────────────────────────
 //  User Profile
 // ───────────────────────────────────────────────
@@ -265,6 +286,17 @@ pub enum ChallengeDifficulty {
     Legendary,
 }
 
+/// Whether challenge progress is tracked per user or pooled across a team.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum ChallengeMode {
+    /// Each participant progresses and completes on their own.
+    Solo,
+    /// Participants join through their guild; progress is pooled and every
+    /// joined member is rewarded when the team reaches the target.
+    Team,
+}
+
 /// A time-limited challenge or quest that users can join to earn rewards.
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[contracttype]
@@ -325,6 +357,18 @@ pub struct UserChallenge {
     pub rank: u32,
 }
 
+/// Completion record for a guild team on a team-mode challenge.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct TeamCompletion {
+    /// Completion rank shared by every member of the team.
+    pub rank: u32,
+    /// Unix timestamp (seconds) when the team reached the target.
+    pub completed_at: u64,
+    /// Index into the team's member list of the next member to be paid.
+    pub next_member: u32,
+}
+
 // ───────────────────────────────────────────────
 //  Guild / Team System
 // ───────────────────────────────────────────────
@@ -597,6 +641,16 @@ pub struct GamificationConfig {
     pub rate_limit_window: u64,
 }
 
+/// Optional policy that decays unclaimed achievement token rewards during inactivity.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct RewardDecayPolicy {
+    /// Seconds without activity after which one decay step is applied.
+    pub inactivity_period: u64,
+    /// Percentage (1–100) of each unclaimed reward removed per elapsed inactivity period.
+    pub decay_percent: u32,
+}
+
 // ───────────────────────────────────────────────
 //  Storage Keys
 // ───────────────────────────────────────────────
@@ -608,6 +662,7 @@ pub enum GamificationKey {
     // ── Admin / Config ──────────────────────────
     Admin,
     Config,
+    RewardDecayPolicy,
 
     // ── Counters ────────────────────────────────
     AchievementCounter,
@@ -621,6 +676,8 @@ pub enum GamificationKey {
     Achievement(u64),
     UserAchievement(Address, u64),
     UserAchievements(Address), // Vec<u64>
+    SkillNode(u64),            // achievement_id → SkillNode
+    SkillTreeNodes,            // Vec<u64>
 
     // ── User Profile ────────────────────────────
     UserProfile(Address),
@@ -628,18 +685,29 @@ pub enum GamificationKey {
     // ── Leaderboards ────────────────────────────
     Leaderboard(LeaderboardCategory), // Vec<LeaderboardEntry>
     GuildLeaderboard,                 // Vec<GuildLeaderboardEntry>
+    /// user → true when opted out of all leaderboards
+    LeaderboardHidden(Address),
 
     // ── Challenges ──────────────────────────────
     Challenge(u64),
     ActiveChallenges, // Vec<u64>
     UserChallenge(Address, u64),
-    UserActiveChallenges(Address), // Vec<u64>
-    ChallengeCompletionCount(u64), // u32 – how many finished this challenge
+    UserActiveChallenges(Address),     // Vec<u64>
+    ChallengeCompletionCount(u64),     // u32 – how many finished this challenge
+    TeamChallengeProgress(u64, u64),   // (challenge_id, guild_id) -> u32 pooled progress
+    TeamChallengeMembers(u64, u64),    // (challenge_id, guild_id) -> Vec<Address>
+    ChallengeModeOf(u64),              // challenge_id -> ChallengeMode (absent = Solo)
+    ChallengeTeam(Address, u64),       // (user, challenge_id) -> u64 guild_id
+    TeamChallengeCompletion(u64, u64), // (challenge_id, guild_id) -> TeamCompletion
 
     // ── Guilds ──────────────────────────────────
     Guild(u64),
-    GuildMember(Address), // Address → GuildMember
-    GuildMembers(u64),    // guild_id → Vec<Address>
+    GuildMember(Address),   // Address → GuildMember
+    GuildMembers(u64),      // guild_id → Vec<Address>
+    GuildXpMultiplier(u64), // guild_id → u32 (100 = 1×)
+    /// Token contract holding guild treasury funds
+    GuildTreasuryToken,
+    GuildTreasury(u64), // guild_id → u64 token balance
 
     // ── Seasons ─────────────────────────────────
     Season(u64),
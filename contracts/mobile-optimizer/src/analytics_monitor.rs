@@ -123,6 +123,30 @@ impl AnalyticsMonitor {
             .unwrap_or_else(|| Vec::new(env))
     }
 
+    pub fn get_reliability_stats(env: &Env, user: &Address) -> OperationReliabilityStats {
+        env.storage().persistent().get(&DataKey::ReliabilityStats(user.clone())).unwrap_or(
+            OperationReliabilityStats { retry_count: 0, failure_count: 0, dead_letter_count: 0 },
+        )
+    }
+
+    /// Add retry, failure, and dead-letter tallies from a batch run or offline sync.
+    pub fn record_reliability(
+        env: &Env,
+        user: &Address,
+        retries: u32,
+        failures: u32,
+        dead_letters: u32,
+    ) {
+        if retries == 0 && failures == 0 && dead_letters == 0 {
+            return;
+        }
+        let mut stats = Self::get_reliability_stats(env, user);
+        stats.retry_count += retries;
+        stats.failure_count += failures;
+        stats.dead_letter_count += dead_letters;
+        env.storage().persistent().set(&DataKey::ReliabilityStats(user.clone()), &stats);
+    }
+
     pub fn get_mobile_analytics(
         env: &Env,
         user: &Address,
@@ -152,6 +176,7 @@ impl AnalyticsMonitor {
         }
 
         network_dist.set(String::from_str(env, "good"), total_ops);
+        let reliability = Self::get_reliability_stats(env, user);
 
         let analytics = MobileAnalytics {
             user: user.clone(),
@@ -160,6 +185,9 @@ impl AnalyticsMonitor {
             total_operations: total_ops,
             successful_operations: successful,
             failed_operations: failed,
+            retry_count: reliability.retry_count,
+            failure_count: reliability.failure_count,
+            dead_letter_count: reliability.dead_letter_count,
             average_gas_used: if total_ops > 0 { 45000 } else { 0 },
             network_quality_distribution: network_dist,
             common_operation_types: Vec::new(env),
@@ -97,6 +97,8 @@ impl BatchManager {
 
         let sc = successful_ids.len();
         let fc = failed_ids.len();
+        // Operations are not retried within a batch, so every failure is dead-lettered
+        crate::analytics_monitor::AnalyticsMonitor::record_reliability(env, &batch.user, 0, fc, fc);
 
         BatchExecutionResult {
             batch_id: batch.batch_id.clone(),
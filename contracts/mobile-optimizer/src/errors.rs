@@ -78,4 +78,6 @@ pub enum MobileOptimizerError {
     CollaborationError = 36,
     /// A user experience or UI preference operation failed.
     UserExperienceError = 37,
+    /// The user already has the maximum number of open sessions.
+    MaxSessionsReached = 38,
 }
@@ -82,6 +82,9 @@ impl MobileOptimizerContract {
         config.validate()?;
 
         env.storage().persistent().set(&DataKey::Config, &config);
+        env.storage()
+            .persistent()
+            .set(&DataKey::SessionLimit, &SessionLimit::for_env(DeploymentEnv::Production));
         env.storage().persistent().set(&DataKey::Admin, &admin);
         env.storage().persistent().set(&DataKey::Initialized, &true);
         env.storage().persistent().set(&DataKey::TotalSessions, &0u64);
@@ -128,6 +131,43 @@ impl MobileOptimizerContract {
         Ok(())
     }
 
+    /// Set the per-user open session limit and what happens when it is reached.
+    ///
+    /// # Arguments
+    /// * `admin` - Address of the contract admin.
+    /// * `limit` - Maximum open sessions per user and the policy past that point.
+    ///
+    /// # Errors
+    /// Returns [`MobileOptimizerError::UnauthorizedAdmin`] if `admin` is not the stored admin.
+    /// Returns [`MobileOptimizerError::InvalidInput`] if `limit.max_sessions_per_user` is zero.
+    ///
+    /// # Example
+    /// ```ignore
+    /// client.set_session_limit(&admin, &limit);
+    /// ```
+    pub fn set_session_limit(
+        env: Env,
+        admin: Address,
+        limit: SessionLimit,
+    ) -> Result<(), MobileOptimizerError> {
+        Self::require_admin(&env, &admin)?;
+        if limit.max_sessions_per_user == 0 {
+            return Err(MobileOptimizerError::InvalidInput);
+        }
+        env.storage().persistent().set(&DataKey::SessionLimit, &limit);
+        Ok(())
+    }
+
+    /// Return the per-user open session limit.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let limit = client.get_session_limit();
+    /// ```
+    pub fn get_session_limit(env: Env) -> SessionLimit {
+        SessionManager::session_limit(&env)
+    }
+
     // ========================================================================
     // Session Management
     // ========================================================================
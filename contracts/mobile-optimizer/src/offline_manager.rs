@@ -49,13 +49,19 @@ impl OfflineManager {
         queue.last_sync_attempt = env.ledger().timestamp();
 
         let total_ops = queue.queued_operations.len();
+        let max_retries = Self::max_sync_retries(env);
         let mut successful = 0u32;
         let mut failed = 0u32;
         let mut conflicts = 0u32;
+        let mut retries = 0u32;
+        let mut dead_letters = 0u32;
 
         let mut updated_ops = Vec::new(env);
         for op in queue.queued_operations.iter() {
             let mut o: QueuedOperation = op.clone();
+            if Self::record_attempt(&mut o) {
+                retries += 1;
+            }
             match Self::sync_single_operation(env, &o) {
                 Ok(()) => {
                     o.status = QueuedOperationStatus::Synced;
@@ -66,13 +72,22 @@ impl OfflineManager {
                     conflicts += 1;
                 }
                 Err(_) => {
-                    o.status = QueuedOperationStatus::Failed;
                     failed += 1;
+                    if Self::record_failure(&mut o, max_retries) {
+                        dead_letters += 1;
+                    }
                 }
             }
             updated_ops.push_back(o);
         }
         queue.queued_operations = updated_ops;
+        crate::analytics_monitor::AnalyticsMonitor::record_reliability(
+            env,
+            &user,
+            retries,
+            failed,
+            dead_letters,
+        );
 
         queue.sync_status = if conflicts > 0 {
             SyncStatus::Conflicts
@@ -232,6 +247,33 @@ impl OfflineManager {
         Ok(())
     }
 
+    /// Note that a sync is about to submit `operation`. Resubmitting an
+    /// operation whose last attempt failed is a retry; returns whether it is one.
+    pub(crate) fn record_attempt(operation: &mut QueuedOperation) -> bool {
+        if operation.status != QueuedOperationStatus::Failed {
+            return false;
+        }
+        operation.retry_count += 1;
+        true
+    }
+
+    /// Mark `operation` failed. Returns whether this failure used up its last
+    /// allowed retry, which is counted once as a dead letter; later syncs still
+    /// resubmit it like any other failed operation.
+    pub(crate) fn record_failure(operation: &mut QueuedOperation, max_retries: u32) -> bool {
+        operation.status = QueuedOperationStatus::Failed;
+        operation.retry_count == max_retries
+    }
+
+    /// Retry allowance for queued operations, from the contract config.
+    fn max_sync_retries(env: &Env) -> u32 {
+        env.storage()
+            .persistent()
+            .get::<DataKey, MobileOptimizerConfig>(&DataKey::Config)
+            .map(|config| config.retry_attempts)
+            .unwrap_or(0)
+    }
+
     fn create_empty_queue(env: &Env, user: &Address, device_id: &String) -> OfflineQueue {
         OfflineQueue {
             user: user.clone(),
@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Vec};
+use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol, Vec};
 
 use crate::types::*;
 
@@ -289,7 +289,7 @@ impl PwaManager {
         notification_id: &String,
     ) -> Result<(), MobileOptimizerError> {
         let key = PwaExtKey::PushQueue(recipient.clone());
-        let mut queue: Vec<PushNotification> = env
+        let queue: Vec<PushNotification> = env
             .storage()
             .persistent()
             .get(&key)
@@ -323,7 +323,7 @@ impl PwaManager {
         notification_id: &String,
     ) -> Result<(), MobileOptimizerError> {
         let key = PwaExtKey::PushQueue(recipient.clone());
-        let mut queue: Vec<PushNotification> = env
+        let queue: Vec<PushNotification> = env
             .storage()
             .persistent()
             .get(&key)
@@ -362,7 +362,7 @@ impl PwaManager {
         let now = env.ledger().timestamp();
         let mut pending: Vec<PushNotification> = Vec::new(env);
         for n in queue.iter() {
-            let not_expired = n.expires_at.map_or(true, |exp| now < exp);
+            let not_expired = n.expires_at.is_none_or(|exp| now < exp);
             if !n.read && not_expired {
                 pending.push_back(n);
             }
@@ -405,7 +405,7 @@ impl PwaManager {
         outcome: InstallPromptOutcome,
     ) -> Result<(), MobileOptimizerError> {
         let key = PwaExtKey::InstallHistory(user.clone());
-        let mut history: Vec<InstallPromptRecord> = env
+        let history: Vec<InstallPromptRecord> = env
             .storage()
             .persistent()
             .get(&key)
@@ -441,7 +441,7 @@ impl PwaManager {
         env.storage().persistent().set(&PwaExtKey::Metrics, &metrics);
 
         env.events().publish(
-            (symbol_short!("pwa_install"), user.clone()),
+            (Symbol::new(env, "pwa_install"), user.clone()),
             outcome == InstallPromptOutcome::Accepted,
         );
 
@@ -1,3 +1,4 @@
+use shared::config::DeploymentEnv;
 use soroban_sdk::{contracttype, Address, Env, Map, String, Vec};
 
 use crate::types::*;
@@ -13,13 +14,100 @@ impl SessionManager {
             .unwrap_or(3600)
     }
 
+    /// Open session limit; contracts initialized before the limit existed
+    /// fall back to the production default.
+    pub fn session_limit(env: &Env) -> SessionLimit {
+        env.storage()
+            .persistent()
+            .get(&DataKey::SessionLimit)
+            .unwrap_or_else(|| SessionLimit::for_env(DeploymentEnv::Production))
+    }
+
+    /// Enforce the per-user open session limit before a new session is stored,
+    /// ending the oldest open session when the policy allows eviction.
+    fn ensure_session_capacity(env: &Env, user: &Address) -> Result<(), MobileOptimizerError> {
+        let SessionLimit { max_sessions_per_user: limit, policy } = Self::session_limit(env);
+        let open = Self::open_sessions(env, user);
+        if open.len() < limit {
+            return Ok(());
+        }
+
+        match policy {
+            SessionLimitPolicy::Reject => Err(MobileOptimizerError::MaxSessionsReached),
+            SessionLimitPolicy::EvictOldest => {
+                // Sessions are appended in creation order, so the front is the oldest
+                for i in 0..=(open.len() - limit) {
+                    if let Some(mut session) = open.get(i) {
+                        session.session_state = SessionState::Expired;
+                        env.storage()
+                            .persistent()
+                            .set(&DataKey::MobileSession(session.session_id.clone()), &session);
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// A user's sessions that have neither been ended nor timed out, oldest first.
+    fn open_sessions(env: &Env, user: &Address) -> Vec<MobileSession> {
+        let now = env.ledger().timestamp();
+        let sessions: Vec<String> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::UserSessions(user.clone()))
+            .unwrap_or_else(|| Vec::new(env));
+
+        let mut open = Vec::new(env);
+        for sid in sessions.iter() {
+            if let Some(session) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, MobileSession>(&DataKey::MobileSession(sid))
+            {
+                if session.session_state != SessionState::Expired && session.expires_at > now {
+                    open.push_back(session);
+                }
+            }
+        }
+        open
+    }
+
+    /// Build a unique session ID of the form `session_<n>`.
+    fn generate_session_id(env: &Env) -> String {
+        let nonce: u64 = env.storage().persistent().get(&DataKey::SessionNonce).unwrap_or(0) + 1;
+        env.storage().persistent().set(&DataKey::SessionNonce, &nonce);
+
+        let prefix = b"session_";
+        let mut buf = [0u8; 28];
+        buf[..prefix.len()].copy_from_slice(prefix);
+
+        let mut digits = [0u8; 20];
+        let mut len = 0;
+        let mut n = nonce;
+        loop {
+            digits[len] = b'0' + (n % 10) as u8;
+            len += 1;
+            n /= 10;
+            if n == 0 {
+                break;
+            }
+        }
+        for i in 0..len {
+            buf[prefix.len() + i] = digits[len - 1 - i];
+        }
+
+        String::from_bytes(env, &buf[..prefix.len() + len])
+    }
+
     pub fn create_session(
         env: &Env,
         user: Address,
         device_id: String,
         preferences: MobilePreferences,
     ) -> Result<String, MobileOptimizerError> {
-        let session_id = String::from_str(env, "session");
+        Self::ensure_session_capacity(env, &user)?;
+        let session_id = Self::generate_session_id(env);
 
         let session = MobileSession {
             session_id: session_id.clone(),
@@ -172,7 +260,8 @@ impl SessionManager {
             return Err(MobileOptimizerError::Unauthorized);
         }
 
-        let target_session_id = String::from_str(env, "synced_session");
+        Self::ensure_session_capacity(env, user)?;
+        let target_session_id = Self::generate_session_id(env);
         let target = MobileSession {
             session_id: target_session_id.clone(),
             user: user.clone(),
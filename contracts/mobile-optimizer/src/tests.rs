@@ -1,5 +1,8 @@
+use crate::errors::MobileOptimizerError;
+use crate::offline_manager::OfflineManager;
 use crate::types::*;
 use crate::{MobileOptimizerContract, MobileOptimizerContractClient};
+use shared::config::DeploymentEnv;
 use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, Map, String, Vec};
 
 fn setup_contract() -> (Env, MobileOptimizerContractClient<'static>, Address, Address) {
@@ -137,6 +140,72 @@ fn test_session_stats() {
     assert_eq!(stats.active_sessions, 2);
 }
 
+fn set_session_limit(
+    client: &MobileOptimizerContractClient,
+    admin: &Address,
+    limit: u32,
+    policy: SessionLimitPolicy,
+) {
+    client.set_session_limit(admin, &SessionLimit { max_sessions_per_user: limit, policy });
+    assert_eq!(client.get_session_limit().max_sessions_per_user, limit);
+}
+
+#[test]
+fn test_session_limit_rejects_until_a_session_is_closed() {
+    let (env, client, admin, user) = setup_contract();
+    set_session_limit(&client, &admin, 2, SessionLimitPolicy::Reject);
+    let prefs = default_preferences();
+
+    let first = client.create_session(&user, &String::from_str(&env, "d1"), &prefs);
+    let second = client.create_session(&user, &String::from_str(&env, "d2"), &prefs);
+    assert_ne!(first, second);
+
+    let result = client.try_create_session(&user, &String::from_str(&env, "d3"), &prefs);
+    assert_eq!(result, Err(Ok(MobileOptimizerError::MaxSessionsReached)));
+
+    // Other users are unaffected
+    let other = Address::generate(&env);
+    client.create_session(&other, &String::from_str(&env, "d4"), &prefs);
+
+    client.end_session(&user, &first);
+    client.create_session(&user, &String::from_str(&env, "d3"), &prefs);
+    assert_eq!(client.get_session_stats(&user).active_sessions, 2);
+}
+
+#[test]
+fn test_session_limit_evicts_oldest_session() {
+    let (env, client, admin, user) = setup_contract();
+    set_session_limit(&client, &admin, 2, SessionLimitPolicy::EvictOldest);
+    let prefs = default_preferences();
+
+    let first = client.create_session(&user, &String::from_str(&env, "d1"), &prefs);
+    let second = client.create_session(&user, &String::from_str(&env, "d2"), &prefs);
+    let third = client.create_session(&user, &String::from_str(&env, "d3"), &prefs);
+
+    assert_eq!(client.get_session(&user, &first).session_state, SessionState::Expired);
+    assert_eq!(client.get_session(&user, &second).session_state, SessionState::Active);
+    assert_eq!(client.get_session(&user, &third).session_state, SessionState::Active);
+    assert_eq!(client.get_session_stats(&user).active_sessions, 2);
+}
+
+#[test]
+fn test_session_limit_defaults_when_not_stored() {
+    let (env, client, admin, _) = setup_contract();
+    assert_eq!(client.get_session_limit(), SessionLimit::for_env(DeploymentEnv::Production));
+
+    // Contracts initialized before the limit existed have no stored value
+    env.as_contract(&client.address, || {
+        env.storage().persistent().remove(&DataKey::SessionLimit);
+    });
+    let limit = client.get_session_limit();
+    assert_eq!(limit.max_sessions_per_user, 10);
+    assert_eq!(limit.policy, SessionLimitPolicy::Reject);
+
+    let zero = SessionLimit { max_sessions_per_user: 0, policy: SessionLimitPolicy::Reject };
+    let result = client.try_set_session_limit(&admin, &zero);
+    assert_eq!(result, Err(Ok(MobileOptimizerError::InvalidInput)));
+}
+
 #[test]
 fn test_session_optimization() {
     let (env, client, _, user) = setup_contract();
@@ -818,6 +887,119 @@ fn test_gas_tips() {
     assert!(tips.len() >= 5);
 }
 
+// ============================================================================
+// Reliability Analytics Tests
+// ============================================================================
+
+fn batch_op(env: &Env, id: &str) -> BatchOperation {
+    BatchOperation {
+        operation_id: String::from_str(env, id),
+        operation_type: OperationType::ProgressUpdate,
+        contract_address: Address::generate(env),
+        function_name: String::from_str(env, "update"),
+        parameters: Vec::new(env),
+        estimated_gas: 50000,
+        priority: OperationPriority::Medium,
+        retry_config: RetryConfig {
+            max_retries: 3,
+            retry_delay_ms: 100,
+            backoff_multiplier: 2,
+            max_delay_ms: 1000,
+            retry_on_network_error: true,
+            retry_on_gas_error: true,
+            retry_on_timeout: true,
+        },
+        dependencies: Vec::new(env),
+    }
+}
+
+fn queued_op(env: &Env, id: &str, status: QueuedOperationStatus) -> QueuedOperation {
+    QueuedOperation {
+        operation_id: String::from_str(env, id),
+        operation_type: OperationType::ProgressUpdate,
+        parameters: Vec::new(env),
+        created_at: 1000,
+        priority: BatchPriority::Normal,
+        local_state_hash: BytesN::from_array(env, &[0u8; 32]),
+        retry_count: 0,
+        status,
+        estimated_gas: 50000,
+    }
+}
+
+#[test]
+fn test_successful_batch_records_no_failures() {
+    let (env, client, _, user) = setup_contract();
+    let mut ops = Vec::new(&env);
+    ops.push_back(batch_op(&env, "ok_op"));
+
+    let batch_id =
+        client.create_batch(&user, &ops, &BatchPriority::Normal, &ExecutionStrategy::Sequential);
+    let result = client.execute_batch(&user, &batch_id);
+    assert_eq!(result.failed_count, 0);
+
+    let device = String::from_str(&env, "device_1");
+    let analytics = client.get_mobile_analytics(&user, &device, &0, &999999);
+    assert_eq!(analytics.retry_count, 0);
+    assert_eq!(analytics.failure_count, 0);
+    assert_eq!(analytics.dead_letter_count, 0);
+}
+
+#[test]
+fn test_failed_offline_operation_dead_lettered_after_retries() {
+    let env = Env::default();
+    let mut op = queued_op(&env, "flaky_op", QueuedOperationStatus::Queued);
+
+    // The first attempt is not a retry; its failure leaves the operation failed
+    assert!(!OfflineManager::record_attempt(&mut op));
+    assert!(!OfflineManager::record_failure(&mut op, 2));
+    assert_eq!(op.status, QueuedOperationStatus::Failed);
+    assert_eq!(op.retry_count, 0);
+
+    // Each resubmission of a failed operation is a retry
+    assert!(OfflineManager::record_attempt(&mut op));
+    assert!(!OfflineManager::record_failure(&mut op, 2));
+    assert!(OfflineManager::record_attempt(&mut op));
+    assert_eq!(op.retry_count, 2);
+
+    // Failing the last allowed retry dead-letters it, once
+    assert!(OfflineManager::record_failure(&mut op, 2));
+    assert!(OfflineManager::record_attempt(&mut op));
+    assert!(!OfflineManager::record_failure(&mut op, 2));
+
+    // Without a retry allowance the first failure is a dead letter
+    let mut op = queued_op(&env, "fragile_op", QueuedOperationStatus::Queued);
+    assert!(OfflineManager::record_failure(&mut op, 0));
+}
+
+#[test]
+fn test_offline_sync_counts_resubmitted_failures_as_retries() {
+    let (env, client, _, user) = setup_contract();
+    let device_id = String::from_str(&env, "device_1");
+    client.queue_offline_operation(
+        &user,
+        &device_id,
+        &queued_op(&env, "failed_op", QueuedOperationStatus::Failed),
+    );
+    client.queue_offline_operation(
+        &user,
+        &device_id,
+        &queued_op(&env, "new_op", QueuedOperationStatus::Queued),
+    );
+
+    // Failed operations are resubmitted as before
+    let result = client.sync_offline_operations(&user, &device_id);
+    assert_eq!(result.successful_syncs, 2);
+    assert_eq!(result.failed_syncs, 0);
+    assert_eq!(result.sync_status, SyncStatus::InSync);
+
+    // Only the resubmitted failure counts as a retry
+    let analytics = client.get_mobile_analytics(&user, &device_id, &0, &999999);
+    assert_eq!(analytics.retry_count, 1);
+    assert_eq!(analytics.failure_count, 0);
+    assert_eq!(analytics.dead_letter_count, 0);
+}
+
 // ============================================================================
 // Network Tests
 // ============================================================================
@@ -419,6 +419,35 @@ pub struct MobileOptimizerConfig {
     pub analytics_retention_days: u32,
 }
 
+/// Per-user open session limit, stored apart from [`MobileOptimizerConfig`].
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SessionLimit {
+    /// Maximum number of open (non-expired) sessions per user.
+    pub max_sessions_per_user: u32,
+    /// What happens when a user opens a session past `max_sessions_per_user`.
+    pub policy: SessionLimitPolicy,
+}
+
+impl SessionLimit {
+    pub fn for_env(profile: DeploymentEnv) -> Self {
+        Self {
+            max_sessions_per_user: ContractConfig::mobile(profile).max_sessions_per_user,
+            policy: SessionLimitPolicy::Reject,
+        }
+    }
+}
+
+/// Behaviour when a user reaches their open session limit.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SessionLimitPolicy {
+    /// Refuse to open the new session.
+    Reject,
+    /// End the user's oldest open session to make room.
+    EvictOldest,
+}
+
 impl MobileOptimizerConfig {
     pub fn for_env(admin: Address, profile: DeploymentEnv) -> Self {
         let defaults = ContractConfig::mobile(profile);
@@ -1484,6 +1513,12 @@ pub struct MobileAnalytics {
     pub successful_operations: u32,
     /// Number of operations that failed.
     pub failed_operations: u32,
+    /// Retry attempts made by the batch and offline managers.
+    pub retry_count: u32,
+    /// Failed execution or sync attempts in the batch and offline managers.
+    pub failure_count: u32,
+    /// Operations that failed after using up their allowed retries.
+    pub dead_letter_count: u32,
     /// Average gas consumed per operation.
     pub average_gas_used: u64,
     /// Distribution of sessions by network quality label.
@@ -1498,6 +1533,18 @@ pub struct MobileAnalytics {
     pub period_end: u64,
 }
 
+/// Running retry and failure tallies for a user's batch and offline operations.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OperationReliabilityStats {
+    /// Retry attempts made after a failed attempt.
+    pub retry_count: u32,
+    /// Failed execution or sync attempts.
+    pub failure_count: u32,
+    /// Operations that failed after using up their allowed retries.
+    pub dead_letter_count: u32,
+}
+
 /// Per-type performance statistics for operations.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -1738,6 +1785,8 @@ pub enum DataKey {
     Admin,
     /// Global mobile optimizer configuration.
     Config,
+    /// Per-user open session limit.
+    SessionLimit,
     /// Contract initialization flag.
     Initialized,
     /// A transaction batch by its ID.
@@ -1774,6 +1823,8 @@ pub enum DataKey {
     AnalyticsDashboard,
     /// Running total of sessions.
     TotalSessions,
+    /// Sequence number used to build unique session IDs.
+    SessionNonce,
     /// Running total of batches submitted.
     TotalBatches,
     /// Running total of offline operations queued.
@@ -1804,6 +1855,8 @@ pub enum DataKey {
     UserFeedbackHistory(Address),
     /// Analytics events for a user.
     AnalyticsEvents(Address),
+    /// Retry, failure, and dead-letter tallies for a user.
+    ReliabilityStats(Address),
     /// Performance log for a session by session ID.
     PerformanceLog(String),
     /// Battery optimization configuration for a user.
@@ -2213,8 +2266,9 @@ pub struct PaginationParams {
 }
 
 /// A paginated wrapper for large datasets.
-#[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
+///
+/// Only used inside the contract, so it is not a `contracttype`; those cannot
+/// be generic.
 pub struct PaginatedResult<T> {
     /// The chunk of items for the current page.
     pub items: Vec<T>,
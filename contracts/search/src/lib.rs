@@ -13,9 +13,11 @@ mod collaborative_filter;
 mod content_analyzer;
 mod learning_path_optimizer;
 mod multilingual_search;
+mod query_parser;
 mod ranking_engine;
 mod recommendation_engine;
 mod search_analytics;
+mod search_cache;
 mod semantic_search;
 mod types;
 mod visual_search;
@@ -28,8 +30,10 @@ use collaborative_filter::CollaborativeFilter;
 use content_analyzer::ContentAnalyzer;
 use learning_path_optimizer::LearningPathOptimizer;
 use multilingual_search::MultilingualSearch;
+use query_parser::QueryParser;
 use ranking_engine::RankingEngine;
 use recommendation_engine::RecommendationEngine;
+use search_cache::SearchCache;
 use semantic_search::SemanticSearch;
 use visual_search::VisualSearch;
 use voice_search::VoiceSearch;
@@ -54,15 +58,7 @@ impl AdvancedSearchContract {
         env.storage().instance().set(&DataKey::Initialized, &true);
 
         // Initialize default ranking configuration
-        let default_config = RankingConfig {
-            relevance_weight: 30,
-            quality_weight: 20,
-            engagement_weight: 15,
-            recency_weight: 10,
-            personalization_weight: 15,
-            authority_weight: 10,
-        };
-        RankingEngine::store_ranking_config(&env, default_config);
+        RankingEngine::store_ranking_config(&env, RankingEngine::default_ranking_config());
 
         Ok(())
     }
@@ -70,6 +66,10 @@ impl AdvancedSearchContract {
     // ==================== Semantic Search Functions ====================
 
     /// Execute semantic search with NLP-enhanced query understanding
+    ///
+    /// When the result cache is enabled, a repeated query within the TTL is
+    /// served from cache until the data it was computed from changes.
+    /// Admin-only content types are returned only when `user` is the admin.
     pub fn semantic_search(
         env: Env,
         query: ProcessedQuery,
@@ -78,7 +78,19 @@ impl AdvancedSearchContract {
     ) -> Result<Vec<SearchResultItem>, Error> {
         Self::require_initialized(&env)?;
 
-        Ok(SemanticSearch::search(&env, query, filters, user))
+        let include_admin_only = match &user {
+            Some(caller) => Self::require_admin(&env, caller).is_ok(),
+            None => false,
+        };
+
+        let cache_key = SearchCache::query_key(&env, &query, &filters, &user);
+        if let Some(results) = SearchCache::get(&env, &cache_key) {
+            return Ok(results);
+        }
+
+        let results = SemanticSearch::search(&env, query, filters, user, include_admin_only);
+        SearchCache::store(&env, &cache_key, &results);
+        Ok(results)
     }
 
     /// Store semantic metadata from oracle (off-chain NLP service)
@@ -95,6 +107,41 @@ impl AdvancedSearchContract {
         Ok(())
     }
 
+    /// Store semantic metadata from oracle together with the kind of document
+    /// it describes, so admin-only types are hidden from public search
+    pub fn store_typed_semantic_metadata(
+        env: Env,
+        oracle: Address,
+        content_id: String,
+        metadata: SemanticMetadata,
+        content_type: SearchResultType,
+    ) -> Result<(), Error> {
+        Self::require_initialized(&env)?;
+        Self::require_authorized_oracle(&env, &oracle)?;
+
+        SemanticSearch::store_content_type(&env, &content_id, content_type);
+        SemanticSearch::store_semantic_metadata(&env, content_id, metadata);
+        Ok(())
+    }
+
+    /// Update per-content-type indexing flags (admin only)
+    pub fn update_content_type_indexing(
+        env: Env,
+        admin: Address,
+        config: ContentTypeIndexing,
+    ) -> Result<(), Error> {
+        Self::require_initialized(&env)?;
+        Self::require_admin(&env, &admin)?;
+
+        SemanticSearch::store_content_type_indexing(&env, config);
+        Ok(())
+    }
+
+    /// Get per-content-type indexing flags
+    pub fn get_content_type_indexing(env: Env) -> ContentTypeIndexing {
+        SemanticSearch::get_content_type_indexing(&env)
+    }
+
     // ==================== Recommendation Functions ====================
 
     /// Generate personalized recommendations
@@ -356,6 +403,43 @@ impl AdvancedSearchContract {
         Ok(())
     }
 
+    /// Get the ranking weights currently in use
+    pub fn get_ranking_config(env: Env) -> RankingConfig {
+        RankingEngine::get_ranking_config(&env)
+    }
+
+    /// Restore the default ranking weights set at initialize (admin only)
+    pub fn reset_search_weights(env: Env, admin: Address) -> Result<(), Error> {
+        Self::require_initialized(&env)?;
+        Self::require_admin(&env, &admin)?;
+
+        let config = RankingEngine::default_ranking_config();
+        RankingEngine::store_ranking_config(&env, config.clone());
+        env.events().publish((soroban_sdk::symbol_short!("rank_rst"),), (admin, config));
+        Ok(())
+    }
+
+    // ==================== Search Cache Functions ====================
+
+    /// Update search result cache settings (admin only)
+    pub fn update_search_cache_config(
+        env: Env,
+        admin: Address,
+        config: SearchCacheConfig,
+    ) -> Result<(), Error> {
+        Self::require_initialized(&env)?;
+        Self::require_admin(&env, &admin)?;
+
+        SearchCache::store_config(&env, config);
+        SearchCache::invalidate(&env);
+        Ok(())
+    }
+
+    /// Get search result cache settings
+    pub fn get_search_cache_config(env: Env) -> SearchCacheConfig {
+        SearchCache::get_config(&env)
+    }
+
     // ==================== Multilingual Functions ====================
 
     /// Store multilingual content from oracle
@@ -386,6 +470,9 @@ impl AdvancedSearchContract {
     }
 
     /// Search by language
+    ///
+    /// `query` supports `"quoted phrases"`, implicit AND between terms and
+    /// explicit `OR`; a blank query returns all content in `language`.
     pub fn search_by_language(
         env: Env,
         language: Language,
@@ -393,7 +480,8 @@ impl AdvancedSearchContract {
     ) -> Result<Vec<String>, Error> {
         Self::require_initialized(&env)?;
 
-        Ok(MultilingualSearch::search_by_language(&env, language, query))
+        let parsed = QueryParser::parse(&env, &query)?;
+        Ok(MultilingualSearch::search_by_language(&env, language, &parsed))
     }
 
     // ==================== Analytics Functions ====================
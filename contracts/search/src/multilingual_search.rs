@@ -1,3 +1,4 @@
+use crate::query_parser::QueryParser;
 use crate::types::*;
 use soroban_sdk::{Address, Env, String, Vec};
 
@@ -55,13 +56,38 @@ impl MultilingualSearch {
     }
 
     /// Search content in specific language
-    pub fn search_by_language(env: &Env, language: Language, _query: String) -> Vec<String> {
+    ///
+    /// Content matches when `query` matches its translated title or
+    /// description in `language`; content without that translation only
+    /// matches a blank query.
+    pub fn search_by_language(env: &Env, language: Language, query: &ParsedQuery) -> Vec<String> {
         let lang_key = Self::language_index_key(env, &language);
-
-        env.storage()
+        let content_ids = env
+            .storage()
             .persistent()
             .get::<String, Vec<String>>(&lang_key)
-            .unwrap_or_else(|| Vec::new(env))
+            .unwrap_or_else(|| Vec::new(env));
+
+        if query.groups.is_empty() {
+            return content_ids;
+        }
+
+        let mut matching = Vec::new(env);
+        for content_id in content_ids.iter() {
+            if let Some(translation) =
+                Self::get_translation_metadata(env, content_id.clone(), language.clone())
+            {
+                let fields = Vec::from_array(
+                    env,
+                    [translation.translated_title, translation.translated_description],
+                );
+                if QueryParser::matches(query, &fields) {
+                    matching.push_back(content_id);
+                }
+            }
+        }
+
+        matching
     }
 
     /// Find content available in user's preferred language
@@ -255,7 +281,8 @@ impl MultilingualSearch {
         // Add content from fallback languages
         for i in 0..fallback_langs.len() {
             if let Some(lang) = fallback_langs.get(i) {
-                let lang_content = Self::search_by_language(env, lang, String::from_str(env, ""));
+                let lang_content =
+                    Self::search_by_language(env, lang, &QueryParser::match_all(env));
 
                 for j in 0..lang_content.len() {
                     if let Some(content_id) = lang_content.get(j) {
@@ -0,0 +1,197 @@
+use crate::errors::Error;
+use crate::types::*;
+use soroban_sdk::{xdr::ToXdr, Env, String, Vec};
+
+/// Longest raw query text accepted by the parser, in bytes
+const MAX_QUERY_BYTES: usize = 256;
+/// Longest prefix of a field that is matched against, in bytes
+const MAX_FIELD_BYTES: usize = 512;
+
+/// Text Query Parser
+/// Parses `"quoted phrases"`, implicit AND between terms, and explicit `OR`
+/// into a `ParsedQuery` and matches it against indexed text fields
+pub struct QueryParser;
+
+impl QueryParser {
+    /// Parse raw query text into OR-separated groups of AND-ed terms
+    ///
+    /// Terms are lowercased and split on punctuation; a quoted phrase stays
+    /// a single multi-word term. A blank query parses to no groups and
+    /// matches everything.
+    pub fn parse(env: &Env, query: &String) -> Result<ParsedQuery, Error> {
+        let len = query.len() as usize;
+        if len > MAX_QUERY_BYTES {
+            return Err(Error::InvalidQuery);
+        }
+
+        let mut raw = [0u8; MAX_QUERY_BYTES];
+        query.copy_into_slice(&mut raw[..len]);
+        let raw = &raw[..len];
+
+        let mut groups = Vec::new(env);
+        let mut current = Vec::new(env);
+        let mut i = 0;
+
+        while i < len {
+            let byte = raw[i];
+            if byte.is_ascii_whitespace() {
+                i += 1;
+                continue;
+            }
+
+            if byte == b'"' {
+                let start = i + 1;
+                let end = start
+                    + raw[start..].iter().position(|b| *b == b'"').ok_or(Error::InvalidQuery)?;
+                Self::push_term(env, &mut current, &raw[start..end]);
+                i = end + 1;
+                continue;
+            }
+
+            let start = i;
+            while i < len && !raw[i].is_ascii_whitespace() && raw[i] != b'"' {
+                i += 1;
+            }
+
+            match &raw[start..i] {
+                b"OR" => {
+                    if !current.is_empty() {
+                        groups.push_back(QueryGroup { terms: current });
+                        current = Vec::new(env);
+                    }
+                }
+                b"AND" => {}
+                word => Self::push_term(env, &mut current, word),
+            }
+        }
+
+        if !current.is_empty() {
+            groups.push_back(QueryGroup { terms: current });
+        }
+
+        Ok(ParsedQuery { groups })
+    }
+
+    /// Query that matches every item, used when no text filter applies
+    pub fn match_all(env: &Env) -> ParsedQuery {
+        ParsedQuery { groups: Vec::new(env) }
+    }
+
+    /// Check whether any group has all of its terms in one of `fields`
+    ///
+    /// Each term must occur as whole words within a single field, so a
+    /// phrase only matches where its words appear contiguously.
+    pub fn matches(query: &ParsedQuery, fields: &Vec<String>) -> bool {
+        if query.groups.is_empty() {
+            return true;
+        }
+
+        for group in query.groups.iter() {
+            let all_terms_found = group
+                .terms
+                .iter()
+                .all(|term| fields.iter().any(|field| Self::field_contains(&field, &term)));
+            if all_terms_found {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Normalize `bytes` and append them to `terms` unless nothing is left
+    fn push_term(env: &Env, terms: &mut Vec<String>, bytes: &[u8]) {
+        let mut normalized = [0u8; MAX_QUERY_BYTES];
+        let len = Self::normalize(bytes, &mut normalized);
+        if len > 0 {
+            terms.push_back(String::from_bytes(env, &normalized[..len]));
+        }
+    }
+
+    /// Whole-word, case-insensitive search for a normalized `term` in `field`
+    ///
+    /// Only the first `MAX_FIELD_BYTES` bytes of a longer field are searched,
+    /// and a word cut off at that limit is dropped so it cannot match as a
+    /// shorter word.
+    fn field_contains(field: &String, term: &String) -> bool {
+        let term_len = term.len() as usize;
+        if term_len > MAX_QUERY_BYTES {
+            return false;
+        }
+
+        let mut raw_field = [0u8; MAX_FIELD_BYTES + 1];
+        let mut field_len = Self::copy_prefix(field, &mut raw_field);
+        if field_len > MAX_FIELD_BYTES {
+            if Self::is_word_byte(raw_field[MAX_FIELD_BYTES]) {
+                while field_len > 0 && Self::is_word_byte(raw_field[field_len - 1]) {
+                    field_len -= 1;
+                }
+            }
+            field_len = field_len.min(MAX_FIELD_BYTES);
+        }
+
+        let mut normalized = [0u8; MAX_FIELD_BYTES];
+        let len = Self::normalize(&raw_field[..field_len], &mut normalized);
+        let haystack = &normalized[..len];
+
+        let mut needle = [0u8; MAX_QUERY_BYTES];
+        term.copy_into_slice(&mut needle[..term_len]);
+        let needle = &needle[..term_len];
+
+        if needle.is_empty() || needle.len() > haystack.len() {
+            return false;
+        }
+
+        (0..=haystack.len() - needle.len()).any(|start| {
+            let end = start + needle.len();
+            haystack[start..end] == *needle
+                && (start == 0 || haystack[start - 1] == b' ')
+                && (end == haystack.len() || haystack[end] == b' ')
+        })
+    }
+
+    /// Copy the leading bytes of `text` into `out`, returning how many were copied
+    fn copy_prefix(text: &String, out: &mut [u8]) -> usize {
+        let text_len = text.len() as usize;
+        if text_len <= out.len() {
+            text.copy_into_slice(&mut out[..text_len]);
+            return text_len;
+        }
+
+        // String has no partial copy; its XDR form is an 8-byte header
+        // (value type and length) followed by the raw bytes
+        let xdr = text.clone().to_xdr(text.env());
+        xdr.slice(8..8 + out.len() as u32).copy_into_slice(out);
+        out.len()
+    }
+
+    /// Bytes that belong to a word rather than separate words
+    fn is_word_byte(byte: u8) -> bool {
+        byte.is_ascii_alphanumeric() || !byte.is_ascii()
+    }
+
+    /// Lowercase ASCII and collapse separator runs into single spaces
+    ///
+    /// Non-ASCII bytes are kept as word characters so UTF-8 text in other
+    /// languages still tokenizes on its ASCII spacing and punctuation.
+    fn normalize(src: &[u8], out: &mut [u8]) -> usize {
+        let mut len = 0;
+        let mut pending_space = false;
+
+        for byte in src {
+            if Self::is_word_byte(*byte) {
+                if pending_space && len > 0 {
+                    out[len] = b' ';
+                    len += 1;
+                }
+                pending_space = false;
+                out[len] = byte.to_ascii_lowercase();
+                len += 1;
+            } else {
+                pending_space = true;
+            }
+        }
+
+        len
+    }
+}
@@ -198,14 +198,22 @@ impl RankingEngine {
     pub fn get_ranking_config(env: &Env) -> RankingConfig {
         let key = DataKey::RankingSignals(String::from_str(env, "default_config"));
 
-        env.storage().persistent().get::<DataKey, RankingConfig>(&key).unwrap_or(RankingConfig {
+        env.storage()
+            .persistent()
+            .get::<DataKey, RankingConfig>(&key)
+            .unwrap_or_else(Self::default_ranking_config)
+    }
+
+    /// Documented default weights, applied at initialize and on reset
+    pub fn default_ranking_config() -> RankingConfig {
+        RankingConfig {
             relevance_weight: 30,
             quality_weight: 20,
             engagement_weight: 15,
             recency_weight: 10,
             personalization_weight: 15,
             authority_weight: 10,
-        })
+        }
     }
 
     /// Apply learning-to-rank model (from oracle)
@@ -1,4 +1,5 @@
 use crate::content_analyzer::ContentAnalyzer;
+use crate::search_cache::SearchCache;
 use crate::types::*;
 use soroban_sdk::{Address, Env, Map, String, Vec};
 
@@ -105,6 +106,8 @@ impl RecommendationEngine {
 
         // Store updated profile
         env.storage().persistent().set(&key, &profile);
+        // Personalized semantic search results depend on the profile
+        SearchCache::invalidate(env);
 
         // Emit event for off-chain processing
         env.events()
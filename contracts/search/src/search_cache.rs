@@ -0,0 +1,87 @@
+use crate::types::*;
+use soroban_sdk::{xdr::ToXdr, Address, BytesN, Env, Vec};
+
+/// Search Result Cache
+/// Short-lived cache of semantic search results keyed by a normalized query hash
+pub struct SearchCache;
+
+impl SearchCache {
+    /// Hash the parts of a query that affect its results
+    ///
+    /// Raw query text is dropped so differently-typed queries that normalize
+    /// to the same form share an entry.
+    pub fn query_key(
+        env: &Env,
+        query: &ProcessedQuery,
+        filters: &SearchFilters,
+        user: &Option<Address>,
+    ) -> BytesN<32> {
+        let mut normalized = query.clone();
+        normalized.original_text = normalized.normalized_text.clone();
+        normalized.original_query = normalized.normalized_text.clone();
+
+        let payload = (normalized, filters.clone(), user.clone()).to_xdr(env);
+        env.crypto().sha256(&payload).into()
+    }
+
+    /// Return cached results if they are within the TTL and the index is unchanged
+    pub fn get(env: &Env, key: &BytesN<32>) -> Option<Vec<SearchResultItem>> {
+        let config = Self::get_config(env);
+        if !config.enabled {
+            return None;
+        }
+
+        let cached: CachedSearchResults =
+            env.storage().temporary().get(&DataKey::SearchResultCache(key.clone()))?;
+
+        let now = env.ledger().timestamp();
+        if cached.index_version != Self::get_index_version(env)
+            || now.saturating_sub(cached.cached_at) >= config.ttl_seconds
+        {
+            return None;
+        }
+
+        Some(cached.results)
+    }
+
+    /// Cache freshly computed results
+    pub fn store(env: &Env, key: &BytesN<32>, results: &Vec<SearchResultItem>) {
+        let config = Self::get_config(env);
+        if !config.enabled {
+            return;
+        }
+
+        let cached = CachedSearchResults {
+            results: results.clone(),
+            cached_at: env.ledger().timestamp(),
+            index_version: Self::get_index_version(env),
+        };
+        env.storage().temporary().set(&DataKey::SearchResultCache(key.clone()), &cached);
+    }
+
+    /// Invalidate every cached entry by bumping the index version
+    ///
+    /// Called by every writer of data that semantic search reads: semantic
+    /// metadata, content type indexing, user profiles and the cache settings.
+    pub fn invalidate(env: &Env) {
+        let version = Self::get_index_version(env);
+        env.storage().instance().set(&DataKey::SearchIndexVersion, &(version + 1));
+    }
+
+    /// Store cache configuration
+    pub fn store_config(env: &Env, config: SearchCacheConfig) {
+        env.storage().instance().set(&DataKey::SearchCacheConfig, &config);
+    }
+
+    /// Get cache configuration (disabled by default)
+    pub fn get_config(env: &Env) -> SearchCacheConfig {
+        env.storage()
+            .instance()
+            .get(&DataKey::SearchCacheConfig)
+            .unwrap_or(SearchCacheConfig { enabled: false, ttl_seconds: 0 })
+    }
+
+    fn get_index_version(env: &Env) -> u64 {
+        env.storage().instance().get(&DataKey::SearchIndexVersion).unwrap_or(0)
+    }
+}
@@ -1,3 +1,4 @@
+use crate::search_cache::SearchCache;
 use crate::types::*;
 use soroban_sdk::{Address, Env, String, Vec};
 
@@ -12,8 +13,10 @@ impl SemanticSearch {
         processed_query: ProcessedQuery,
         filters: SearchFilters,
         user: Option<Address>,
+        include_admin_only: bool,
     ) -> Vec<SearchResultItem> {
         let mut results = Vec::new(env);
+        let indexing = Self::get_content_type_indexing(env);
 
         // Get all content with semantic metadata
         let content_ids = Self::get_indexed_content(env);
@@ -22,6 +25,12 @@ impl SemanticSearch {
             if let Some(content_id) = content_ids.get(i) {
                 // Get semantic metadata for this content
                 if let Some(metadata) = Self::get_semantic_metadata(env, &content_id) {
+                    // Admin-only document types never reach public results
+                    let content_type = Self::get_content_type(env, &content_id);
+                    if !include_admin_only && indexing.admin_only_types.contains(&content_type) {
+                        continue;
+                    }
+
                     // Calculate semantic match score
                     let score =
                         Self::calculate_semantic_score(env, &processed_query, &metadata, &user);
@@ -31,8 +40,13 @@ impl SemanticSearch {
                         // Minimum relevance threshold
                         // Apply filters
                         if Self::passes_filters(&metadata, &filters) {
-                            let result =
-                                Self::create_search_result(env, &content_id, &metadata, score);
+                            let result = Self::create_search_result(
+                                env,
+                                &content_id,
+                                &metadata,
+                                content_type,
+                                score,
+                            );
                             results.push_back(result);
                         }
                     }
@@ -59,6 +73,39 @@ impl SemanticSearch {
             index.push_back(content_id);
             env.storage().persistent().set(&index_key, &index);
         }
+
+        SearchCache::invalidate(env);
+    }
+
+    /// Record the kind of document behind indexed content
+    pub fn store_content_type(env: &Env, content_id: &String, content_type: SearchResultType) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::SemanticContentType(content_id.clone()), &content_type);
+        SearchCache::invalidate(env);
+    }
+
+    /// Kind of document behind indexed content; content indexed without a
+    /// type is treated as a course
+    pub fn get_content_type(env: &Env, content_id: &String) -> SearchResultType {
+        env.storage()
+            .persistent()
+            .get(&DataKey::SemanticContentType(content_id.clone()))
+            .unwrap_or(SearchResultType::Course)
+    }
+
+    /// Store per-content-type indexing flags
+    pub fn store_content_type_indexing(env: &Env, config: ContentTypeIndexing) {
+        env.storage().instance().set(&DataKey::ContentTypeIndexing, &config);
+        SearchCache::invalidate(env);
+    }
+
+    /// Get per-content-type indexing flags (every type public by default)
+    pub fn get_content_type_indexing(env: &Env) -> ContentTypeIndexing {
+        env.storage()
+            .instance()
+            .get(&DataKey::ContentTypeIndexing)
+            .unwrap_or(ContentTypeIndexing { admin_only_types: Vec::new(env) })
     }
 
     /// Retrieve semantic metadata for content
@@ -379,13 +426,14 @@ impl SemanticSearch {
         env: &Env,
         content_id: &String,
         _metadata: &SemanticMetadata,
+        content_type: SearchResultType,
         score: u32,
     ) -> SearchResultItem {
         // This would fetch full item data in production
         // For now, return minimal result
         SearchResultItem {
             item_id: content_id.clone(),
-            item_type: SearchResultType::Course,
+            item_type: content_type,
             title: String::from_str(env, "Course Title"),
             description: String::from_str(env, "Course Description"),
             relevance_score: score,
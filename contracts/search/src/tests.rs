@@ -1,5 +1,5 @@
 use super::*;
-use soroban_sdk::{testutils::Address as _, Address, Env, Map};
+use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, Address, Env, Map};
 
 fn create_test_env() -> (Env, Address, Address) {
     let env = Env::default();
@@ -267,3 +267,366 @@ fn test_success_rate_responds_to_course_fit() {
     assert!(matched_rate > advanced_rate);
     assert!(matched_rate <= 100);
 }
+
+fn semantic_metadata(env: &Env, content_id: &String) -> SemanticMetadata {
+    SemanticMetadata {
+        content_id: content_id.clone(),
+        topics: Vec::from_array(env, [String::from_str(env, "rust")]),
+        intent_scores: Map::new(env),
+        semantic_tags: Vec::from_array(env, [String::from_str(env, "programming")]),
+        entity_types: Vec::new(env),
+        complexity_score: 50,
+        last_updated: 1000,
+        category: String::from_str(env, "tech"),
+        instructor_id: Address::generate(env),
+        language: String::from_str(env, "en"),
+        price: 100,
+        rating: 45,
+        duration_hours: 10,
+        has_prerequisites: false,
+        has_certificate: true,
+        is_premium: false,
+        is_featured: true,
+        certificate_types: Vec::new(env),
+        certificate_status: Vec::new(env),
+    }
+}
+
+fn rust_query(env: &Env, original_text: &str) -> ProcessedQuery {
+    ProcessedQuery {
+        original_text: String::from_str(env, original_text),
+        normalized_text: String::from_str(env, "rust"),
+        original_query: String::from_str(env, original_text),
+        extracted_intent: String::from_str(env, "search"),
+        intent: String::from_str(env, "search"),
+        entities: Vec::new(env),
+        expanded_terms: Vec::new(env),
+        semantic_tags: Vec::from_array(env, [String::from_str(env, "rust")]),
+        suggested_filters: Vec::new(env),
+        query_type: String::from_str(env, "informational"),
+        confidence: 1000,
+    }
+}
+
+fn no_filters(env: &Env) -> SearchFilters {
+    SearchFilters {
+        categories: Vec::new(env),
+        difficulty_levels: Vec::new(env),
+        duration_range: MaybeDurationRange::None,
+        instructor_ids: Vec::new(env),
+        languages: Vec::new(env),
+        price_range: MaybePriceRange::None,
+        rating_range: MaybeRatingRange::None,
+        tags: Vec::new(env),
+        certificate_status: Vec::new(env),
+        issue_date_range: MaybeDateRange::None,
+        expiry_date_range: MaybeDateRange::None,
+        certificate_types: Vec::new(env),
+        completion_range: MaybeCompletionRange::None,
+        enrollment_date_range: MaybeDateRange::None,
+        last_activity_range: MaybeDateRange::None,
+        has_prerequisites: MaybeBool::None,
+        has_certificate: MaybeBool::None,
+        is_premium: MaybeBool::None,
+        is_featured: MaybeBool::None,
+    }
+}
+
+/// Drop the semantic index without going through a writer, so only a cache
+/// hit can still return the indexed content.
+fn clear_index_behind_cache(env: &Env, contract_id: &Address) {
+    env.as_contract(contract_id, || {
+        let index_key = DataKey::IndexMetadata(String::from_str(env, "semantic_index"));
+        env.storage().persistent().remove(&index_key);
+    });
+}
+
+fn setup_cached_search(env: &Env, admin: &Address, contract_id: &Address) {
+    let client = AdvancedSearchContractClient::new(env, contract_id);
+    client.initialize(admin);
+    let config = SearchCacheConfig { enabled: true, ttl_seconds: 300 };
+    client.update_search_cache_config(admin, &config);
+
+    let oracle = Address::generate(env);
+    client.authorize_oracle(admin, &oracle);
+    let content_id = String::from_str(env, "course_1");
+    client.store_semantic_metadata(&oracle, &content_id, &semantic_metadata(env, &content_id));
+}
+
+#[test]
+fn test_semantic_search_served_from_cache_within_ttl() {
+    let (env, admin, contract_id) = create_test_env();
+    let client = AdvancedSearchContractClient::new(&env, &contract_id);
+    setup_cached_search(&env, &admin, &contract_id);
+
+    let filters = no_filters(&env);
+    let first = client.semantic_search(&rust_query(&env, "rust"), &None, &filters);
+    assert_eq!(first.len(), 1);
+    clear_index_behind_cache(&env, &contract_id);
+
+    // Same normalized query, different raw text: served from cache
+    let second = client.semantic_search(&rust_query(&env, "  Rust "), &None, &filters);
+    assert_eq!(second, first);
+
+    // Past the TTL the query is recomputed
+    env.ledger().with_mut(|li| li.timestamp += 300);
+    assert!(client.semantic_search(&rust_query(&env, "rust"), &None, &filters).is_empty());
+}
+
+#[test]
+fn test_semantic_index_update_invalidates_search_cache() {
+    let (env, admin, contract_id) = create_test_env();
+    let client = AdvancedSearchContractClient::new(&env, &contract_id);
+    setup_cached_search(&env, &admin, &contract_id);
+
+    let filters = no_filters(&env);
+    let query = rust_query(&env, "rust");
+    assert_eq!(client.semantic_search(&query, &None, &filters).len(), 1);
+
+    let oracle = Address::generate(&env);
+    client.authorize_oracle(&admin, &oracle);
+    let course_2 = String::from_str(&env, "course_2");
+    client.store_semantic_metadata(&oracle, &course_2, &semantic_metadata(&env, &course_2));
+
+    assert_eq!(client.semantic_search(&query, &None, &filters).len(), 2);
+}
+
+#[test]
+fn test_profile_and_cache_config_updates_invalidate_search_cache() {
+    let (env, admin, contract_id) = create_test_env();
+    let client = AdvancedSearchContractClient::new(&env, &contract_id);
+    setup_cached_search(&env, &admin, &contract_id);
+
+    let filters = no_filters(&env);
+    let query = rust_query(&env, "rust");
+    let student = Some(Address::generate(&env));
+    assert_eq!(client.semantic_search(&query, &student, &filters).len(), 1);
+    assert_eq!(client.semantic_search(&query, &None, &filters).len(), 1);
+    clear_index_behind_cache(&env, &contract_id);
+
+    // A profile update can change personalized scores, so results are recomputed
+    let course_id = String::from_str(&env, "course_1");
+    client.update_user_profile(&student.clone().unwrap(), &course_id, &true);
+    assert!(client.semantic_search(&query, &student, &filters).is_empty());
+
+    // So does any change to the cache settings
+    let outsider = Address::generate(&env);
+    let config = SearchCacheConfig { enabled: true, ttl_seconds: 600 };
+    client.update_search_cache_config(&admin, &config);
+    assert!(client.semantic_search(&query, &Some(outsider), &filters).is_empty());
+    assert!(client.semantic_search(&query, &None, &filters).is_empty());
+}
+
+fn store_english_content(
+    env: &Env,
+    client: &AdvancedSearchContractClient,
+    oracle: &Address,
+    content_id: &str,
+    title: &str,
+) {
+    let content_id = String::from_str(env, content_id);
+    let mut translations = Map::new(env);
+    translations.set(
+        String::from_str(env, "en"),
+        TranslationMeta {
+            target_language: Language::English,
+            translated_title: String::from_str(env, title),
+            translated_description: String::from_str(env, "An online course"),
+            quality_score: 900,
+            translation_quality: 90,
+            last_updated: env.ledger().timestamp(),
+        },
+    );
+    let content = MultilingualContent {
+        content_id: content_id.clone(),
+        primary_language: Language::English,
+        available_languages: Vec::from_array(env, [Language::English]),
+        translations,
+    };
+    client.store_multilingual_content(oracle, &content_id, &content);
+}
+
+fn setup_language_search(env: &Env) -> AdvancedSearchContractClient<'_> {
+    let admin = Address::generate(env);
+    let contract_id = env.register(AdvancedSearchContract, ());
+    let client = AdvancedSearchContractClient::new(env, &contract_id);
+    client.initialize(&admin);
+
+    let oracle = Address::generate(env);
+    client.authorize_oracle(&admin, &oracle);
+    store_english_content(env, &client, &oracle, "contiguous", "Writing Smart Contracts in Rust");
+    store_english_content(env, &client, &oracle, "split", "Smart Rust Contracts");
+    store_english_content(env, &client, &oracle, "python", "Python for Data Science");
+    client
+}
+
+#[test]
+fn test_quoted_phrase_matches_only_contiguous_words() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = setup_language_search(&env);
+
+    let results = client
+        .search_by_language(&Language::English, &String::from_str(&env, "\"smart contracts\""));
+    assert_eq!(results, Vec::from_array(&env, [String::from_str(&env, "contiguous")]));
+
+    // Without quotes the same words are AND-ed and may appear anywhere
+    let results =
+        client.search_by_language(&Language::English, &String::from_str(&env, "smart contracts"));
+    assert_eq!(results.len(), 2);
+
+    // Unterminated quotes are rejected
+    let result =
+        client.try_search_by_language(&Language::English, &String::from_str(&env, "\"smart"));
+    assert_eq!(result, Err(Ok(SearchError::InvalidQuery)));
+}
+
+#[test]
+fn test_implicit_and_query_requires_all_terms() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = setup_language_search(&env);
+
+    let results =
+        client.search_by_language(&Language::English, &String::from_str(&env, "rust writing"));
+    assert_eq!(results, Vec::from_array(&env, [String::from_str(&env, "contiguous")]));
+
+    // A plain single term still matches whole words case-insensitively
+    let results = client.search_by_language(&Language::English, &String::from_str(&env, "RUST"));
+    assert_eq!(results.len(), 2);
+    let results = client.search_by_language(&Language::English, &String::from_str(&env, "rus"));
+    assert!(results.is_empty());
+}
+
+#[test]
+fn test_long_fields_match_within_leading_bytes() {
+    let (env, admin, contract_id) = create_test_env();
+    let client = AdvancedSearchContractClient::new(&env, &contract_id);
+    client.initialize(&admin);
+    let oracle = Address::generate(&env);
+    client.authorize_oracle(&admin, &oracle);
+
+    // "rustacean" straddles the 512-byte limit and "tail" lies past it
+    let mut title = [b' '; 600];
+    title[..5].copy_from_slice(b"intro");
+    title[508..517].copy_from_slice(b"rustacean");
+    title[590..594].copy_from_slice(b"tail");
+    store_english_content(&env, &client, &oracle, "long", core::str::from_utf8(&title).unwrap());
+
+    let search =
+        |query: &str| client.search_by_language(&Language::English, &String::from_str(&env, query));
+    assert_eq!(search("intro"), Vec::from_array(&env, [String::from_str(&env, "long")]));
+    assert!(search("tail").is_empty());
+    // The word cut off at the limit matches neither whole nor as its prefix
+    assert!(search("rustacean").is_empty());
+    assert!(search("rust").is_empty());
+}
+
+#[test]
+fn test_or_query_matches_any_group() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = setup_language_search(&env);
+
+    let results =
+        client.search_by_language(&Language::English, &String::from_str(&env, "python OR writing"));
+    assert_eq!(
+        results,
+        Vec::from_array(
+            &env,
+            [String::from_str(&env, "contiguous"), String::from_str(&env, "python")]
+        )
+    );
+
+    // A blank query returns everything indexed for the language
+    let results = client.search_by_language(&Language::English, &String::from_str(&env, " "));
+    assert_eq!(results.len(), 3);
+}
+
+#[test]
+fn test_reset_search_weights_restores_defaults() {
+    let (env, admin, contract_id) = create_test_env();
+    let client = AdvancedSearchContractClient::new(&env, &contract_id);
+    client.initialize(&admin);
+
+    let defaults = RankingConfig {
+        relevance_weight: 30,
+        quality_weight: 20,
+        engagement_weight: 15,
+        recency_weight: 10,
+        personalization_weight: 15,
+        authority_weight: 10,
+    };
+    assert_eq!(client.get_ranking_config(), defaults);
+
+    let results = Vec::from_array(&env, [String::from_str(&env, "course_1")]);
+    let default_score = client.rank_results(&results, &None).get(0).unwrap().final_score;
+
+    client.update_ranking_config(
+        &admin,
+        &RankingConfig {
+            relevance_weight: 100,
+            quality_weight: 0,
+            engagement_weight: 0,
+            recency_weight: 0,
+            personalization_weight: 0,
+            authority_weight: 0,
+        },
+    );
+    let tuned_score = client.rank_results(&results, &None).get(0).unwrap().final_score;
+    assert_ne!(tuned_score, default_score);
+
+    client.reset_search_weights(&admin);
+    assert_eq!(client.get_ranking_config(), defaults);
+    let reset_score = client.rank_results(&results, &None).get(0).unwrap().final_score;
+    assert_eq!(reset_score, default_score);
+
+    let outsider = Address::generate(&env);
+    assert_eq!(client.try_reset_search_weights(&outsider), Err(Ok(Error::Unauthorized)));
+}
+
+#[test]
+fn test_admin_only_content_type_hidden_from_regular_users() {
+    let (env, admin, contract_id) = create_test_env();
+    let client = AdvancedSearchContractClient::new(&env, &contract_id);
+    client.initialize(&admin);
+    client.update_content_type_indexing(
+        &admin,
+        &ContentTypeIndexing {
+            admin_only_types: Vec::from_array(&env, [SearchResultType::UserProgress]),
+        },
+    );
+
+    let oracle = Address::generate(&env);
+    client.authorize_oracle(&admin, &oracle);
+    let course_id = String::from_str(&env, "course_1");
+    client.store_semantic_metadata(&oracle, &course_id, &semantic_metadata(&env, &course_id));
+    let note_id = String::from_str(&env, "progress_note_1");
+    client.store_typed_semantic_metadata(
+        &oracle,
+        &note_id,
+        &semantic_metadata(&env, &note_id),
+        &SearchResultType::UserProgress,
+    );
+
+    let filters = no_filters(&env);
+    let student = Address::generate(&env);
+    for user in [None, Some(student)] {
+        let results = client.semantic_search(&rust_query(&env, "rust"), &user, &filters);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results.get(0).unwrap().item_id, course_id);
+    }
+
+    let results = client.semantic_search(&rust_query(&env, "rust"), &Some(admin.clone()), &filters);
+    assert_eq!(results.len(), 2);
+    assert!(results
+        .iter()
+        .any(|r| r.item_id == note_id && r.item_type == SearchResultType::UserProgress));
+
+    // Without the flag the type is public again
+    client.update_content_type_indexing(
+        &admin,
+        &ContentTypeIndexing { admin_only_types: Vec::new(&env) },
+    );
+    assert_eq!(client.semantic_search(&rust_query(&env, "rust"), &None, &filters).len(), 2);
+}
@@ -331,6 +331,30 @@ pub struct SearchResults {
     pub search_metadata: SearchMetadata, // Additional metadata
 }
 
+/// Semantic search result cache settings
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SearchCacheConfig {
+    pub enabled: bool,
+    pub ttl_seconds: u64, // How long cached results stay valid
+}
+
+/// Per-content-type indexing flags for semantic search
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContentTypeIndexing {
+    pub admin_only_types: Vec<SearchResultType>, // Indexed but hidden from non-admin searches
+}
+
+/// Cached semantic search results for a normalized query
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CachedSearchResults {
+    pub results: Vec<SearchResultItem>,
+    pub cached_at: u64,
+    pub index_version: u64, // Index version the results were computed against
+}
+
 /// Simple search result (for AI functions)
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -776,6 +800,16 @@ pub enum DataKey {
     AuthorizedOracles(Address), // Oracle address
     /// Catalog of content IDs with stored analysis
     ContentCatalog,
+    /// Cached semantic search results
+    SearchResultCache(BytesN<32>), // Normalized query hash
+    /// Search result cache settings
+    SearchCacheConfig,
+    /// Semantic index version, bumped on every index change
+    SearchIndexVersion,
+    /// Per-content-type indexing flags
+    ContentTypeIndexing,
+    /// Kind of document behind indexed semantic metadata (Course when unset)
+    SemanticContentType(String), // Content ID
 }
 
 // ============================================================================
@@ -826,6 +860,20 @@ pub struct ProcessedQuery {
     pub confidence: u32, // 0-1000
 }
 
+/// Text query parsed by `QueryParser`; content matches when any group matches
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParsedQuery {
+    pub groups: Vec<QueryGroup>,
+}
+
+/// Terms that must all match; a multi-word term is an exact phrase
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QueryGroup {
+    pub terms: Vec<String>,
+}
+
 /// Extracted entity
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -1,8 +1,9 @@
 use crate::errors::AccessControlError;
 use crate::events::AccessControlEvents;
 use crate::permissions::RolePermissions;
+use crate::rate_limiter::{enforce_rate_limit, RateLimitConfig};
 use crate::roles::{Permission, Role, RoleLevel};
-use crate::storage::AccessControlStorage;
+use crate::storage::{AccessControlStorage, DataKey};
 use soroban_sdk::{Address, Env, Vec};
 
 /// OpenZeppelin-style AccessControl implementation
@@ -548,6 +549,65 @@ impl AccessControl {
         Ok(())
     }
 
+    /// Set the rate limit applied to callers holding `role_level`
+    pub fn set_role_rate_limit(
+        env: &Env,
+        admin: &Address,
+        role_level: RoleLevel,
+        config: &RateLimitConfig,
+    ) -> Result<(), AccessControlError> {
+        let admin_role = AccessControlStorage::validate_user_role(env, admin)?;
+        if !matches!(admin_role.level, RoleLevel::Admin | RoleLevel::SuperAdmin) {
+            AccessControlEvents::emit_access_denied(env, admin, &Permission::InitializeContract);
+            return Err(AccessControlError::PermissionDenied);
+        }
+        if config.max_calls == 0 || config.window_seconds == 0 {
+            return Err(AccessControlError::InvalidRateLimit);
+        }
+
+        env.storage().instance().set(&DataKey::RoleRateLimit(role_level), config);
+        Ok(())
+    }
+
+    /// Get the rate limit configured for `role_level`, if any
+    pub fn get_role_rate_limit(env: &Env, role_level: RoleLevel) -> Option<RateLimitConfig> {
+        env.storage().instance().get(&DataKey::RoleRateLimit(role_level))
+    }
+
+    /// Rate limit that applies to `user`
+    ///
+    /// Falls back to `default` when the user has no valid role or their role
+    /// has no limit configured.
+    pub fn effective_rate_limit(
+        env: &Env,
+        user: &Address,
+        default: &RateLimitConfig,
+    ) -> RateLimitConfig {
+        AccessControlStorage::validate_user_role(env, user)
+            .ok()
+            .and_then(|role| Self::get_role_rate_limit(env, role.level))
+            .unwrap_or_else(|| default.clone())
+    }
+
+    /// Count one call by `user` against their role's rate limit
+    ///
+    /// `storage_key` identifies the user and operation being limited, as with
+    /// [`enforce_rate_limit`].
+    pub fn enforce_role_rate_limit<K>(
+        env: &Env,
+        user: &Address,
+        storage_key: &K,
+        default: &RateLimitConfig,
+    ) -> Result<(), AccessControlError>
+    where
+        K: soroban_sdk::IntoVal<Env, soroban_sdk::Val>
+            + soroban_sdk::TryFromVal<Env, soroban_sdk::Val>,
+    {
+        let config = Self::effective_rate_limit(env, user, default);
+        enforce_rate_limit(env, storage_key, &config)
+            .map_err(|_| AccessControlError::RateLimitExceeded)
+    }
+
     /// Require a specific permission (for use in function modifiers)
     pub fn require_permission(
         env: &Env,
@@ -23,6 +23,7 @@ pub struct MobileDefaults {
     pub retry_attempts: u32,
     pub cache_ttl_seconds: u64,
     pub max_devices_per_user: u32,
+    pub max_sessions_per_user: u32,
     pub analytics_retention_days: u32,
 }
 
@@ -76,6 +77,7 @@ impl ContractConfig {
                 retry_attempts: 7,
                 cache_ttl_seconds: 172_800,
                 max_devices_per_user: 10,
+                max_sessions_per_user: 20,
                 analytics_retention_days: 30,
             },
             DeploymentEnv::Staging => MobileDefaults {
@@ -87,6 +89,7 @@ impl ContractConfig {
                 retry_attempts: 6,
                 cache_ttl_seconds: 129_600,
                 max_devices_per_user: 7,
+                max_sessions_per_user: 15,
                 analytics_retention_days: 60,
             },
             DeploymentEnv::Production => MobileDefaults {
@@ -98,6 +101,7 @@ impl ContractConfig {
                 retry_attempts: 5,
                 cache_ttl_seconds: 86_400,
                 max_devices_per_user: 5,
+                max_sessions_per_user: 10,
                 analytics_retention_days: 90,
             },
         }
@@ -47,6 +47,12 @@ pub enum AccessControlError {
     InvalidRole = 14,
     /// The requested role template was not found.
     TemplateNotFound = 15,
+
+    // Rate limiting errors
+    /// The caller exceeded the rate limit configured for their role.
+    RateLimitExceeded = 16,
+    /// The rate limit configuration has a zero call count or window.
+    InvalidRateLimit = 17,
 }
 
 impl StandardizedError for AccessControlError {
@@ -142,6 +148,18 @@ impl StandardizedError for AccessControlError {
                 "Create the template first or update the request to reference an existing template",
                 ErrorCodeCategory::NotFound,
             ),
+            Self::RateLimitExceeded => ErrorDescriptor::new(
+                "SHR-016",
+                "Rate limit for the caller's role was exceeded",
+                "Wait for the current rate-limit window to end before retrying",
+                ErrorCodeCategory::BusinessLogic,
+            ),
+            Self::InvalidRateLimit => ErrorDescriptor::new(
+                "SHR-017",
+                "Rate limit configuration is invalid",
+                "Use a non-zero call count and window length",
+                ErrorCodeCategory::Configuration,
+            ),
         }
     }
 }
@@ -1,7 +1,10 @@
 use soroban_sdk::{contracttype, Address, BytesN, Env, String, Symbol, Vec};
 
-/// Standard event schema version
-pub const EVENT_SCHEMA_VERSION: u32 = 1;
+/// Standard event schema version.
+///
+/// Version 2 publishes the typed `EventData` as the last element of the event
+/// data tuple; version 1 published a fixed category string there.
+pub const EVENT_SCHEMA_VERSION: u32 = 2;
 
 /// Standard event wrapper that all contracts should use
 #[contracttype]
@@ -1024,6 +1027,11 @@ pub struct MultisigConfigUpdatedEvent {
 }
 #[contracttype]
 #[derive(Clone, Debug)]
+pub struct BatchStartedEvent {
+    pub total: u32,
+}
+#[contracttype]
+#[derive(Clone, Debug)]
 pub struct BatchCompletedEvent {
     pub total: u32,
     pub succeeded: u32,
@@ -1066,6 +1074,7 @@ pub enum CertificationEventData {
     MultisigRequestRejected(MultisigRequestRejectedEvent),
     MultisigRequestApproved(MultisigRequestApprovedEvent),
     MultisigConfigUpdated(MultisigConfigUpdatedEvent),
+    BatchStarted(BatchStartedEvent),
     BatchCompleted(BatchCompletedEvent),
     CertificateShared(CertificateSharedEvent),
     ComplianceChecked(ComplianceCheckedEvent),
@@ -1145,6 +1154,21 @@ pub struct GuildLeftEvent {
 }
 #[contracttype]
 #[derive(Clone, Debug)]
+pub struct GuildTreasuryContributedEvent {
+    pub guild_id: u64,
+    pub member: Address,
+    pub amount: u64,
+}
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct GuildTreasurySpentEvent {
+    pub guild_id: u64,
+    pub officer: Address,
+    pub recipient: Address,
+    pub amount: u64,
+}
+#[contracttype]
+#[derive(Clone, Debug)]
 pub struct SeasonStartedEvent {
     pub season_id: u64,
 }
@@ -1186,6 +1210,8 @@ pub enum GamificationEventData {
     GuildCreated(GuildCreatedEvent),
     GuildJoined(GuildJoinedEvent),
     GuildLeft(GuildLeftEvent),
+    GuildTreasuryContributed(GuildTreasuryContributedEvent),
+    GuildTreasurySpent(GuildTreasurySpentEvent),
     SeasonStarted(SeasonStartedEvent),
     SeasonEnded(SeasonEndedEvent),
     Endorsed(EndorsedEvent),
@@ -1273,6 +1299,12 @@ pub struct MetricsUpdatedEvent {
     pub metric_id: Symbol,
     pub new_value: u64,
 }
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct AnalyticsAchievementEarnedEvent {
+    pub achievement_id: Symbol,
+    pub earned_date: u64,
+}
 
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -1281,6 +1313,7 @@ pub enum AnalyticsEventData {
     ActionTracked(ActionTrackedEvent),
     SessionCompleted(SessionCompletedEvent),
     MetricsUpdated(MetricsUpdatedEvent),
+    AchievementEarned(AnalyticsAchievementEarnedEvent),
 }
 
 // Search Event Structs
@@ -1451,7 +1484,7 @@ impl StandardEvent {
             self.timestamp,
             self.tx_hash.clone(),
             self.sequence.unwrap_or(0),
-            self.serialize_event_data(env),
+            self.event_data.clone(),
         );
 
         env.events().publish(topics, data);
@@ -1521,6 +1554,7 @@ impl StandardEvent {
                 AnalyticsEventData::ActionTracked(_) => "action_tracked",
                 AnalyticsEventData::SessionCompleted(_) => "session_completed",
                 AnalyticsEventData::MetricsUpdated(_) => "metrics_updated",
+                AnalyticsEventData::AchievementEarned(_) => "achievement_earned",
             },
             EventData::Token(data) => match data {
                 TokenEventData::TokensTransferred(_) => "tokens_transferred",
@@ -1599,6 +1633,7 @@ impl StandardEvent {
                 CertificationEventData::MultisigRequestRejected(_) => "multisig_request_rejected",
                 CertificationEventData::MultisigRequestApproved(_) => "multisig_request_approved",
                 CertificationEventData::MultisigConfigUpdated(_) => "multisig_config_updated",
+                CertificationEventData::BatchStarted(_) => "batch_started",
                 CertificationEventData::BatchCompleted(_) => "batch_completed",
                 CertificationEventData::CertificateShared(_) => "cert_shared",
                 CertificationEventData::ComplianceChecked(_) => "compliance_checked",
@@ -1617,6 +1652,8 @@ impl StandardEvent {
                 GamificationEventData::GuildCreated(_) => "guild_created",
                 GamificationEventData::GuildJoined(_) => "guild_joined",
                 GamificationEventData::GuildLeft(_) => "guild_left",
+                GamificationEventData::GuildTreasuryContributed(_) => "guild_treasury_contributed",
+                GamificationEventData::GuildTreasurySpent(_) => "guild_treasury_spent",
                 GamificationEventData::SeasonStarted(_) => "season_started",
                 GamificationEventData::SeasonEnded(_) => "season_ended",
                 GamificationEventData::Endorsed(_) => "endorsed",
@@ -1659,31 +1696,6 @@ impl StandardEvent {
             },
         }
     }
-
-    /// Serialize event data for emission (simplified for now)
-    fn serialize_event_data(&self, env: &Env) -> String {
-        // For now, return a simple string representation
-        // In a full implementation, this would serialize to a structured format
-        match &self.event_data {
-            EventData::AccessControl(_) => String::from_str(env, "access_control_event"),
-            EventData::Certificate(_) => String::from_str(env, "certificate_event"),
-            EventData::Analytics(_) => String::from_str(env, "analytics_event"),
-            EventData::Token(_) => String::from_str(env, "token_event"),
-            EventData::Progress(_) => String::from_str(env, "progress_event"),
-            EventData::System(_) => String::from_str(env, "system_event"),
-            EventData::Assessment(_) => String::from_str(env, "assessment_event"),
-            EventData::Community(_) => String::from_str(env, "community_event"),
-            EventData::Mentorship(_) => String::from_str(env, "mentorship_event"),
-            EventData::Governance(_) => String::from_str(env, "governance_event"),
-            EventData::Security(_) => String::from_str(env, "security_event"),
-            EventData::Certification(_) => String::from_str(env, "certification_event"),
-            EventData::Gamification(_) => String::from_str(env, "gamification_event"),
-            EventData::CrossChain(_) => String::from_str(env, "crosschain_event"),
-            EventData::Search(_) => String::from_str(env, "search_event"),
-            EventData::Err(_) => String::from_str(env, "error_event"),
-            EventData::Monitoring(_) => String::from_str(env, "monitoring_event"),
-        }
-    }
 }
 
 /// Helper macros for easy event emission
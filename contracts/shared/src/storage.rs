@@ -25,6 +25,8 @@ pub enum DataKey {
     PermissionTemplate(soroban_sdk::Symbol),
     /// Key for role inheritance
     RoleInheritance(crate::roles::RoleLevel),
+    /// Key for the rate limit applied to holders of a role
+    RoleRateLimit(crate::roles::RoleLevel),
 }
 
 /// RBAC storage operations
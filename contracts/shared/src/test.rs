@@ -2,11 +2,13 @@ use crate::{
     access_control::AccessControl,
     errors::AccessControlError,
     permissions::RolePermissions,
+    rate_limiter::RateLimitConfig,
     reentrancy_guard::{ReentrancyGuard, ReentrancyLock},
     roles::{Permission, RoleLevel},
     storage::AccessControlStorage,
 };
 use soroban_sdk::{
+    contract, contractimpl, symbol_short,
     testutils::{Address as _, Ledger, MockAuth, MockAuthInvoke},
     vec, Address, Env, IntoVal, Vec,
 };
@@ -450,3 +452,112 @@ fn test_reentrancy_guard_exit_without_enter() {
     ReentrancyGuard::enter(&env);
     ReentrancyGuard::exit(&env);
 }
+
+// Per-role rate limit tests
+#[contract]
+struct RoleRateLimitTestContract;
+
+#[contractimpl]
+impl RoleRateLimitTestContract {}
+
+#[test]
+fn test_higher_role_gets_higher_rate_limit() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(RoleRateLimitTestContract, ());
+    let admin = Address::generate(&env);
+    let instructor = Address::generate(&env);
+    let default = RateLimitConfig { max_calls: 1, window_seconds: 86_400 };
+
+    env.as_contract(&contract_id, || {
+        AccessControl::initialize(&env, &admin).unwrap();
+        let role = RolePermissions::create_role_with_default_permissions(
+            &env,
+            RoleLevel::Instructor,
+            admin.clone(),
+            env.ledger().timestamp(),
+        );
+        AccessControlStorage::set_role(&env, &instructor, &role);
+
+        let instructor_limit = RateLimitConfig { max_calls: 2, window_seconds: 86_400 };
+        let admin_limit = RateLimitConfig { max_calls: 5, window_seconds: 86_400 };
+        AccessControl::set_role_rate_limit(&env, &admin, RoleLevel::Instructor, &instructor_limit)
+            .unwrap();
+        AccessControl::set_role_rate_limit(&env, &admin, RoleLevel::SuperAdmin, &admin_limit)
+            .unwrap();
+
+        assert_eq!(
+            AccessControl::effective_rate_limit(&env, &instructor, &default),
+            instructor_limit
+        );
+        assert_eq!(AccessControl::effective_rate_limit(&env, &admin, &default), admin_limit);
+
+        let op = symbol_short!("revoke");
+        for _ in 0..2 {
+            AccessControl::enforce_role_rate_limit(
+                &env,
+                &instructor,
+                &(op.clone(), instructor.clone()),
+                &default,
+            )
+            .unwrap();
+        }
+        assert_eq!(
+            AccessControl::enforce_role_rate_limit(
+                &env,
+                &instructor,
+                &(op.clone(), instructor.clone()),
+                &default
+            ),
+            Err(AccessControlError::RateLimitExceeded)
+        );
+
+        for _ in 0..5 {
+            AccessControl::enforce_role_rate_limit(
+                &env,
+                &admin,
+                &(op.clone(), admin.clone()),
+                &default,
+            )
+            .unwrap();
+        }
+        assert_eq!(
+            AccessControl::enforce_role_rate_limit(
+                &env,
+                &admin,
+                &(op.clone(), admin.clone()),
+                &default
+            ),
+            Err(AccessControlError::RateLimitExceeded)
+        );
+    });
+}
+
+#[test]
+fn test_role_rate_limit_defaults_and_validation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(RoleRateLimitTestContract, ());
+    let admin = Address::generate(&env);
+    let outsider = Address::generate(&env);
+    let default = RateLimitConfig { max_calls: 3, window_seconds: 3_600 };
+
+    env.as_contract(&contract_id, || {
+        AccessControl::initialize(&env, &admin).unwrap();
+
+        // Roles without a configured limit, and users without a role, use the default
+        assert_eq!(AccessControl::get_role_rate_limit(&env, RoleLevel::Student), None);
+        assert_eq!(AccessControl::effective_rate_limit(&env, &admin, &default), default);
+        assert_eq!(AccessControl::effective_rate_limit(&env, &outsider, &default), default);
+
+        let zero = RateLimitConfig { max_calls: 0, window_seconds: 3_600 };
+        assert_eq!(
+            AccessControl::set_role_rate_limit(&env, &admin, RoleLevel::Student, &zero),
+            Err(AccessControlError::InvalidRateLimit)
+        );
+        assert_eq!(
+            AccessControl::set_role_rate_limit(&env, &outsider, RoleLevel::Student, &default),
+            Err(AccessControlError::RoleNotFound)
+        );
+    });
+}
@@ -17,4 +17,10 @@ pub enum StudentProgressError {
     // Validation (20-49)
     /// The provided completion percentage is outside the valid 0–100 range.
     InvalidPercent = 20,
+    /// The same module was updated again before the minimum update interval elapsed.
+    UpdateTooSoon = 21,
+    /// The module was marked complete before the course's minimum module time elapsed.
+    ModuleTimeNotMet = 22,
+    /// The module was marked complete under a minimum module time without ever being started.
+    ModuleNotStarted = 23,
 }
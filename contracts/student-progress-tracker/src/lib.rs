@@ -22,6 +22,10 @@ pub struct Progress {
 enum DataKey {
     Progress(Address, Symbol), // (student, course_id)
     Admin,
+    MinUpdateInterval,
+    LastUpdate(Address, Symbol, Symbol), // (student, course_id, module_id)
+    MinModuleTime(Symbol),               // course_id
+    ModuleStart(Address, Symbol, Symbol), // (student, course_id, module_id)
 }
 
 #[contract]
@@ -73,6 +77,12 @@ impl ProgressTracker {
     /// # Errors
     /// Returns [`StudentProgressError::InvalidPercent`] if `percent` is greater than 100.
     /// Returns [`StudentProgressError::AdminNotSet`] if the contract has not been initialized.
+    /// Returns [`StudentProgressError::UpdateTooSoon`] if the same module was updated less than the
+    /// configured minimum interval ago.
+    /// Returns [`StudentProgressError::ModuleNotStarted`] if `percent` is 100, the course has a
+    /// minimum module time, and the module was never started.
+    /// Returns [`StudentProgressError::ModuleTimeNotMet`] if `percent` is 100 before the course's
+    /// minimum module time has elapsed since the student started the module.
     ///
     /// # Example
     /// ```ignore
@@ -98,6 +108,50 @@ impl ProgressTracker {
         } else {
             admin.require_auth();
         }
+
+        let min_interval: u64 =
+            env.storage().instance().get(&DataKey::MinUpdateInterval).unwrap_or(0);
+        if min_interval > 0 {
+            let last_key =
+                DataKey::LastUpdate(student.clone(), course_id.clone(), module_id.clone());
+            let now = env.ledger().timestamp();
+            if let Some(last) = env.storage().persistent().get::<_, u64>(&last_key) {
+                if now < last.saturating_add(min_interval) {
+                    return Err(StudentProgressError::UpdateTooSoon);
+                }
+            }
+            env.storage().persistent().set(&last_key, &now);
+            env.storage().persistent().extend_ttl(
+                &last_key,
+                TTL_BUMP_THRESHOLD,
+                TTL_PERSISTENT_YEAR,
+            );
+        }
+
+        let min_module_time: u64 =
+            env.storage().persistent().get(&DataKey::MinModuleTime(course_id.clone())).unwrap_or(0);
+        if min_module_time > 0 {
+            let start_key =
+                DataKey::ModuleStart(student.clone(), course_id.clone(), module_id.clone());
+            let now = env.ledger().timestamp();
+            let started: u64 = match env.storage().persistent().get(&start_key) {
+                Some(started) => started,
+                None if percent == 100 => return Err(StudentProgressError::ModuleNotStarted),
+                None => {
+                    env.storage().persistent().set(&start_key, &now);
+                    now
+                }
+            };
+            if percent == 100 && now < started.saturating_add(min_module_time) {
+                return Err(StudentProgressError::ModuleTimeNotMet);
+            }
+            env.storage().persistent().extend_ttl(
+                &start_key,
+                TTL_BUMP_THRESHOLD,
+                TTL_PERSISTENT_YEAR,
+            );
+        }
+
         let key = DataKey::Progress(student.clone(), course_id.clone());
 
         let mut progress_map: Map<Symbol, u32> =
@@ -122,6 +176,51 @@ impl ProgressTracker {
         Ok(())
     }
 
+    /// Starts the minimum-module-time clock for a student's module.
+    ///
+    /// A partial `update_progress` also starts the clock; calling this first lets a module be
+    /// completed with a single 100% update (e.g. one synced from an assessment). Calling it again
+    /// keeps the original start time.
+    ///
+    /// Requires authorization from `student` (or admin if student is the admin).
+    ///
+    /// # Arguments
+    /// * `student` - Address of the student starting the module.
+    /// * `course_id` - Symbol identifier for the course.
+    /// * `module_id` - Symbol identifier for the module within the course.
+    ///
+    /// # Errors
+    /// Returns [`StudentProgressError::AdminNotSet`] if the contract has not been initialized.
+    ///
+    /// # Example
+    /// ```ignore
+    /// client.start_module(&student, &course_id, &module_id);
+    /// ```
+    pub fn start_module(
+        env: Env,
+        student: Address,
+        course_id: Symbol,
+        module_id: Symbol,
+    ) -> Result<(), StudentProgressError> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(StudentProgressError::AdminNotSet)?;
+        if student != admin {
+            student.require_auth();
+        } else {
+            admin.require_auth();
+        }
+
+        let start_key = DataKey::ModuleStart(student, course_id, module_id);
+        if !env.storage().persistent().has(&start_key) {
+            env.storage().persistent().set(&start_key, &env.ledger().timestamp());
+        }
+        env.storage().persistent().extend_ttl(&start_key, TTL_BUMP_THRESHOLD, TTL_PERSISTENT_YEAR);
+        Ok(())
+    }
+
     /// Returns a map of module IDs to completion percentages for a student in a course.
     ///
     /// Returns an empty map if no progress has been recorded yet.
@@ -143,6 +242,105 @@ impl ProgressTracker {
         env.storage().persistent().get(&key).unwrap_or(Map::new(&env))
     }
 
+    /// Sets the minimum number of seconds between updates to the same module.
+    ///
+    /// Applies per `(student, course_id, module_id)`; `0` disables the check.
+    ///
+    /// # Arguments
+    /// * `admin` - The stored admin address.
+    /// * `seconds` - Minimum interval between updates to the same module.
+    ///
+    /// # Errors
+    /// Returns [`StudentProgressError::AdminNotSet`] if the contract has not been initialized.
+    /// Returns [`StudentProgressError::Unauthorized`] if `admin` is not the stored admin.
+    ///
+    /// # Example
+    /// ```ignore
+    /// client.set_min_update_interval(&admin, &60u64);
+    /// ```
+    pub fn set_min_update_interval(
+        env: Env,
+        admin: Address,
+        seconds: u64,
+    ) -> Result<(), StudentProgressError> {
+        let stored: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(StudentProgressError::AdminNotSet)?;
+        if admin != stored {
+            return Err(StudentProgressError::Unauthorized);
+        }
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::MinUpdateInterval, &seconds);
+        env.storage().instance().extend_ttl(TTL_BUMP_THRESHOLD, TTL_PERSISTENT_YEAR);
+        Ok(())
+    }
+
+    /// Returns the minimum interval between updates to the same module (`0` if disabled).
+    ///
+    /// # Example
+    /// ```ignore
+    /// let seconds = client.get_min_update_interval();
+    /// ```
+    pub fn get_min_update_interval(env: Env) -> u64 {
+        env.storage().instance().get(&DataKey::MinUpdateInterval).unwrap_or(0)
+    }
+
+    /// Sets the minimum seconds a student must spend on each module of a course before completing it.
+    ///
+    /// The clock starts on the student's first update to the module; `0` disables the check.
+    ///
+    /// # Arguments
+    /// * `admin` - The stored admin address.
+    /// * `course_id` - Symbol identifier for the course.
+    /// * `seconds` - Minimum time between first touching a module and marking it 100% complete.
+    ///
+    /// # Errors
+    /// Returns [`StudentProgressError::AdminNotSet`] if the contract has not been initialized.
+    /// Returns [`StudentProgressError::Unauthorized`] if `admin` is not the stored admin.
+    ///
+    /// # Example
+    /// ```ignore
+    /// client.set_min_module_time(&admin, &course_id, &600u64);
+    /// ```
+    pub fn set_min_module_time(
+        env: Env,
+        admin: Address,
+        course_id: Symbol,
+        seconds: u64,
+    ) -> Result<(), StudentProgressError> {
+        let stored: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(StudentProgressError::AdminNotSet)?;
+        if admin != stored {
+            return Err(StudentProgressError::Unauthorized);
+        }
+        admin.require_auth();
+
+        let key = DataKey::MinModuleTime(course_id);
+        if seconds == 0 {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &seconds);
+            env.storage().persistent().extend_ttl(&key, TTL_BUMP_THRESHOLD, TTL_PERSISTENT_YEAR);
+        }
+        Ok(())
+    }
+
+    /// Returns the minimum module time for a course in seconds (`0` if not configured).
+    ///
+    /// # Example
+    /// ```ignore
+    /// let seconds = client.get_min_module_time(&course_id);
+    /// ```
+    pub fn get_min_module_time(env: Env, course_id: Symbol) -> u64 {
+        env.storage().persistent().get(&DataKey::MinModuleTime(course_id)).unwrap_or(0)
+    }
+
     /// Returns the admin address stored during initialization.
     ///
     /// # Errors
@@ -2,7 +2,7 @@ use super::*;
 use crate::errors::StudentProgressError;
 use soroban_sdk::{
     symbol_short,
-    testutils::{Address as _, MockAuth, MockAuthInvoke},
+    testutils::{Address as _, Ledger as _, MockAuth, MockAuthInvoke},
     Address, Env, IntoVal,
 };
 
@@ -502,3 +502,112 @@ fn test_unauthorized_update_progress() {
     // Test unauthorized user trying to update student's progress (should panic)
     client.update_progress(&student, &course_id, &module_id, &percent);
 }
+
+#[test]
+fn test_rapid_duplicate_update_rejected() {
+    let (env, client, admin, student) = setup_test_env();
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.set_min_update_interval(&admin, &60u64);
+    assert_eq!(client.get_min_update_interval(), 60);
+
+    let course_id = symbol_short!("RUST101");
+    let module_id = symbol_short!("MOD1");
+    client.update_progress(&student, &course_id, &module_id, &20u32);
+
+    env.ledger().with_mut(|li| li.timestamp += 59);
+    let result = client.try_update_progress(&student, &course_id, &module_id, &30u32);
+    assert_eq!(result, Err(Ok(StudentProgressError::UpdateTooSoon)));
+    assert_eq!(client.get_progress(&student, &course_id).get(module_id), Some(20u32));
+
+    // Other modules are tracked independently
+    client.update_progress(&student, &course_id, &symbol_short!("MOD2"), &10u32);
+}
+
+#[test]
+fn test_update_after_min_interval_accepted() {
+    let (env, client, admin, student) = setup_test_env();
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.set_min_update_interval(&admin, &60u64);
+
+    let course_id = symbol_short!("RUST101");
+    let module_id = symbol_short!("MOD1");
+    client.update_progress(&student, &course_id, &module_id, &20u32);
+
+    env.ledger().with_mut(|li| li.timestamp += 60);
+    client.update_progress(&student, &course_id, &module_id, &30u32);
+    assert_eq!(client.get_progress(&student, &course_id).get(module_id), Some(30u32));
+
+    // Only the admin may change the interval
+    let result = client.try_set_min_update_interval(&student, &0u64);
+    assert_eq!(result, Err(Ok(StudentProgressError::Unauthorized)));
+}
+
+#[test]
+fn test_complete_before_min_module_time_rejected() {
+    let (env, client, admin, student) = setup_test_env();
+    env.mock_all_auths();
+    client.initialize(&admin);
+
+    let course_id = symbol_short!("RUST101");
+    let module_id = symbol_short!("MOD1");
+    client.set_min_module_time(&admin, &course_id, &600u64);
+    assert_eq!(client.get_min_module_time(&course_id), 600);
+
+    // Completing a module that was never started is rejected
+    let result = client.try_update_progress(&student, &course_id, &module_id, &100u32);
+    assert_eq!(result, Err(Ok(StudentProgressError::ModuleNotStarted)));
+
+    client.update_progress(&student, &course_id, &module_id, &40u32);
+    env.ledger().with_mut(|li| li.timestamp += 599);
+    let result = client.try_update_progress(&student, &course_id, &module_id, &100u32);
+    assert_eq!(result, Err(Ok(StudentProgressError::ModuleTimeNotMet)));
+    assert_eq!(client.get_progress(&student, &course_id).get(module_id), Some(40u32));
+
+    // Courses without the config are unaffected
+    client.update_progress(&student, &symbol_short!("GO101"), &symbol_short!("MOD1"), &100u32);
+}
+
+#[test]
+fn test_complete_after_min_module_time_accepted() {
+    let (env, client, admin, student) = setup_test_env();
+    env.mock_all_auths();
+    client.initialize(&admin);
+
+    let course_id = symbol_short!("RUST101");
+    let module_id = symbol_short!("MOD1");
+    client.set_min_module_time(&admin, &course_id, &600u64);
+
+    client.update_progress(&student, &course_id, &module_id, &10u32);
+    env.ledger().with_mut(|li| li.timestamp += 600);
+    client.update_progress(&student, &course_id, &module_id, &100u32);
+    assert_eq!(client.get_progress(&student, &course_id).get(module_id), Some(100u32));
+
+    // Only the admin may change the minimum
+    let result = client.try_set_min_module_time(&student, &course_id, &0u64);
+    assert_eq!(result, Err(Ok(StudentProgressError::Unauthorized)));
+}
+
+#[test]
+fn test_started_module_completes_with_single_update() {
+    let (env, client, admin, student) = setup_test_env();
+    env.mock_all_auths();
+    client.initialize(&admin);
+
+    let course_id = symbol_short!("RUST101");
+    let module_id = symbol_short!("MOD1");
+    client.set_min_module_time(&admin, &course_id, &600u64);
+
+    client.start_module(&student, &course_id, &module_id);
+    env.ledger().with_mut(|li| li.timestamp += 300);
+
+    // Starting again does not reset the clock
+    client.start_module(&student, &course_id, &module_id);
+    let result = client.try_update_progress(&student, &course_id, &module_id, &100u32);
+    assert_eq!(result, Err(Ok(StudentProgressError::ModuleTimeNotMet)));
+
+    env.ledger().with_mut(|li| li.timestamp += 300);
+    client.update_progress(&student, &course_id, &module_id, &100u32);
+    assert_eq!(client.get_progress(&student, &course_id).get(module_id), Some(100u32));
+}
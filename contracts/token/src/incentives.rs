@@ -11,7 +11,10 @@ use crate::types::{
 };
 use shared::access_control::AccessControl;
 use shared::roles::Permission;
-use soroban_sdk::{contracttype, Address, Env, String, Vec};
+use soroban_sdk::{
+    contracttype, token::StellarAssetClient, Address, Env, Map, String, Symbol, TryFromVal, Val,
+    Vec,
+};
 
 /// Token incentive management system
 pub struct IncentiveManager;
@@ -61,6 +64,10 @@ impl IncentiveManager {
     }
 
     /// Reward user for course completion
+    ///
+    /// Paid by minting on the course's configured reward token if one is set,
+    /// otherwise credited to the native balance. Either way the reward is
+    /// recorded and counted in the user's stats and achievements.
     pub fn reward_course_completion(
         env: &Env,
         user: &Address,
@@ -97,6 +104,9 @@ impl IncentiveManager {
             multiplier: streak_multiplier * event_multiplier / 100,
         };
 
+        if let Some(token) = Self::get_course_reward_token(env, course_id) {
+            StellarAssetClient::new(env, &token).mint(user, &reward_amount);
+        }
         Self::process_reward(env, &reward_id, &reward)?;
         Self::update_user_streak(env, user)?;
         Self::check_achievements(env, user)?;
@@ -104,6 +114,32 @@ impl IncentiveManager {
         Ok(reward_amount)
     }
 
+    /// Route a course's completion rewards through an external token contract
+    ///
+    /// The token contract must expose `mint(to, amount)` and accept this
+    /// contract as its minter. Passing `None` restores the native balance.
+    pub fn set_course_reward_token(
+        env: &Env,
+        admin: &Address,
+        course_id: &String,
+        token: Option<Address>,
+    ) -> Result<(), Error> {
+        AccessControl::require_permission(env, admin, &Permission::UpdateCertificateMetadata)
+            .map_err(|_| Error::Unauthorized)?;
+
+        let key = IncentiveDataKey::CourseRewardToken(course_id.clone());
+        match token {
+            Some(token) => env.storage().persistent().set(&key, &token),
+            None => env.storage().persistent().remove(&key),
+        }
+        Ok(())
+    }
+
+    /// Get the external token contract paying a course's rewards, if any
+    pub fn get_course_reward_token(env: &Env, course_id: &String) -> Option<Address> {
+        env.storage().persistent().get(&IncentiveDataKey::CourseRewardToken(course_id.clone()))
+    }
+
     /// Reward user for module completion
     pub fn reward_module_completion(
         env: &Env,
@@ -301,10 +337,7 @@ impl IncentiveManager {
     }
 
     fn get_streak_multiplier(env: &Env, user: &Address) -> u32 {
-        let streak_data: Option<StreakData> =
-            env.storage().persistent().get(&IncentiveDataKey::UserStats(user.clone()));
-
-        if let Some(data) = streak_data {
+        if let Some(data) = Self::load_streak(env, user) {
             let config = Self::get_config(env).unwrap_or_default();
             let bonus = data.current_streak * config.streak_bonus_rate / 10000;
             (100 + bonus).min(config.max_streak_multiplier)
@@ -339,15 +372,38 @@ impl IncentiveManager {
         Ok(())
     }
 
+    /// Load a user's streak, migrating it off the legacy `UserStats` key
+    ///
+    /// Streaks used to be written under `UserStats`, the same key as the
+    /// per-user reward stats. A streak still found there is moved to
+    /// `UserStreak` and the shared slot freed for the stats. Callers load the
+    /// streak before touching stats, so the move happens before the slot is
+    /// overwritten.
+    fn load_streak(env: &Env, user: &Address) -> Option<StreakData> {
+        let streak_key = IncentiveDataKey::UserStreak(user.clone());
+        if let Some(streak) = env.storage().persistent().get(&streak_key) {
+            return Some(streak);
+        }
+
+        // Both records are maps; only a streak has `last_activity_date`
+        let legacy_key = IncentiveDataKey::UserStats(user.clone());
+        let streak = env
+            .storage()
+            .persistent()
+            .get::<_, Map<Symbol, Val>>(&legacy_key)
+            .filter(|fields| fields.contains_key(Symbol::new(env, "last_activity_date")))
+            .and_then(|fields| StreakData::try_from_val(env, &fields.to_val()).ok())?;
+        env.storage().persistent().set(&streak_key, &streak);
+        env.storage().persistent().remove(&legacy_key);
+        Some(streak)
+    }
+
     fn update_user_streak(env: &Env, user: &Address) -> Result<(), Error> {
         let current_time = env.ledger().timestamp();
         let one_day = 86400u64;
 
-        let mut streak_data: StreakData = env
-            .storage()
-            .persistent()
-            .get(&IncentiveDataKey::UserStats(user.clone()))
-            .unwrap_or_else(|| StreakData {
+        let mut streak_data: StreakData =
+            Self::load_streak(env, user).unwrap_or_else(|| StreakData {
                 user: user.clone(),
                 current_streak: 0,
                 max_streak: 0,
@@ -365,7 +421,7 @@ impl IncentiveManager {
         streak_data.max_streak = streak_data.max_streak.max(streak_data.current_streak);
         streak_data.last_activity_date = current_time;
 
-        env.storage().persistent().set(&IncentiveDataKey::UserStats(user.clone()), &streak_data);
+        env.storage().persistent().set(&IncentiveDataKey::UserStreak(user.clone()), &streak_data);
 
         Ok(())
     }
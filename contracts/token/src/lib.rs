@@ -185,3 +185,5 @@ pub mod benchmarks;
 pub mod gas_optimized;
 #[cfg(test)]
 pub mod property_tests;
+#[cfg(test)]
+mod reward_token_tests;
@@ -391,9 +391,11 @@ pub enum IncentiveDataKey {
 
     // Configuration
     TokenomicsConfig,
+    CourseRewardToken(String), // course_id -> external token contract paying its rewards
 
     // Statistics
     UserStats(Address),
+    UserStreak(Address), // user -> StreakData
     GlobalStats,
 
     // Leaderboards